@@ -0,0 +1,94 @@
+//! Throughput of the generation stages, run with `cargo bench`. an argument
+//! like `cargo bench -- split` only runs the benches with it in their name.
+//!
+//! The benches are grouped by stage: parsing glyph data, transforming whole
+//! glyphs, splitting them into strokes and serializing the svg. stages that
+//! can't run on their own through the public api are measured with the ones
+//! before them, the difference between two groups is the cost of a stage.
+//! every bench reports its throughput in captchas or glyphs per second.
+
+use std::io;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+use biosvg::{BiosvgBuilder, Font, NoiseStyle};
+
+/// path data of a glyph with lines, curves and several sub-paths
+const GLYPH: &str = "M 12 4 C 6 4 2 9 2 16 C 2 23 6 28 12 28 C 18 28 22 23 22 16 \
+    C 22 9 18 4 12 4 Z M 12 10 L 12 22 M 8 16 L 16 16 Q 20 20 16 24";
+
+fn builder() -> BiosvgBuilder {
+    BiosvgBuilder::new().length(4).difficulty(6).colors(vec![
+        "#0078D6".to_string(),
+        "#aa3333".to_string(),
+        "#f08012".to_string(),
+        "#33aa00".to_string(),
+    ])
+}
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("glyph", |b| {
+        b.iter(|| Font::default().with_glyph('a', GLYPH).unwrap())
+    });
+    group.finish();
+}
+
+/// whole glyphs without noise, only the layout transforms and the output
+fn transform(c: &mut Criterion) {
+    let mut group = c.benchmark_group("transform");
+    group.throughput(Throughput::Elements(1));
+    let whole = builder().difficulty(0).split(false).compile().unwrap();
+    group.bench_function("whole glyphs", |b| b.iter(|| whole.generate().unwrap()));
+    let nested = builder()
+        .difficulty(0)
+        .split(false)
+        .nested_groups(3)
+        .compile()
+        .unwrap();
+    group.bench_function("nested groups", |b| b.iter(|| nested.generate().unwrap()));
+    group.finish();
+}
+
+fn split(c: &mut Criterion) {
+    let mut group = c.benchmark_group("split");
+    group.throughput(Throughput::Elements(1));
+    let strokes = builder().difficulty(0).compile().unwrap();
+    group.bench_function("strokes", |b| b.iter(|| strokes.generate().unwrap()));
+    let scrambled = builder().difficulty(0).scramble(true).compile().unwrap();
+    group.bench_function("scrambled", |b| b.iter(|| scrambled.generate().unwrap()));
+    group.finish();
+}
+
+/// complete captchas, written to a string or streamed
+fn serialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("serialize");
+    group.throughput(Throughput::Elements(1));
+    let default = builder().compile().unwrap();
+    group.bench_function("string", |b| b.iter(|| default.generate().unwrap()));
+    // streaming skips the captcha metadata and the svg string
+    group.bench_function("stream", |b| {
+        b.iter(|| default.write_svg(io::sink()).unwrap())
+    });
+    let optimized = builder().optimize(2).compile().unwrap();
+    group.bench_function("stream optimized", |b| {
+        b.iter(|| optimized.write_svg(io::sink()).unwrap())
+    });
+    let obfuscated = builder().obfuscate(true).compile().unwrap();
+    group.bench_function("string obfuscated", |b| {
+        b.iter(|| obfuscated.generate().unwrap())
+    });
+    let hard = builder()
+        .length(6)
+        .difficulty(20)
+        .dots(20)
+        .noise_style(NoiseStyle::Mimic)
+        .compile()
+        .unwrap();
+    group.bench_function("string hard", |b| b.iter(|| hard.generate().unwrap()));
+    group.finish();
+}
+
+criterion_group!(benches, parse, transform, split, serialize);
+criterion_main!(benches);
@@ -0,0 +1,50 @@
+/*
+ * C bindings of biosvg, exported by the shared library built with the `ffi`
+ * feature: `cargo build --release --features ffi`.
+ */
+
+#ifndef BIOSVG_H
+#define BIOSVG_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * A generated captcha as nul terminated utf-8 strings, free it with
+ * `biosvg_free`.
+ */
+typedef struct BiosvgCaptcha {
+    /* answer text of the captcha */
+    char *answer;
+    /* rendered svg document */
+    char *svg;
+} BiosvgCaptcha;
+
+/*
+ * Generate a captcha of `length` characters with `difficulty` noise lines in
+ * the `colors_len` colors at `colors`, returns null if the colors are missing
+ * or invalid or the captcha can't be generated.
+ *
+ * `colors` must point to `colors_len` valid nul terminated strings.
+ */
+BiosvgCaptcha *biosvg_generate(size_t length,
+                               uint16_t difficulty,
+                               const char *const *colors,
+                               size_t colors_len);
+
+/*
+ * Free a captcha of `biosvg_generate`, null is ignored.
+ *
+ * `captcha` must be null or returned by `biosvg_generate` and not freed yet.
+ */
+void biosvg_free(BiosvgCaptcha *captcha);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* BIOSVG_H */
@@ -0,0 +1,309 @@
+//! Ready-made actix-web responder and middleware, enabled by the
+//! `biosvg-actix` feature.
+
+use ::actix_web::body::MessageBody;
+use ::actix_web::dev::{ServiceRequest, ServiceResponse};
+use ::actix_web::error::ErrorInternalServerError;
+use ::actix_web::http::header::{CACHE_CONTROL, CONTENT_TYPE, COOKIE, SET_COOKIE};
+use ::actix_web::http::StatusCode;
+use ::actix_web::middleware::Next;
+use ::actix_web::{web, Error, HttpRequest, HttpResponse, Responder, ResponseError};
+use thiserror::Error;
+
+use super::captcha::entropy_rng;
+use super::integration::{credentials, token_cookie};
+pub use super::integration::{
+    CaptchaState, ANSWER_FIELD, ANSWER_HEADER, TOKEN_COOKIE, TOKEN_FIELD, TOKEN_HEADER,
+};
+use super::token::TokenError;
+use super::{Captcha, SVG_CONTENT_TYPE};
+
+/// CaptchaResponse responds with the svg of a captcha as `image/svg+xml`,
+/// the signed token is set in the `x-captcha-token` header and the
+/// `biosvg_token` cookie
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaptchaResponse {
+    svg: String,
+    token: String,
+    max_age: u64,
+    secure: bool,
+}
+
+impl CaptchaResponse {
+    pub fn new(captcha: Captcha, secret: &[u8]) -> CaptchaResponse {
+        CaptchaResponse {
+            token: captcha.sign(secret),
+            max_age: captcha.expires_at.saturating_sub(captcha.created_at),
+            svg: captcha.svg,
+            secure: true,
+        }
+    }
+
+    /// set whether the token cookie is `Secure`, defaults to true. browsers
+    /// only send it back over https then, turn it off for plain http in
+    /// development.
+    pub fn secure_cookie(mut self, secure: bool) -> CaptchaResponse {
+        self.secure = secure;
+        self
+    }
+}
+
+impl Responder for CaptchaResponse {
+    type Body = ::actix_web::body::BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse {
+        let cookie = token_cookie(&self.token, self.max_age, self.secure);
+        HttpResponse::Ok()
+            .content_type(SVG_CONTENT_TYPE)
+            .insert_header((CACHE_CONTROL, "no-store"))
+            .insert_header((TOKEN_HEADER, self.token))
+            .insert_header((SET_COOKIE, cookie))
+            .body(self.svg)
+    }
+}
+
+/// Serve a fresh captcha of the registered `CaptchaState`, generated on the
+/// blocking thread pool with a fresh random generator, the `seed` of the
+/// builder is ignored.
+pub async fn captcha_handler(state: web::Data<CaptchaState>) -> Result<CaptchaResponse, Error> {
+    let generator = state.generator.clone();
+    let captcha = web::block(move || generator.generate_with_rng(&mut entropy_rng()))
+        .await
+        .map_err(ErrorInternalServerError)?
+        .map_err(ErrorInternalServerError)?;
+    Ok(CaptchaResponse::new(captcha, &state.secret).secure_cookie(state.secure))
+}
+
+/// CaptchaRejection is why a request has no valid captcha
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CaptchaRejection {
+    /// the token or the answer is missing
+    #[error("missing captcha token or answer")]
+    Missing,
+    /// the token is invalid, expired or the answer is wrong
+    #[error(transparent)]
+    Invalid(TokenError),
+}
+
+impl ResponseError for CaptchaRejection {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            CaptchaRejection::Missing => StatusCode::BAD_REQUEST,
+            CaptchaRejection::Invalid(_) => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+/// Middleware that only passes on requests with a valid token and the right
+/// answer, wrap the routes that need a solved captcha with
+/// `middleware::from_fn(require_captcha)`. a token is only accepted once.
+///
+/// the answer is read from the `x-captcha-answer` header, or else from the
+/// `captcha_answer` field of an `application/x-www-form-urlencoded` body, so
+/// plain html forms can post it. the token comes from the header, the cookie
+/// or the `captcha_token` field. the body is buffered and handed on to the
+/// handler unchanged.
+pub async fn require_captcha(
+    mut req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let state = req
+        .app_data::<web::Data<CaptchaState>>()
+        .cloned()
+        .ok_or_else(|| ErrorInternalServerError("CaptchaState is not registered"))?;
+    let form = req
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"))
+        && !req.headers().contains_key(ANSWER_HEADER);
+    let form = match form {
+        true => {
+            let body = req.extract::<web::Bytes>().await?;
+            req.set_payload(body.clone().into());
+            Some(body)
+        }
+        false => None,
+    };
+    let headers = req.headers();
+    let (token, answer) = credentials(
+        |name| headers.get(name)?.to_str().ok(),
+        headers
+            .get_all(COOKIE)
+            .filter_map(|value| value.to_str().ok()),
+        form.as_deref()
+            .and_then(|form| std::str::from_utf8(form).ok()),
+    )
+    .ok_or(CaptchaRejection::Missing)?;
+    state
+        .used
+        .verify_token(&state.secret, &token, &answer)
+        .map_err(CaptchaRejection::Invalid)?;
+    next.call(req).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::PathError;
+    use crate::BiosvgBuilder;
+
+    #[test]
+    fn actix_integration() {
+        use ::actix_web::http::StatusCode;
+        use ::actix_web::middleware::from_fn;
+        use ::actix_web::test::{call_service, init_service, try_call_service, TestRequest};
+        use ::actix_web::{web, App};
+
+        let builder = BiosvgBuilder::new()
+            .text("AbcD")
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .max_attempts(2);
+        // bad settings fail when the state is made
+        assert!(matches!(
+            CaptchaState::new(builder.clone().colors(Vec::new()), b"secret"),
+            Err(PathError::Build(_))
+        ));
+        let state = web::Data::new(CaptchaState::new(builder.clone(), b"secret").unwrap());
+        let seeded = web::Data::new(CaptchaState::new(builder.seed(7), b"secret").unwrap());
+        ::actix_web::rt::System::new().block_on(async {
+            let app = init_service(
+                App::new()
+                    .app_data(state)
+                    .route("/captcha", web::get().to(captcha_handler))
+                    .service(
+                        web::resource("/submit")
+                            .wrap(from_fn(require_captcha))
+                            .route(web::post().to(|| async { "ok" })),
+                    )
+                    .service(
+                        web::resource("/comment")
+                            .wrap(from_fn(require_captcha))
+                            .route(web::post().to(|body: String| async { body })),
+                    ),
+            )
+            .await;
+
+            let response =
+                call_service(&app, TestRequest::get().uri("/captcha").to_request()).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            let header = |name: &str| response.headers().get(name).unwrap().to_str().unwrap();
+            assert_eq!(header("content-type"), "image/svg+xml");
+            let token = header(TOKEN_HEADER).to_string();
+            assert!(header("set-cookie").starts_with(&format!("biosvg_token={}", token)));
+            assert!(header("set-cookie").ends_with("; Secure"));
+
+            let submit = |token: &str, answer: &str| {
+                TestRequest::post()
+                    .uri("/submit")
+                    .insert_header(("cookie", format!("theme=dark; biosvg_token={}", token)))
+                    .insert_header((ANSWER_HEADER, answer.to_string()))
+                    .to_request()
+            };
+            // rejections of the middleware are errors, the server turns them
+            // into responses
+            let status = |result: Result<_, ::actix_web::Error>| match result {
+                Ok(response) => ::actix_web::dev::ServiceResponse::status(&response),
+                Err(err) => err.as_response_error().status_code(),
+            };
+            let response = try_call_service(&app, submit(&token, "AbcD")).await;
+            assert_eq!(status(response), StatusCode::OK);
+            // a solved token can't be replayed
+            let response = try_call_service(&app, submit(&token, "AbcD")).await;
+            assert_eq!(
+                response.err().map(|err| err.to_string()),
+                Some(TokenError::AlreadyUsed.to_string())
+            );
+            let response = try_call_service(&app, submit(&token, "abcd")).await;
+            assert_eq!(status(response), StatusCode::FORBIDDEN);
+
+            // the right answer fails after max_attempts wrong guesses
+            let response =
+                call_service(&app, TestRequest::get().uri("/captcha").to_request()).await;
+            let token = response
+                .headers()
+                .get(TOKEN_HEADER)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string();
+            for answer in ["abcd", "AbcE"] {
+                let response = try_call_service(&app, submit(&token, answer)).await;
+                assert_eq!(
+                    response.err().map(|err| err.to_string()),
+                    Some(TokenError::WrongAnswer.to_string())
+                );
+            }
+            let response = try_call_service(&app, submit(&token, "AbcD")).await;
+            assert_eq!(
+                response.err().map(|err| err.to_string()),
+                Some(TokenError::TooManyAttempts.to_string())
+            );
+            let request = TestRequest::post().uri("/submit").to_request();
+            let response = try_call_service(&app, request).await;
+            assert_eq!(status(response), StatusCode::BAD_REQUEST);
+
+            // plain html forms post the answer in the body, the handler still
+            // gets the whole body
+            let post = |token: &str, answer: &str| {
+                let form = format!(
+                    "text=hi+there&captcha_token={}&captcha_answer={}",
+                    token, answer
+                );
+                TestRequest::post()
+                    .uri("/comment")
+                    .insert_header(("content-type", "application/x-www-form-urlencoded"))
+                    .set_payload(form)
+                    .to_request()
+            };
+            let response =
+                call_service(&app, TestRequest::get().uri("/captcha").to_request()).await;
+            let token = response
+                .headers()
+                .get(TOKEN_HEADER)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string();
+            let response = try_call_service(&app, post(&token, "Abc%44"))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = ::actix_web::test::read_body(response).await;
+            assert_eq!(
+                body,
+                format!(
+                    "text=hi+there&captcha_token={}&captcha_answer=Abc%44",
+                    token
+                )
+            );
+            let response = try_call_service(&app, post(&token, "AbcD")).await;
+            assert_eq!(
+                response.err().map(|err| err.to_string()),
+                Some(TokenError::AlreadyUsed.to_string())
+            );
+            let request = TestRequest::post()
+                .uri("/comment")
+                .insert_header(("content-type", "application/x-www-form-urlencoded"))
+                .set_payload(format!("captcha_token={}", token))
+                .to_request();
+            let response = try_call_service(&app, request).await;
+            assert_eq!(status(response), StatusCode::BAD_REQUEST);
+
+            // the seed of the builder doesn't make every captcha the same
+            let app = init_service(
+                App::new()
+                    .app_data(seeded)
+                    .route("/captcha", web::get().to(captcha_handler)),
+            )
+            .await;
+            let mut svgs = Vec::new();
+            for _ in 0..2 {
+                let response =
+                    call_service(&app, TestRequest::get().uri("/captcha").to_request()).await;
+                svgs.push(::actix_web::test::read_body(response).await);
+            }
+            assert_ne!(svgs[0], svgs[1]);
+        });
+    }
+}
@@ -0,0 +1,124 @@
+//! Progressive hardening of the captcha for clients that keep failing it.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::BiosvgBuilder;
+
+/// DifficultyController tracks failed and solved captchas per client and
+/// escalates the builder of suspicious clients: every net failure adds a
+/// level, up to `max_level`, and every solved captcha takes one away
+#[derive(Debug)]
+pub struct DifficultyController {
+    base: BiosvgBuilder,
+    max_level: u32,
+    levels: Mutex<HashMap<String, u32>>,
+}
+
+impl DifficultyController {
+    /// escalate `base` for failing clients, up to 5 levels
+    pub fn new(base: BiosvgBuilder) -> DifficultyController {
+        DifficultyController {
+            base,
+            max_level: 5,
+            levels: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// set the highest level a client can reach, defaults to 5
+    pub fn max_level(mut self, max_level: u32) -> DifficultyController {
+        self.max_level = max_level;
+        self
+    }
+
+    /// the client failed a captcha, e.g. keyed by ip address or session
+    pub fn record_failure(&self, client: &str) {
+        let mut levels = self.levels.lock().unwrap();
+        let level = levels.entry(client.to_string()).or_insert(0);
+        *level = (*level + 1).min(self.max_level);
+    }
+
+    /// the client solved a captcha, clients back at level 0 are forgotten
+    pub fn record_success(&self, client: &str) {
+        let mut levels = self.levels.lock().unwrap();
+        if let Some(level) = levels.get_mut(client) {
+            *level = level.saturating_sub(1);
+            if *level == 0 {
+                levels.remove(client);
+            }
+        }
+    }
+
+    /// current level of the client, 0 for clients without failures
+    pub fn level(&self, client: &str) -> u32 {
+        self.levels
+            .lock()
+            .unwrap()
+            .get(client)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// forget all clients, e.g. periodically so the table doesn't grow
+    pub fn reset(&self) {
+        self.levels.lock().unwrap().clear();
+    }
+
+    /// Builder for the next captcha of the client. each level adds a
+    /// character every other level, 2 noise strokes, a wave warp of 2% of
+    /// the character height and 0.05 radians of rotation.
+    pub fn builder(&self, client: &str) -> BiosvgBuilder {
+        self.escalate(self.level(client))
+    }
+
+    /// the base builder escalated to `level`
+    fn escalate(&self, level: u32) -> BiosvgBuilder {
+        let mut builder = self.base.clone();
+        if level == 0 {
+            return builder;
+        }
+        let level_f = level as f64;
+        builder.length += (level as usize).div_ceil(2);
+        builder.difficulty = builder
+            .difficulty
+            .saturating_add((level * 2).min(u16::MAX as u32) as u16);
+        builder.wave_amplitude += 0.02 * level_f;
+        let (min_angle, max_angle) = builder.rotation_range;
+        builder.rotation_range = (min_angle - 0.05 * level_f, max_angle + 0.05 * level_f);
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn difficulty_controller() {
+        let base = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(6)
+            .colors(vec!["#0078D6".to_string()]);
+        let controller = DifficultyController::new(base.clone()).max_level(3);
+        assert_eq!(controller.builder("1.2.3.4").length, 4);
+        for _ in 0..5 {
+            controller.record_failure("1.2.3.4");
+        }
+        assert_eq!(controller.level("1.2.3.4"), 3);
+        assert_eq!(controller.level("5.6.7.8"), 0);
+        let hard = controller.builder("1.2.3.4");
+        assert_eq!((hard.length, hard.difficulty), (6, 12));
+        assert!(hard.wave_amplitude > base.wave_amplitude);
+        assert!(hard.rotation_range.1 > base.rotation_range.1);
+        let (answer, _) = hard.build().unwrap();
+        assert_eq!(answer.len(), 6);
+
+        controller.record_success("1.2.3.4");
+        assert_eq!(controller.level("1.2.3.4"), 2);
+        controller.record_success("1.2.3.4");
+        controller.record_success("1.2.3.4");
+        controller.record_success("1.2.3.4");
+        assert_eq!(controller.level("1.2.3.4"), 0);
+        assert_eq!(controller.builder("1.2.3.4").difficulty, 6);
+    }
+}
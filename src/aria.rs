@@ -0,0 +1,55 @@
+//! Accessibility attributes of the svg root.
+
+use std::fmt::{self, Write};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::format::escape;
+
+/// AriaOptions describes the captcha to assistive technology, the svg gets
+/// `role="img"` and the set texts
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct AriaOptions {
+    /// text of the `<title>` element, the accessible name of the image
+    pub title: Option<String>,
+    /// text of the `<desc>` element, a longer description
+    pub desc: Option<String>,
+    /// value of the `aria-label` attribute
+    pub label: Option<String>,
+}
+
+impl Default for AriaOptions {
+    /// a "captcha challenge" title and label, without description
+    fn default() -> Self {
+        AriaOptions {
+            title: Some("captcha challenge".to_string()),
+            desc: None,
+            label: Some("captcha challenge".to_string()),
+        }
+    }
+}
+
+impl AriaOptions {
+    /// Write the attributes of the svg root, with a leading space.
+    pub(crate) fn write_attributes<W: Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        out.write_str(r#" role="img""#)?;
+        if let Some(label) = &self.label {
+            write!(out, r#" aria-label="{}""#, escape(label))?;
+        }
+        Ok(())
+    }
+
+    /// Write the `<title>` and `<desc>` elements, the first children of the
+    /// svg root.
+    pub(crate) fn write_elements<W: Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        if let Some(title) = &self.title {
+            write!(out, "<title>{}</title>", escape(title))?;
+        }
+        if let Some(desc) = &self.desc {
+            write!(out, "<desc>{}</desc>", escape(desc))?;
+        }
+        Ok(())
+    }
+}
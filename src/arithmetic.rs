@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Operator of an arithmetic expression
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl Operator {
+    /// the glyph the operator is drawn with
+    pub fn symbol(self) -> char {
+        match self {
+            Operator::Add => '+',
+            Operator::Sub => '-',
+            Operator::Mul => '×',
+        }
+    }
+}
+
+/// Expression is a chain of single digit operands and operators, evaluated
+/// with the usual precedence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expression {
+    pub operands: Vec<i64>,
+    pub operators: Vec<Operator>,
+}
+
+impl Expression {
+    /// Generate a random expression with `terms` operands, at least two.
+    /// operands are 2 to 9 since the built-in font has no glyphs for 0 and
+    /// 1, and expressions with a negative value are generated again.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R, terms: usize) -> Expression {
+        let terms = terms.max(2);
+        loop {
+            let expression = Expression {
+                operands: (0..terms).map(|_| rng.gen_range(2..=9)).collect(),
+                operators: (1..terms)
+                    .map(|_| {
+                        *[Operator::Add, Operator::Sub, Operator::Mul]
+                            .choose(rng)
+                            .unwrap()
+                    })
+                    .collect(),
+            };
+            if expression.value() >= 0 {
+                return expression;
+            }
+        }
+    }
+
+    /// value of the expression, multiplication binds tighter than addition
+    /// and subtraction
+    pub fn value(&self) -> i64 {
+        let mut sum = 0;
+        let mut sign = 1;
+        let mut product = self.operands.first().copied().unwrap_or(0);
+        for (operator, operand) in self.operators.iter().zip(self.operands.iter().skip(1)) {
+            match operator {
+                Operator::Mul => product *= *operand,
+                Operator::Add | Operator::Sub => {
+                    sum += sign * product;
+                    sign = if *operator == Operator::Add { 1 } else { -1 };
+                    product = *operand;
+                }
+            }
+        }
+        sum + sign * product
+    }
+}
+
+/// most operands of an expression, longer ones take too long to count and
+/// their products overflow soon after
+pub const MAX_TERMS: usize = 8;
+
+/// `count_values` of 2 to `MAX_TERMS` operands
+const VALUE_COUNTS: [usize; MAX_TERMS - 1] = [40, 147, 657, 2470, 7648, 22622, 60718];
+
+/// Number of distinct values of the expressions `Expression::random` can
+/// generate with `terms` operands, at most `MAX_TERMS`.
+pub fn value_count(terms: usize) -> usize {
+    VALUE_COUNTS[terms.clamp(2, MAX_TERMS) - 2]
+}
+
+/// Count the values by walking the partial sums and the signed product of
+/// the last term, takes seconds for long expressions. only used to check
+/// the table of `value_count`.
+#[cfg(test)]
+pub fn count_values(terms: usize) -> usize {
+    use std::collections::HashSet;
+
+    let mut states = (2..=9)
+        .map(|operand| (0, operand))
+        .collect::<HashSet<(i64, i64)>>();
+    for _ in 1..terms.max(2) {
+        let mut next = HashSet::new();
+        for &(sum, product) in &states {
+            for operand in 2..=9 {
+                next.insert((sum, product * operand));
+                next.insert((sum + product, operand));
+                next.insert((sum + product, -operand));
+            }
+        }
+        states = next;
+    }
+    states
+        .into_iter()
+        .map(|(sum, product)| sum + product)
+        .filter(|value| *value >= 0)
+        .collect::<HashSet<i64>>()
+        .len()
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(first) = self.operands.first() {
+            write!(f, "{}", first)?;
+        }
+        for (operator, operand) in self.operators.iter().zip(self.operands.iter().skip(1)) {
+            write!(f, "{}{}", operator.symbol(), operand)?;
+        }
+        Ok(())
+    }
+}
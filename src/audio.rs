@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+
+use super::model::{BuildError, PathError};
+
+/// Voice is a set of recorded clips, one per character, that audio captchas
+/// are assembled from
+#[derive(Debug, Clone)]
+pub struct Voice {
+    sample_rate: u32,
+    clips: HashMap<char, Vec<i16>>,
+}
+
+impl Voice {
+    /// an empty voice, all clips must have this sample rate
+    pub fn new(sample_rate: u32) -> Voice {
+        Voice {
+            sample_rate,
+            clips: HashMap::new(),
+        }
+    }
+
+    /// add the clip of a character as 16 bit mono samples
+    pub fn clip(mut self, ch: char, samples: Vec<i16>) -> Voice {
+        self.clips.insert(ch, samples);
+        self
+    }
+
+    /// add the clip of a character from a 16 bit mono pcm wav file with the
+    /// sample rate of the voice
+    pub fn clip_wav(self, ch: char, wav: &[u8]) -> Result<Voice, PathError> {
+        let (sample_rate, samples) = decode_wav(wav)?;
+        if sample_rate != self.sample_rate {
+            return Err(PathError::AudioError(format!(
+                "sample rate {} of {:?} doesn't match the voice",
+                sample_rate, ch
+            )));
+        }
+        Ok(self.clip(ch, samples))
+    }
+
+    /// whether the voice has a clip for the character, letters of either case
+    /// share a clip
+    pub fn contains(&self, ch: char) -> bool {
+        self.clip_of(ch).is_some()
+    }
+
+    fn clip_of(&self, ch: char) -> Option<&Vec<i16>> {
+        self.clips
+            .get(&ch)
+            .or_else(|| self.clips.get(&ch.to_ascii_lowercase()))
+            .or_else(|| self.clips.get(&ch.to_ascii_uppercase()))
+    }
+
+    /// Read the text as wav file: the clips with random pauses in between and
+    /// a random volume each, over a layer of white noise.
+    pub(crate) fn speak<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        text: &str,
+    ) -> Result<Vec<u8>, PathError> {
+        let rate = self.sample_rate as f64;
+        let mut samples = Vec::new();
+        for ch in text.chars() {
+            let clip = self
+                .clip_of(ch)
+                .ok_or(BuildError::UnsupportedCharacter(ch))?;
+            let pause = (rng.gen_range(0.25..0.6) * rate) as usize;
+            samples.extend(std::iter::repeat_n(0.0, pause));
+            let gain = rng.gen_range(0.7..1.0);
+            samples.extend(clip.iter().map(|sample| *sample as f64 * gain));
+        }
+        let pause = (0.4 * rate) as usize;
+        samples.extend(std::iter::repeat_n(0.0, pause));
+
+        let noise = i16::MAX as f64 * 0.02;
+        let samples = samples
+            .into_iter()
+            .map(|sample| {
+                let sample = sample + rng.gen_range(-noise..=noise);
+                sample.clamp(i16::MIN as f64, i16::MAX as f64) as i16
+            })
+            .collect::<Vec<i16>>();
+        Ok(encode_wav(self.sample_rate, &samples))
+    }
+}
+
+/// Encode 16 bit mono samples as pcm wav file.
+pub(crate) fn encode_wav(sample_rate: u32, samples: &[i16]) -> Vec<u8> {
+    let data_len = samples.len() as u32 * 2;
+    let mut wav = Vec::with_capacity(44 + data_len as usize);
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    // pcm, mono
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    // block align, bits per sample
+    wav.extend_from_slice(&2u16.to_le_bytes());
+    wav.extend_from_slice(&16u16.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        wav.extend_from_slice(&sample.to_le_bytes());
+    }
+    wav
+}
+
+/// Decode a 16 bit mono pcm wav file, returns the sample rate and samples.
+fn decode_wav(wav: &[u8]) -> Result<(u32, Vec<i16>), PathError> {
+    let invalid = |reason: &str| PathError::AudioError(reason.to_string());
+    if wav.len() < 12 || &wav[0..4] != b"RIFF" || &wav[8..12] != b"WAVE" {
+        return Err(invalid("not a wav file"));
+    }
+    let mut sample_rate = None;
+    let mut chunks = &wav[12..];
+    while chunks.len() >= 8 {
+        let id = &chunks[0..4];
+        let len = u32::from_le_bytes([chunks[4], chunks[5], chunks[6], chunks[7]]) as usize;
+        let body = chunks
+            .get(8..8 + len)
+            .ok_or_else(|| invalid("truncated chunk"))?;
+        match id {
+            b"fmt " => {
+                if body.len() < 16 {
+                    return Err(invalid("truncated format chunk"));
+                }
+                let field = |i: usize| u16::from_le_bytes([body[i], body[i + 1]]);
+                if field(0) != 1 || field(2) != 1 || field(14) != 16 {
+                    return Err(invalid("only 16 bit mono pcm is supported"));
+                }
+                sample_rate = Some(u32::from_le_bytes([body[4], body[5], body[6], body[7]]));
+            }
+            b"data" => {
+                let sample_rate = sample_rate.ok_or_else(|| invalid("data before format"))?;
+                let samples = body
+                    .chunks_exact(2)
+                    .map(|bytes| i16::from_le_bytes([bytes[0], bytes[1]]))
+                    .collect();
+                return Ok((sample_rate, samples));
+            }
+            _ => {}
+        }
+        // chunks are padded to an even length
+        let next = (8 + len + len % 2).min(chunks.len());
+        chunks = &chunks[next..];
+    }
+    Err(invalid("no data chunk"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BiosvgBuilder;
+
+    #[test]
+    fn audio_captcha() {
+        let tone = (0..800)
+            .map(|i| ((i % 40) * 500) as i16)
+            .collect::<Vec<i16>>();
+        let wav = encode_wav(8000, &tone);
+        let mut voice = Voice::new(8000);
+        for ch in "2345".chars() {
+            voice = voice.clip_wav(ch, &wav).unwrap();
+        }
+        assert!(Voice::new(16000).clip_wav('2', &wav).is_err());
+        assert!(Voice::new(8000).clip_wav('2', b"RIFF").is_err());
+
+        let (answer, svg, wav) = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .charset("2345")
+            .voice(voice.clone())
+            .build_audio()
+            .unwrap();
+        assert_eq!(answer.len(), 4);
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(&wav[0..4], b"RIFF");
+        // at least the four clips and the last pause
+        assert!(wav.len() > 44 + 2 * (4 * 800 + 3200));
+
+        let err = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .charset("2345A")
+            .text("2A")
+            .voice(voice)
+            .build_audio()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            PathError::Build(BuildError::UnsupportedCharacter('A'))
+        ));
+    }
+}
@@ -0,0 +1,289 @@
+//! Ready-made axum handler and extractors, enabled by the `biosvg-axum`
+//! feature.
+
+use ::axum::body::Bytes;
+use ::axum::extract::{FromRef, FromRequest, FromRequestParts, Request, State};
+use ::axum::http::header::{CACHE_CONTROL, CONTENT_TYPE, COOKIE, SET_COOKIE};
+use ::axum::http::request::Parts;
+use ::axum::http::{HeaderMap, StatusCode};
+use ::axum::response::{IntoResponse, Response};
+
+use super::captcha::entropy_rng;
+use super::integration::{credentials, token_cookie};
+pub use super::integration::{
+    CaptchaState, ANSWER_FIELD, ANSWER_HEADER, TOKEN_COOKIE, TOKEN_FIELD, TOKEN_HEADER,
+};
+use super::model::PathError;
+use super::token::TokenError;
+use super::SVG_CONTENT_TYPE;
+
+/// Serve a fresh captcha as `image/svg+xml`, the signed token is set in the
+/// `x-captcha-token` header and the `biosvg_token` cookie. the captcha is
+/// generated on the blocking thread pool with a fresh random generator, the
+/// `seed` of the builder is ignored.
+pub async fn captcha_handler(State(state): State<CaptchaState>) -> Response {
+    let generator = state.generator.clone();
+    let generated =
+        tokio::task::spawn_blocking(move || generator.generate_with_rng(&mut entropy_rng()));
+    let captcha = match generated.await {
+        Ok(captcha) => captcha,
+        Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+        Err(_) => Err(PathError::Cancelled),
+    };
+    let captcha = match captcha {
+        Ok(captcha) => captcha,
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    };
+    let token = captcha.sign(&state.secret);
+    let max_age = captcha.expires_at.saturating_sub(captcha.created_at);
+    let cookie = token_cookie(&token, max_age, state.secure);
+    (
+        [
+            (CONTENT_TYPE.as_str(), SVG_CONTENT_TYPE.to_string()),
+            (CACHE_CONTROL.as_str(), "no-store".to_string()),
+            (TOKEN_HEADER, token),
+            (SET_COOKIE.as_str(), cookie),
+        ],
+        captcha.svg,
+    )
+        .into_response()
+}
+
+/// ValidCaptcha only extracts from requests with a valid token and the right
+/// answer in the `x-captcha-answer` header, add it to the handlers that need
+/// a solved captcha. a token is only accepted once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidCaptcha;
+
+/// CaptchaRejection is why a request has no valid captcha
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaptchaRejection {
+    /// the token or the answer is missing
+    Missing,
+    /// the token is invalid, expired or the answer is wrong
+    Invalid(TokenError),
+}
+
+impl IntoResponse for CaptchaRejection {
+    fn into_response(self) -> Response {
+        match self {
+            CaptchaRejection::Missing => {
+                (StatusCode::BAD_REQUEST, "missing captcha token or answer").into_response()
+            }
+            CaptchaRejection::Invalid(err) => {
+                (StatusCode::FORBIDDEN, err.to_string()).into_response()
+            }
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for ValidCaptcha
+where
+    CaptchaState: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = CaptchaRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        check(&CaptchaState::from_ref(state), &parts.headers, None)?;
+        Ok(ValidCaptcha)
+    }
+}
+
+/// ValidCaptchaForm is `ValidCaptcha` for plain html forms: the answer can
+/// also be posted in the `captcha_answer` field of an
+/// `application/x-www-form-urlencoded` body and the token in the
+/// `captcha_token` field. it consumes the body, so it must be the last
+/// argument of the handler, and hands it on unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidCaptchaForm(pub Bytes);
+
+impl<S> FromRequest<S> for ValidCaptchaForm
+where
+    CaptchaState: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let captcha = CaptchaState::from_ref(state);
+        let headers = req.headers().clone();
+        let body = Bytes::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        let form = headers
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"))
+            .then(|| std::str::from_utf8(&body).ok())
+            .flatten();
+        check(&captcha, &headers, form).map_err(IntoResponse::into_response)?;
+        Ok(ValidCaptchaForm(body))
+    }
+}
+
+/// check the token and answer of a request with `UsedTokens` of the state
+fn check(
+    state: &CaptchaState,
+    headers: &HeaderMap,
+    form: Option<&str>,
+) -> Result<(), CaptchaRejection> {
+    let (token, answer) = credentials(
+        |name| headers.get(name)?.to_str().ok(),
+        headers
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok()),
+        form,
+    )
+    .ok_or(CaptchaRejection::Missing)?;
+    state
+        .used
+        .verify_token(&state.secret, &token, &answer)
+        .map_err(CaptchaRejection::Invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BiosvgBuilder;
+
+    #[tokio::test]
+    async fn axum_integration() {
+        use ::axum::body::Body;
+        use ::axum::http::{Request, StatusCode};
+        use ::axum::routing::{get, post};
+        use ::axum::Router;
+        use tower::ServiceExt;
+
+        let builder = BiosvgBuilder::new()
+            .text("AbcD")
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .max_attempts(2);
+        // bad settings fail when the state is made
+        assert!(matches!(
+            CaptchaState::new(builder.clone().colors(Vec::new()), b"secret"),
+            Err(PathError::Build(_))
+        ));
+        let app = Router::new()
+            .route("/captcha", get(captcha_handler))
+            .route("/submit", post(|_: ValidCaptcha| async { "ok" }))
+            .route(
+                "/comment",
+                post(|ValidCaptchaForm(body)| async move { body }),
+            )
+            .with_state(CaptchaState::new(builder.clone(), b"secret").unwrap());
+
+        let response = app
+            .clone()
+            .oneshot(Request::get("/captcha").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "image/svg+xml");
+        let token = response.headers()[TOKEN_HEADER]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let cookie = response.headers()["set-cookie"].to_str().unwrap();
+        assert!(cookie.starts_with(&format!("biosvg_token={}", token)));
+        assert!(cookie.ends_with("; Secure"));
+
+        let submit = |token: &str, answer: &str| {
+            Request::post("/submit")
+                .header("cookie", format!("theme=dark; biosvg_token={}", token))
+                .header(ANSWER_HEADER, answer)
+                .body(Body::empty())
+                .unwrap()
+        };
+        let response = app.clone().oneshot(submit(&token, "AbcD")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        // a solved token can't be replayed
+        let response = app.clone().oneshot(submit(&token, "AbcD")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let body = ::axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body, TokenError::AlreadyUsed.to_string().as_bytes());
+        let response = app.clone().oneshot(submit(&token, "abcd")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // the right answer fails after max_attempts wrong guesses
+        let response = app
+            .clone()
+            .oneshot(Request::get("/captcha").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let token = response.headers()[TOKEN_HEADER]
+            .to_str()
+            .unwrap()
+            .to_string();
+        for answer in ["abcd", "AbcE"] {
+            let response = app.clone().oneshot(submit(&token, answer)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+        let response = app.clone().oneshot(submit(&token, "AbcD")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let body = ::axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body, TokenError::TooManyAttempts.to_string().as_bytes());
+        let request = Request::post("/submit").body(Body::empty()).unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        // plain html forms post the answer in the body, the handler still
+        // gets the whole body
+        let response = app
+            .clone()
+            .oneshot(Request::get("/captcha").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let token = response.headers()[TOKEN_HEADER]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let post = |form: String| {
+            Request::post("/comment")
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from(form))
+                .unwrap()
+        };
+        let form = format!(
+            "text=hi+there&captcha_token={}&captcha_answer=Abc%44",
+            token
+        );
+        let response = app.clone().oneshot(post(form.clone())).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = ::axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body, form.as_bytes());
+        let response = app.clone().oneshot(post(form)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response = app
+            .clone()
+            .oneshot(post(format!("captcha_token={}", token)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        // the seed of the builder doesn't make every captcha the same
+        let app = Router::new()
+            .route("/captcha", get(captcha_handler))
+            .with_state(CaptchaState::new(builder.seed(7), b"secret").unwrap());
+        let mut svgs = Vec::new();
+        for _ in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(Request::get("/captcha").body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            let body = ::axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            svgs.push(body);
+        }
+        assert_ne!(svgs[0], svgs[1]);
+    }
+}
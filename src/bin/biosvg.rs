@@ -0,0 +1,161 @@
+//! Generate captchas from the command line, enabled by the `cli` feature.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use biosvg::BiosvgBuilder;
+
+const USAGE: &str = "usage: biosvg [options]
+
+options:
+    --length <n>        length of the captcha text, defaults to 4
+    --difficulty <n>    number of noise lines, defaults to 6
+    --colors <list>     comma separated colors, defaults to a bright palette
+    --seed <n>          seed of the random generator
+    --count <n>         number of captchas to generate, defaults to 1
+    --out <file.svg>    where to write the svg, numbered when --count is more
+                        than 1, defaults to captcha.svg
+    --json              write the answer to a json file next to each svg
+                        instead of printing it
+    --help              print this message";
+
+const DEFAULT_COLORS: &str = "#0078D6,#aa3333,#f08012,#33aa00,#aa33aa";
+
+struct Options {
+    length: usize,
+    difficulty: u16,
+    colors: Vec<String>,
+    seed: Option<u64>,
+    count: usize,
+    out: PathBuf,
+    json: bool,
+}
+
+fn main() -> ExitCode {
+    let options = match parse(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("biosvg: {}\n\n{}", err, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match generate(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("biosvg: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// parse the arguments, `None` if help is asked for
+fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut options = Options {
+        length: 4,
+        difficulty: 6,
+        colors: split_colors(DEFAULT_COLORS),
+        seed: None,
+        count: 1,
+        out: PathBuf::from("captcha.svg"),
+        json: false,
+    };
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value of {}", arg))
+        };
+        match arg.as_str() {
+            "--length" => options.length = number(&arg, value()?)?,
+            "--difficulty" => options.difficulty = number(&arg, value()?)?,
+            "--colors" => options.colors = split_colors(&value()?),
+            "--seed" => options.seed = Some(number(&arg, value()?)?),
+            "--count" => options.count = number(&arg, value()?)?,
+            "--out" => options.out = PathBuf::from(value()?),
+            "--json" => options.json = true,
+            "--help" | "-h" => return Ok(None),
+            _ => return Err(format!("unknown option {}", arg)),
+        }
+    }
+    if options.colors.is_empty() {
+        return Err("no colors given".to_string());
+    }
+    Ok(Some(options))
+}
+
+fn number<T: std::str::FromStr>(arg: &str, value: String) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value {:?} of {}", value, arg))
+}
+
+fn split_colors(colors: &str) -> Vec<String> {
+    colors
+        .split(',')
+        .map(str::trim)
+        .filter(|color| !color.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn generate(options: &Options) -> Result<(), String> {
+    for i in 0..options.count {
+        let mut builder = BiosvgBuilder::new()
+            .length(options.length)
+            .difficulty(options.difficulty)
+            .colors(options.colors.clone());
+        if let Some(seed) = options.seed {
+            // every captcha of a batch gets its own seed
+            builder = builder.seed(seed.wrapping_add(i as u64));
+        }
+        let (answer, svg) = builder.build().map_err(|err| err.to_string())?;
+
+        let out = if options.count > 1 {
+            numbered(&options.out, i + 1)
+        } else {
+            options.out.clone()
+        };
+        write(&out, &svg)?;
+        if options.json {
+            let sidecar = format!(
+                "{{\"answer\":\"{}\",\"svg\":\"{}\"}}\n",
+                escape(&answer),
+                escape(&out.to_string_lossy())
+            );
+            write(&out.with_extension("json"), &sidecar)?;
+        } else {
+            println!("{}", answer);
+        }
+    }
+    Ok(())
+}
+
+/// `captcha.svg` as `captcha-3.svg`
+fn numbered(path: &Path, i: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(extension) => format!("{}-{}.{}", stem, i, extension.to_string_lossy()),
+        None => format!("{}-{}", stem, i),
+    };
+    path.with_file_name(name)
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+/// escape a string for a json string literal
+fn escape(text: &str) -> String {
+    text.chars()
+        .map(|ch| match ch {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            ch if ch.is_control() => format!("\\u{:04x}", ch as u32),
+            ch => ch.to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,227 @@
+use std::fmt;
+use std::time::Duration;
+
+use rand::Rng;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::Scene;
+
+/// CaptchaKind is what the captcha asks for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CaptchaKind {
+    /// the answer is the text shown in the image
+    #[default]
+    Text,
+    /// the image shows an expression like `3+7×2`, the answer is its value.
+    /// `length` sets the number of operands, at most 8.
+    Arithmetic,
+    /// the answer is a word out of the `words` of the builder, or a built-in
+    /// word list if none are set
+    Words,
+}
+
+/// Captcha is a generated captcha with its answer and metadata
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Captcha {
+    /// answer text of the captcha, its hash with `hash_answer`
+    pub answer: String,
+    /// rendered svg document
+    pub svg: String,
+    /// width of the svg canvas
+    pub width: f64,
+    /// height of the svg canvas
+    pub height: f64,
+    /// creation time in seconds since the unix epoch
+    pub created_at: u64,
+    /// expiry time in seconds since the unix epoch, `created_at` plus the
+    /// `ttl` of the builder
+    pub expires_at: u64,
+    /// random id of the captcha, unique even for seeded captchas
+    pub nonce: String,
+    /// wrong guesses its signed token allows, the `max_attempts` of the
+    /// builder, 0 for no limit
+    pub max_attempts: u32,
+    /// whether the answer is checked ignoring case, the `case_insensitive`
+    /// of the builder. it is signed into the token too.
+    pub case_insensitive: bool,
+    /// entropy of the answer in bits
+    pub(crate) entropy_bits: f64,
+    /// plaintext answer of a `hash_answer` captcha, for signing and the
+    /// challenge stores
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) plain_answer: Option<PlainAnswer>,
+    /// bounding boxes of the characters in svg coordinates, in answer order
+    #[cfg(feature = "debug-geometry")]
+    pub glyphs: Vec<GlyphBox>,
+}
+
+/// PlainAnswer is the plaintext behind a hashed answer, hidden from `Debug`
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct PlainAnswer(String);
+
+impl fmt::Debug for PlainAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PlainAnswer(..)")
+    }
+}
+
+/// GlyphBox is where a character landed in the svg, the axis aligned bounding
+/// box of its rotated glyph
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct GlyphBox {
+    /// the character
+    pub ch: char,
+    /// left edge
+    pub x: f64,
+    /// top edge
+    pub y: f64,
+    /// width of the box
+    pub w: f64,
+    /// height of the box
+    pub h: f64,
+    /// rotation of the glyph in radians
+    pub angle: f64,
+}
+
+impl GlyphBox {
+    /// whether the point is inside the box
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        (self.x..=self.x + self.w).contains(&x) && (self.y..=self.y + self.h).contains(&y)
+    }
+}
+
+/// ClickCaptcha asks to click one character out of several scattered ones
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ClickCaptcha {
+    /// the character to click
+    pub target: char,
+    /// rendered svg document
+    pub svg: String,
+    /// width of the svg canvas
+    pub width: f64,
+    /// height of the svg canvas
+    pub height: f64,
+    /// bounding boxes of all characters in svg coordinates
+    pub glyphs: Vec<GlyphBox>,
+    /// creation time in seconds since the unix epoch
+    pub created_at: u64,
+}
+
+impl ClickCaptcha {
+    pub(crate) fn new(svg: String, scene: Scene, target: char) -> ClickCaptcha {
+        ClickCaptcha {
+            target,
+            svg,
+            width: scene.width,
+            height: scene.height,
+            glyphs: scene.glyphs,
+            created_at: now(),
+        }
+    }
+
+    /// bounding box of the target character
+    pub fn target_box(&self) -> Option<&GlyphBox> {
+        self.glyphs.iter().find(|glyph| glyph.ch == self.target)
+    }
+
+    /// whether a click at (`x`, `y`) in svg coordinates hits the target
+    pub fn is_hit(&self, x: f64, y: f64) -> bool {
+        self.target_box().is_some_and(|glyph| glyph.contains(x, y))
+    }
+}
+
+impl Captcha {
+    /// captcha of a scene, valid for `ttl` and `max_attempts` wrong guesses.
+    /// `plain_answer` is the plaintext of a hashed answer.
+    pub(crate) fn new(
+        svg: String,
+        scene: Scene,
+        plain_answer: Option<String>,
+        ttl: Duration,
+        max_attempts: u32,
+        case_insensitive: bool,
+        entropy_bits: f64,
+    ) -> Captcha {
+        let created_at = now();
+        Captcha {
+            answer: scene.answer,
+            svg,
+            width: scene.width,
+            height: scene.height,
+            created_at,
+            expires_at: created_at.saturating_add(ttl.as_secs()),
+            nonce: nonce(),
+            max_attempts,
+            case_insensitive,
+            entropy_bits,
+            plain_answer: plain_answer.map(PlainAnswer),
+            #[cfg(feature = "debug-geometry")]
+            glyphs: scene.glyphs,
+        }
+    }
+
+    /// The answer the user input is checked against on the server, the
+    /// plaintext even if `hash_answer` made `answer` a hash. it is meant for
+    /// tokens and challenge stores, don't send it to the client.
+    pub fn plain_answer(&self) -> &str {
+        self.plain_answer
+            .as_ref()
+            .map_or(&self.answer, |answer| &answer.0)
+    }
+
+    /// whether the captcha has expired
+    pub fn is_expired(&self) -> bool {
+        now() > self.expires_at
+    }
+
+    /// Entropy of the answer in bits, log2 of the number of answers the
+    /// builder can generate: the charset size to the power of the length,
+    /// the words of a word captcha or the values of an arithmetic captcha.
+    /// a fixed text has none.
+    pub fn entropy_bits(&self) -> f64 {
+        self.entropy_bits
+    }
+}
+
+/// 128 random bits from the entropy generator as hex
+fn nonce() -> String {
+    entropy_rng()
+        .gen::<[u8; 16]>()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// generator of unseeded captchas, the thread local generator or straight
+/// getrandom in the browser
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+pub(crate) fn entropy_rng() -> rand::rngs::ThreadRng {
+    rand::thread_rng()
+}
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub(crate) fn entropy_rng() -> rand::rngs::StdRng {
+    rand::SeedableRng::from_entropy()
+}
+
+/// seconds since the unix epoch
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+pub(crate) fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+/// seconds since the unix epoch, `SystemTime` isn't available in the browser
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub(crate) fn now() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
@@ -0,0 +1,546 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use super::model::BuildError;
+
+/// ColorSpec is a stroke color of the captcha text
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(untagged))]
+pub enum ColorSpec {
+    /// a plain color, e.g. `#0078D6`
+    Solid(String),
+    /// a linear gradient through the colors, running across each character
+    Gradient(Vec<String>),
+}
+
+impl From<String> for ColorSpec {
+    fn from(color: String) -> Self {
+        ColorSpec::Solid(color)
+    }
+}
+
+impl From<&str> for ColorSpec {
+    fn from(color: &str) -> Self {
+        ColorSpec::Solid(color.to_string())
+    }
+}
+
+impl From<Vec<String>> for ColorSpec {
+    fn from(colors: Vec<String>) -> Self {
+        ColorSpec::Gradient(colors)
+    }
+}
+
+/// Color is an opaque rgb color, parsed from the css notations: `#rgb`,
+/// `#rrggbb`, `rgb(r, g, b)`, `hsl(h, s%, l%)` and the css color names. the
+/// alpha forms `#rgba`, `#rrggbbaa`, `rgba()` and `hsla()` only parse when
+/// they are opaque.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+/// the css color names
+const NAMED: [(&str, [u8; 3]); 148] = [
+    ("aliceblue", [240, 248, 255]),
+    ("antiquewhite", [250, 235, 215]),
+    ("aqua", [0, 255, 255]),
+    ("aquamarine", [127, 255, 212]),
+    ("azure", [240, 255, 255]),
+    ("beige", [245, 245, 220]),
+    ("bisque", [255, 228, 196]),
+    ("black", [0, 0, 0]),
+    ("blanchedalmond", [255, 235, 205]),
+    ("blue", [0, 0, 255]),
+    ("blueviolet", [138, 43, 226]),
+    ("brown", [165, 42, 42]),
+    ("burlywood", [222, 184, 135]),
+    ("cadetblue", [95, 158, 160]),
+    ("chartreuse", [127, 255, 0]),
+    ("chocolate", [210, 105, 30]),
+    ("coral", [255, 127, 80]),
+    ("cornflowerblue", [100, 149, 237]),
+    ("cornsilk", [255, 248, 220]),
+    ("crimson", [220, 20, 60]),
+    ("cyan", [0, 255, 255]),
+    ("darkblue", [0, 0, 139]),
+    ("darkcyan", [0, 139, 139]),
+    ("darkgoldenrod", [184, 134, 11]),
+    ("darkgray", [169, 169, 169]),
+    ("darkgreen", [0, 100, 0]),
+    ("darkgrey", [169, 169, 169]),
+    ("darkkhaki", [189, 183, 107]),
+    ("darkmagenta", [139, 0, 139]),
+    ("darkolivegreen", [85, 107, 47]),
+    ("darkorange", [255, 140, 0]),
+    ("darkorchid", [153, 50, 204]),
+    ("darkred", [139, 0, 0]),
+    ("darksalmon", [233, 150, 122]),
+    ("darkseagreen", [143, 188, 143]),
+    ("darkslateblue", [72, 61, 139]),
+    ("darkslategray", [47, 79, 79]),
+    ("darkslategrey", [47, 79, 79]),
+    ("darkturquoise", [0, 206, 209]),
+    ("darkviolet", [148, 0, 211]),
+    ("deeppink", [255, 20, 147]),
+    ("deepskyblue", [0, 191, 255]),
+    ("dimgray", [105, 105, 105]),
+    ("dimgrey", [105, 105, 105]),
+    ("dodgerblue", [30, 144, 255]),
+    ("firebrick", [178, 34, 34]),
+    ("floralwhite", [255, 250, 240]),
+    ("forestgreen", [34, 139, 34]),
+    ("fuchsia", [255, 0, 255]),
+    ("gainsboro", [220, 220, 220]),
+    ("ghostwhite", [248, 248, 255]),
+    ("gold", [255, 215, 0]),
+    ("goldenrod", [218, 165, 32]),
+    ("gray", [128, 128, 128]),
+    ("green", [0, 128, 0]),
+    ("greenyellow", [173, 255, 47]),
+    ("grey", [128, 128, 128]),
+    ("honeydew", [240, 255, 240]),
+    ("hotpink", [255, 105, 180]),
+    ("indianred", [205, 92, 92]),
+    ("indigo", [75, 0, 130]),
+    ("ivory", [255, 255, 240]),
+    ("khaki", [240, 230, 140]),
+    ("lavender", [230, 230, 250]),
+    ("lavenderblush", [255, 240, 245]),
+    ("lawngreen", [124, 252, 0]),
+    ("lemonchiffon", [255, 250, 205]),
+    ("lightblue", [173, 216, 230]),
+    ("lightcoral", [240, 128, 128]),
+    ("lightcyan", [224, 255, 255]),
+    ("lightgoldenrodyellow", [250, 250, 210]),
+    ("lightgray", [211, 211, 211]),
+    ("lightgreen", [144, 238, 144]),
+    ("lightgrey", [211, 211, 211]),
+    ("lightpink", [255, 182, 193]),
+    ("lightsalmon", [255, 160, 122]),
+    ("lightseagreen", [32, 178, 170]),
+    ("lightskyblue", [135, 206, 250]),
+    ("lightslategray", [119, 136, 153]),
+    ("lightslategrey", [119, 136, 153]),
+    ("lightsteelblue", [176, 196, 222]),
+    ("lightyellow", [255, 255, 224]),
+    ("lime", [0, 255, 0]),
+    ("limegreen", [50, 205, 50]),
+    ("linen", [250, 240, 230]),
+    ("magenta", [255, 0, 255]),
+    ("maroon", [128, 0, 0]),
+    ("mediumaquamarine", [102, 205, 170]),
+    ("mediumblue", [0, 0, 205]),
+    ("mediumorchid", [186, 85, 211]),
+    ("mediumpurple", [147, 112, 219]),
+    ("mediumseagreen", [60, 179, 113]),
+    ("mediumslateblue", [123, 104, 238]),
+    ("mediumspringgreen", [0, 250, 154]),
+    ("mediumturquoise", [72, 209, 204]),
+    ("mediumvioletred", [199, 21, 133]),
+    ("midnightblue", [25, 25, 112]),
+    ("mintcream", [245, 255, 250]),
+    ("mistyrose", [255, 228, 225]),
+    ("moccasin", [255, 228, 181]),
+    ("navajowhite", [255, 222, 173]),
+    ("navy", [0, 0, 128]),
+    ("oldlace", [253, 245, 230]),
+    ("olive", [128, 128, 0]),
+    ("olivedrab", [107, 142, 35]),
+    ("orange", [255, 165, 0]),
+    ("orangered", [255, 69, 0]),
+    ("orchid", [218, 112, 214]),
+    ("palegoldenrod", [238, 232, 170]),
+    ("palegreen", [152, 251, 152]),
+    ("paleturquoise", [175, 238, 238]),
+    ("palevioletred", [219, 112, 147]),
+    ("papayawhip", [255, 239, 213]),
+    ("peachpuff", [255, 218, 185]),
+    ("peru", [205, 133, 63]),
+    ("pink", [255, 192, 203]),
+    ("plum", [221, 160, 221]),
+    ("powderblue", [176, 224, 230]),
+    ("purple", [128, 0, 128]),
+    ("rebeccapurple", [102, 51, 153]),
+    ("red", [255, 0, 0]),
+    ("rosybrown", [188, 143, 143]),
+    ("royalblue", [65, 105, 225]),
+    ("saddlebrown", [139, 69, 19]),
+    ("salmon", [250, 128, 114]),
+    ("sandybrown", [244, 164, 96]),
+    ("seagreen", [46, 139, 87]),
+    ("seashell", [255, 245, 238]),
+    ("sienna", [160, 82, 45]),
+    ("silver", [192, 192, 192]),
+    ("skyblue", [135, 206, 235]),
+    ("slateblue", [106, 90, 205]),
+    ("slategray", [112, 128, 144]),
+    ("slategrey", [112, 128, 144]),
+    ("snow", [255, 250, 250]),
+    ("springgreen", [0, 255, 127]),
+    ("steelblue", [70, 130, 180]),
+    ("tan", [210, 180, 140]),
+    ("teal", [0, 128, 128]),
+    ("thistle", [216, 191, 216]),
+    ("tomato", [255, 99, 71]),
+    ("turquoise", [64, 224, 208]),
+    ("violet", [238, 130, 238]),
+    ("wheat", [245, 222, 179]),
+    ("white", [255, 255, 255]),
+    ("whitesmoke", [245, 245, 245]),
+    ("yellow", [255, 255, 0]),
+    ("yellowgreen", [154, 205, 50]),
+];
+
+impl Color {
+    pub const fn new(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+
+    /// WCAG 2 relative luminance
+    pub fn luminance(&self) -> f64 {
+        let linear = |c: u8| {
+            let c = c as f64 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    /// `rgb`, `rgba`, `rrggbb` or `rrggbbaa` hex digits and the alpha
+    fn from_hex(hex: &str) -> Option<(Color, f64)> {
+        // from_str_radix also takes a sign, `#+f+f+f` isn't a color
+        if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+        let rgba = match hex.len() {
+            3 | 4 => hex
+                .chars()
+                .map(|c| channel(&c.to_string().repeat(2)))
+                .collect::<Option<Vec<u8>>>()?,
+            6 | 8 => (0..hex.len() / 2)
+                .map(|i| channel(hex.get(i * 2..i * 2 + 2)?))
+                .collect::<Option<Vec<u8>>>()?,
+            _ => return None,
+        };
+        let alpha = rgba.get(3).map_or(1.0, |alpha| *alpha as f64 / 255.0);
+        Some((Color::new(rgba[0], rgba[1], rgba[2]), alpha))
+    }
+
+    /// `r, g, b` channels, each a number from 0 to 255 or a percentage, and
+    /// the alpha
+    fn from_rgb(args: &str) -> Option<(Color, f64)> {
+        let (args, alpha) = arguments(args)?;
+        let channels = args
+            .iter()
+            .map(|arg| match arg.strip_suffix('%') {
+                Some(percent) => number(percent, 0.0, 100.0).map(|p| p * 255.0 / 100.0),
+                None => number(arg, 0.0, 255.0),
+            })
+            .collect::<Option<Vec<f64>>>()?;
+        let [r, g, b] = channels[..] else {
+            return None;
+        };
+        Some((
+            Color::new(r.round() as u8, g.round() as u8, b.round() as u8),
+            opacity(alpha)?,
+        ))
+    }
+
+    /// `h, s%, l%` with the hue in degrees, and the alpha
+    fn from_hsl(args: &str) -> Option<(Color, f64)> {
+        let (args, alpha) = arguments(args)?;
+        let [h, s, l] = &args[..] else {
+            return None;
+        };
+        let h = h
+            .strip_suffix("deg")
+            .unwrap_or(h)
+            .trim()
+            .parse::<f64>()
+            .ok()?;
+        if !h.is_finite() {
+            return None;
+        }
+        let s = number(s.strip_suffix('%')?, 0.0, 100.0)? / 100.0;
+        let l = number(l.strip_suffix('%')?, 0.0, 100.0)? / 100.0;
+        Some((Color::hsl(h, s, l), opacity(alpha)?))
+    }
+
+    /// a color from its hue in degrees and its saturation and lightness
+    /// from 0 to 1
+    pub fn hsl(h: f64, s: f64, l: f64) -> Color {
+        let (s, l) = (s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+        let h = h.rem_euclid(360.0) / 30.0;
+        let a = s * l.min(1.0 - l);
+        let channel = |n: f64| {
+            let k = (n + h) % 12.0;
+            let c = l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0);
+            (c * 255.0).round() as u8
+        };
+        Color::new(channel(0.0), channel(8.0), channel(4.0))
+    }
+
+    /// hue in degrees, 0 for grays
+    pub fn hue(&self) -> f64 {
+        let [r, g, b] = [self.r, self.g, self.b].map(|c| c as f64 / 255.0);
+        let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+        let delta = max - min;
+        if delta == 0.0 {
+            return 0.0;
+        }
+        let sector = if max == r {
+            (g - b) / delta
+        } else if max == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+        (sector * 60.0).rem_euclid(360.0)
+    }
+}
+
+/// `n` colors of evenly spread hues starting opposite to the hue of
+/// `background`, each darkened or lightened until it reaches the minimum
+/// contrast to the background
+pub(crate) fn palette(background: Color, n: usize) -> Vec<Color> {
+    let dark = background.luminance() < 0.18;
+    let contrast = |color: Color| {
+        let (a, b) = (color.luminance(), background.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    };
+    (0..n)
+        .map(|i| {
+            let hue = background.hue() + 180.0 + 360.0 * i as f64 / n as f64;
+            let mut lightness = if dark { 0.7 } else { 0.4 };
+            let mut color = Color::hsl(hue, 0.7, lightness);
+            while contrast(color) < MIN_CONTRAST && (0.0..=1.0).contains(&lightness) {
+                lightness += if dark { 0.05 } else { -0.05 };
+                color = Color::hsl(hue, 0.7, lightness);
+            }
+            color
+        })
+        .collect()
+}
+
+/// split the arguments of a css function by commas or spaces, along with
+/// the alpha, a fourth argument after a comma or the one after a `/`
+fn arguments(args: &str) -> Option<(Vec<&str>, Option<&str>)> {
+    let args = args.strip_suffix(')')?;
+    if args.contains(',') {
+        let mut args = args.split(',').map(str::trim).collect::<Vec<&str>>();
+        let alpha = if args.len() == 4 { args.pop() } else { None };
+        Some((args, alpha))
+    } else {
+        let (args, alpha) = match args.split_once('/') {
+            Some((args, alpha)) => (args, Some(alpha)),
+            None => (args, None),
+        };
+        Some((args.split_whitespace().collect(), alpha))
+    }
+}
+
+/// an alpha from 0 to 1 or a percentage, opaque if there is none
+fn opacity(alpha: Option<&str>) -> Option<f64> {
+    match alpha {
+        None => Some(1.0),
+        Some(alpha) => match alpha.trim().strip_suffix('%') {
+            Some(percent) => number(percent, 0.0, 100.0).map(|p| p / 100.0),
+            None => number(alpha, 0.0, 1.0),
+        },
+    }
+}
+
+/// Parse a css color into its rgb channels and its alpha from 0 to 1,
+/// `transparent` is black with an alpha of 0. `currentColor` has no value of
+/// its own and doesn't parse.
+pub(crate) fn parse(color: &str) -> Option<(Color, f64)> {
+    let value = color.trim().to_ascii_lowercase();
+    let function = |names: [&str; 2]| {
+        names
+            .iter()
+            .find_map(|name| value.strip_prefix(name)?.strip_prefix('('))
+    };
+    if let Some(hex) = value.strip_prefix('#') {
+        Color::from_hex(hex)
+    } else if let Some(args) = function(["rgb", "rgba"]) {
+        Color::from_rgb(args)
+    } else if let Some(args) = function(["hsl", "hsla"]) {
+        Color::from_hsl(args)
+    } else if value == "transparent" {
+        Some((Color::new(0, 0, 0), 0.0))
+    } else {
+        NAMED
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, [r, g, b])| (Color::new(*r, *g, *b), 1.0))
+    }
+}
+
+/// a number within `min..=max`
+fn number(s: &str, min: f64, max: f64) -> Option<f64> {
+    s.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|n| (min..=max).contains(n))
+}
+
+impl FromStr for Color {
+    type Err = BuildError;
+
+    fn from_str(color: &str) -> Result<Self, Self::Err> {
+        match parse(color) {
+            Some((parsed, alpha)) if alpha >= 1.0 => Ok(parsed),
+            _ => Err(BuildError::InvalidColor(color.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Check that a color is one svg accepts, a `Color`, a translucent color or
+/// `currentColor`, so it is written into the svg as a valid attribute.
+pub(crate) fn check(color: &str) -> Result<(), BuildError> {
+    if parse(color).is_some() || color.trim().eq_ignore_ascii_case("currentcolor") {
+        Ok(())
+    } else {
+        Err(BuildError::InvalidColor(color.to_string()))
+    }
+}
+
+/// minimum contrast ratio of normal text in WCAG 2
+pub(crate) const MIN_CONTRAST: f64 = 4.5;
+
+impl ColorSpec {
+    /// the lowest contrast ratio of the colors to `background`, `None` if a
+    /// color is not an opaque `Color`
+    pub fn contrast(&self, background: &str) -> Option<f64> {
+        let colors = match self {
+            ColorSpec::Solid(color) => std::slice::from_ref(color),
+            ColorSpec::Gradient(colors) => colors.as_slice(),
+        };
+        colors
+            .iter()
+            .map(|color| contrast_ratio(color, background))
+            .try_fold(f64::INFINITY, |min, ratio| Some(min.min(ratio?)))
+    }
+}
+
+/// WCAG 2 contrast ratio of two colors, from 1 to 21
+pub(crate) fn contrast_ratio(a: &str, b: &str) -> Option<f64> {
+    let luminance = |color: &str| color.parse::<Color>().ok().map(|color| color.luminance());
+    let (a, b) = (luminance(a)?, luminance(b)?);
+    Some((a.max(b) + 0.05) / (a.min(b) + 0.05))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BiosvgBuilder, PathError};
+
+    #[test]
+    fn color_parsing() {
+        let from_str = |color: &str| color.parse::<Color>();
+        assert_eq!(from_str("#0078D6"), Ok(Color::new(0, 0x78, 0xd6)));
+        assert_eq!(from_str("#fa0"), Ok(Color::new(255, 170, 0)));
+        assert_eq!(from_str("rgb(0, 120, 214)"), Ok(Color::new(0, 120, 214)));
+        assert_eq!(from_str("rgb(100% 0% 50%)"), Ok(Color::new(255, 0, 128)));
+        assert_eq!(from_str("hsl(120, 100%, 25%)"), Ok(Color::new(0, 128, 0)));
+        assert_eq!(from_str("hsl(-120deg 100% 50%)"), Ok(Color::new(0, 0, 255)));
+        assert_eq!(from_str(" Teal "), Ok(Color::new(0, 128, 128)));
+        assert_eq!(from_str("CornflowerBlue"), Ok(Color::new(100, 149, 237)));
+        assert_eq!(from_str("rebeccapurple"), Ok(Color::new(102, 51, 153)));
+        assert_eq!(from_str("#0078d6ff"), Ok(Color::new(0, 0x78, 0xd6)));
+        assert_eq!(
+            from_str("rgba(0, 120, 214, 1)"),
+            Ok(Color::new(0, 120, 214))
+        );
+        assert_eq!(
+            from_str("hsl(120 100% 25% / 100%)"),
+            Ok(Color::new(0, 128, 0))
+        );
+        // translucent colors are valid in svg but not an opaque Color
+        for translucent in [
+            "#0078d680",
+            "#fa08",
+            "rgba(0, 120, 214, 0.5)",
+            "rgb(0 120 214 / 50%)",
+            "hsla(120, 100%, 25%, 0.2)",
+            "transparent",
+        ] {
+            assert!(from_str(translucent).is_err(), "{}", translucent);
+            assert_eq!(check(translucent), Ok(()), "{}", translucent);
+        }
+        assert_eq!(
+            parse("#0078d680"),
+            Some((Color::new(0, 0x78, 0xd6), 128.0 / 255.0))
+        );
+        assert_eq!(check("currentColor"), Ok(()));
+        assert_eq!(Color::new(0, 0x78, 0xd6).to_string(), "#0078d6");
+        for invalid in [
+            "#12",
+            "#ggg",
+            "#+f+f+f",
+            "#-1-1-1",
+            "rgb(256, 0, 0)",
+            "rgb(1, 2)",
+            "hsl(0, 50, 50)",
+            "rgba(0, 0, 0, 2)",
+            "rgb(0 0 0 / )",
+            "#12345",
+            "bluish",
+        ] {
+            assert_eq!(
+                from_str(invalid),
+                Err(BuildError::InvalidColor(invalid.to_string()))
+            );
+        }
+        assert!(BiosvgBuilder::new()
+            .length(4)
+            .colors(vec![
+                "rgb(0, 120, 214)".to_string(),
+                "hsl(0, 50%, 40%)".to_string(),
+                "cornflowerblue".to_string(),
+                "rgba(0, 0, 0, 0.6)".to_string(),
+                "currentColor".to_string(),
+            ])
+            .background("transparent")
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn auto_colors() {
+        for background in ["#ffffff", "#1e1e1e", "hsl(200, 60%, 45%)", "#ffeb3b"] {
+            let builder = BiosvgBuilder::new().length(4).auto_colors(background, 5);
+            assert_eq!(builder.colors.len(), 5);
+            let mut distinct = builder.colors.clone();
+            distinct.dedup();
+            assert_eq!(distinct.len(), 5);
+            for color in &builder.colors {
+                let ratio = contrast_ratio(color, background).unwrap();
+                assert!(ratio >= MIN_CONTRAST, "{color} on {background}");
+            }
+            assert!(builder.build().is_ok());
+        }
+        assert!(matches!(
+            BiosvgBuilder::new()
+                .length(4)
+                .auto_colors("nope", 5)
+                .build(),
+            Err(PathError::Build(BuildError::InvalidColor(_)))
+        ));
+    }
+}
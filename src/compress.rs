@@ -0,0 +1,70 @@
+use std::io::Write;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use super::captcha::Captcha;
+use super::SVG_CONTENT_TYPE;
+
+/// `Content-Encoding` of svgz documents
+pub const SVGZ_CONTENT_ENCODING: &str = "gzip";
+
+/// Headers to serve an svgz document with, the content type stays
+/// `image/svg+xml` and browsers decompress it by its encoding.
+pub fn svgz_headers() -> [(&'static str, &'static str); 2] {
+    [
+        ("content-type", SVG_CONTENT_TYPE),
+        ("content-encoding", SVGZ_CONTENT_ENCODING),
+    ]
+}
+
+/// Compress an svg document to gzip compressed svgz.
+pub fn to_svgz(svg: &str) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    // writing to a vector doesn't fail
+    encoder
+        .write_all(svg.as_bytes())
+        .expect("failed to compress svg");
+    encoder.finish().expect("failed to compress svg")
+}
+
+impl Captcha {
+    /// the svg of the captcha compressed to svgz, serve it with the
+    /// `svgz_headers`
+    pub fn to_svgz(&self) -> Vec<u8> {
+        to_svgz(&self.svg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BiosvgBuilder;
+
+    #[test]
+    fn svgz() {
+        use std::io::Read;
+
+        let captcha = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(6)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .build_captcha()
+            .unwrap();
+        let svgz = captcha.to_svgz();
+        assert_eq!(svgz[..2], [0x1f, 0x8b]);
+        assert!(svgz.len() * 2 < captcha.svg.len());
+        let mut svg = String::new();
+        flate2::read::GzDecoder::new(svgz.as_slice())
+            .read_to_string(&mut svg)
+            .unwrap();
+        assert_eq!(svg, captcha.svg);
+        assert_eq!(
+            svgz_headers(),
+            [
+                ("content-type", "image/svg+xml"),
+                ("content-encoding", "gzip")
+            ]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! C bindings to call the generator from other languages through the shared
+//! library, enabled by the `ffi` feature. `include/biosvg.h` declares them,
+//! keep it in sync with the functions here.
+
+use std::ffi::{c_char, CStr, CString};
+use std::panic::catch_unwind;
+use std::ptr;
+
+use super::BiosvgBuilder;
+
+/// BiosvgCaptcha is a generated captcha as nul terminated utf-8 strings, free
+/// it with `biosvg_free`
+#[repr(C)]
+#[derive(Debug)]
+pub struct BiosvgCaptcha {
+    /// answer text of the captcha
+    pub answer: *mut c_char,
+    /// rendered svg document
+    pub svg: *mut c_char,
+}
+
+/// Generate a captcha of `length` characters with `difficulty` noise lines
+/// in the `colors_len` colors at `colors`, returns null if the colors are
+/// missing or invalid or the captcha can't be generated.
+///
+/// # Safety
+///
+/// `colors` must point to `colors_len` valid nul terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn biosvg_generate(
+    length: usize,
+    difficulty: u16,
+    colors: *const *const c_char,
+    colors_len: usize,
+) -> *mut BiosvgCaptcha {
+    if colors.is_null() || colors_len == 0 {
+        return ptr::null_mut();
+    }
+    let colors = std::slice::from_raw_parts(colors, colors_len)
+        .iter()
+        .map(|color| {
+            if color.is_null() {
+                return None;
+            }
+            CStr::from_ptr(*color).to_str().ok().map(str::to_string)
+        })
+        .collect::<Option<Vec<String>>>();
+    let Some(colors) = colors else {
+        return ptr::null_mut();
+    };
+    // panics must not unwind into the caller
+    let captcha = catch_unwind(|| {
+        BiosvgBuilder::new()
+            .length(length)
+            .difficulty(difficulty)
+            .colors(colors)
+            .build()
+    });
+    let Ok(Ok((answer, svg))) = captcha else {
+        return ptr::null_mut();
+    };
+    match (CString::new(answer), CString::new(svg)) {
+        (Ok(answer), Ok(svg)) => Box::into_raw(Box::new(BiosvgCaptcha {
+            answer: answer.into_raw(),
+            svg: svg.into_raw(),
+        })),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Free a captcha of `biosvg_generate`, null is ignored.
+///
+/// # Safety
+///
+/// `captcha` must be null or returned by `biosvg_generate` and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn biosvg_free(captcha: *mut BiosvgCaptcha) {
+    if captcha.is_null() {
+        return;
+    }
+    let captcha = Box::from_raw(captcha);
+    drop(CString::from_raw(captcha.answer));
+    drop(CString::from_raw(captcha.svg));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ffi_generate() {
+        let colors = [
+            CString::new("#0078D6").unwrap(),
+            CString::new("#aa3333").unwrap(),
+        ];
+        let pointers = colors
+            .iter()
+            .map(|color| color.as_ptr())
+            .collect::<Vec<_>>();
+        unsafe {
+            let captcha = biosvg_generate(4, 6, pointers.as_ptr(), pointers.len());
+            assert!(!captcha.is_null());
+            assert_eq!(CStr::from_ptr((*captcha).answer).to_bytes().len(), 4);
+            assert!(CStr::from_ptr((*captcha).svg)
+                .to_str()
+                .unwrap()
+                .starts_with("<svg"));
+            biosvg_free(captcha);
+
+            assert!(biosvg_generate(4, 6, pointers.as_ptr(), 0).is_null());
+            biosvg_free(std::ptr::null_mut());
+        }
+    }
+}
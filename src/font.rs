@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "ttf")]
+use super::model::Command;
+use super::model::{CommandType, Path, PathError};
+use super::resource::font_glyphs;
+#[cfg(feature = "ttf")]
+use super::resource::OPERATOR_TABLE;
+#[cfg(feature = "font-serif")]
+use super::resource::SERIF_GLYPHS;
+
+/// glyphs are scaled so that one em is this many units, the built-in glyphs
+/// use about the same size
+#[cfg(feature = "ttf")]
+const UNITS_PER_EM: f64 = 1000.0;
+
+/// BuiltinFont is one of the embedded glyph sets, sets other than `Mono` are
+/// behind cargo features to keep the binary small. the variants depend on the
+/// features, so matches need a wildcard arm
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BuiltinFont {
+    /// single stroke monospace glyphs, always available
+    #[default]
+    Mono,
+    /// outlines of a serif typeface, requires the `font-serif` feature
+    #[cfg(feature = "font-serif")]
+    Serif,
+}
+
+/// Font is a table of glyph paths used to draw the captcha text
+#[derive(Debug, Clone, Default)]
+pub struct Font {
+    glyphs: HashMap<char, Path>,
+}
+
+impl Font {
+    /// the built-in single stroke font
+    pub fn builtin() -> Font {
+        Font {
+            glyphs: font_glyphs()
+                .map(|(ch, glyph)| (*ch, glyph.path()))
+                .collect(),
+        }
+    }
+
+    /// one of the embedded glyph sets
+    pub fn from_builtin(builtin: BuiltinFont) -> Font {
+        match builtin {
+            BuiltinFont::Mono => Font::builtin(),
+            #[cfg(feature = "font-serif")]
+            BuiltinFont::Serif => Font {
+                glyphs: SERIF_GLYPHS
+                    .iter()
+                    .map(|(ch, glyph)| (*ch, glyph.path()))
+                    .collect(),
+            },
+        }
+    }
+
+    /// Convert the outlines of all printable ascii characters and the
+    /// arithmetic operators of a TrueType or OpenType font, characters
+    /// missing in the font are skipped.
+    #[cfg(feature = "ttf")]
+    pub fn from_ttf_bytes(data: &[u8]) -> Result<Font, PathError> {
+        let chars = (' '..='~')
+            .chain(OPERATOR_TABLE.chars())
+            .collect::<String>();
+        Font::from_ttf_bytes_with_chars(data, &chars)
+    }
+
+    /// Convert the outlines of the given characters of a TrueType or OpenType
+    /// font, characters missing in the font are skipped.
+    #[cfg(feature = "ttf")]
+    pub fn from_ttf_bytes_with_chars(data: &[u8], chars: &str) -> Result<Font, PathError> {
+        let face = ttf_parser::Face::parse(data, 0)
+            .map_err(|err| PathError::FontError(err.to_string()))?;
+        let scale = UNITS_PER_EM / face.units_per_em() as f64;
+        let mut glyphs = HashMap::new();
+        for ch in chars.chars() {
+            let Some(id) = face.glyph_index(ch) else {
+                continue;
+            };
+            let mut outline = Outline {
+                scale,
+                commands: Vec::new(),
+                start: (0.0, 0.0),
+            };
+            if face.outline_glyph(id, &mut outline).is_some() && !outline.commands.is_empty() {
+                glyphs.insert(ch, Path::from_commands(outline.commands));
+            }
+        }
+        if glyphs.is_empty() {
+            return Err(PathError::FontError("no glyph outlines found".to_string()));
+        }
+        Ok(Font { glyphs })
+    }
+
+    /// Add the glyph of a character from svg path data, for example exported
+    /// from a font editor. all path commands are supported, the y axis points
+    /// down like in svg. glyphs keep the size of the path data, the built-in
+    /// glyphs are about 700 units high. path data that draws nothing, empty
+    /// or only moves, is a `ParseError`.
+    pub fn with_glyph(mut self, ch: char, path: &str) -> Result<Font, PathError> {
+        let glyph = Path::parse(path)?;
+        if glyph
+            .commands
+            .iter()
+            .all(|command| command.command_type == CommandType::Move)
+        {
+            return Err(PathError::ParseError);
+        }
+        self.glyphs.insert(ch, glyph);
+        Ok(self)
+    }
+
+    /// whether the font has a glyph for the character
+    pub fn contains(&self, ch: char) -> bool {
+        self.glyphs.contains_key(&ch)
+    }
+
+    /// all characters with a glyph, in no particular order
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.glyphs.keys().copied()
+    }
+
+    pub(crate) fn glyph(&self, ch: char) -> Option<&Path> {
+        self.glyphs.get(&ch)
+    }
+}
+
+/// Collects glyph outlines as commands, flipping the y axis of the font.
+#[cfg(feature = "ttf")]
+struct Outline {
+    scale: f64,
+    commands: Vec<Command>,
+    start: (f64, f64),
+}
+
+#[cfg(feature = "ttf")]
+impl Outline {
+    fn point(&self, x: f32, y: f32) -> (f64, f64) {
+        (x as f64 * self.scale, -y as f64 * self.scale)
+    }
+}
+
+#[cfg(feature = "ttf")]
+impl ttf_parser::OutlineBuilder for Outline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.start = (x, y);
+        self.commands.push(Command::new(x, y, CommandType::Move));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.commands.push(Command::new(x, y, CommandType::LineTo));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (cx, cy) = self.point(x1, y1);
+        let (x, y) = self.point(x, y);
+        self.commands
+            .push(Command::new(x, y, CommandType::QuadTo(cx, cy)));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (c1x, c1y) = self.point(x1, y1);
+        let (c2x, c2y) = self.point(x2, y2);
+        let (x, y) = self.point(x, y);
+        self.commands
+            .push(Command::new(x, y, CommandType::CubicTo(c1x, c1y, c2x, c2y)));
+    }
+
+    fn close(&mut self) {
+        let (x, y) = self.start;
+        self.commands.push(Command::new(x, y, CommandType::Close));
+    }
+}
@@ -0,0 +1,263 @@
+//! Number and path data formatting of the svg output.
+//!
+//! The default format writes every number with its full precision and the
+//! path data with absolute commands. the compact format of
+//! `BiosvgBuilder::optimize` rounds the numbers, merges collinear lines and
+//! writes relative commands with as few separators as the svg grammar
+//! allows.
+
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+
+use super::model::{Command, CommandType};
+
+/// Format is how numbers and path data are written
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Format {
+    /// decimal places of the numbers, all of them if `None`
+    pub precision: Option<u8>,
+    /// relative commands, merged lines and minimal separators
+    pub compact: bool,
+}
+
+impl Format {
+    /// Write a number with the precision of the format, without trailing
+    /// zeros. the compact format also drops the leading zero of fractions.
+    pub fn number(&self, value: f64) -> String {
+        let Some(precision) = self.precision else {
+            return value.to_string();
+        };
+        let mut number = format!("{:.*}", precision as usize, value);
+        if number.contains('.') {
+            number.truncate(number.trim_end_matches('0').trim_end_matches('.').len());
+        }
+        if number == "-0" {
+            number = "0".to_string();
+        }
+        if self.compact {
+            if let Some(fraction) = number.strip_prefix("0.") {
+                number = format!(".{}", fraction);
+            } else if let Some(fraction) = number.strip_prefix("-0.") {
+                number = format!("-.{}", fraction);
+            }
+        }
+        number
+    }
+
+    /// a value rounded to the precision of the format
+    fn round(&self, value: f64) -> f64 {
+        match self.precision {
+            Some(precision) => {
+                let scale = 10f64.powi(precision as i32);
+                (value * scale).round() / scale
+            }
+            None => value,
+        }
+    }
+
+    /// Write the `d` attribute of a path.
+    pub fn write_path_data<W: Write + ?Sized>(
+        &self,
+        out: &mut W,
+        commands: &[Command],
+    ) -> fmt::Result {
+        if !self.compact {
+            for (i, command) in commands.iter().enumerate() {
+                if i > 0 {
+                    out.write_char(' ')?;
+                }
+                self.write_absolute(out, command)?;
+            }
+            return Ok(());
+        }
+
+        let mut data = PathData {
+            out,
+            command: None,
+            fraction: false,
+        };
+        // the current point and the start of the sub-path, rounded so the
+        // relative offsets add up to the rounded absolute points
+        let (mut x, mut y) = (0.0, 0.0);
+        let (mut start_x, mut start_y) = (0.0, 0.0);
+        for command in self.merge_lines(commands) {
+            let (to_x, to_y) = (self.round(command.x), self.round(command.y));
+            let (dx, dy) = (to_x - x, to_y - y);
+            let relative = |cx: f64, cy: f64| [self.round(cx) - x, self.round(cy) - y];
+            match command.command_type {
+                CommandType::Move => {
+                    data.push(self, 'm', &[dx, dy])?;
+                    (start_x, start_y) = (to_x, to_y);
+                }
+                CommandType::LineTo if dx == 0.0 => data.push(self, 'v', &[dy])?,
+                CommandType::LineTo if dy == 0.0 => data.push(self, 'h', &[dx])?,
+                CommandType::LineTo => data.push(self, 'l', &[dx, dy])?,
+                CommandType::QuadTo(cx, cy) => {
+                    let [cx, cy] = relative(cx, cy);
+                    data.push(self, 'q', &[cx, cy, dx, dy])?;
+                }
+                CommandType::CubicTo(c1x, c1y, c2x, c2y) => {
+                    let [c1x, c1y] = relative(c1x, c1y);
+                    let [c2x, c2y] = relative(c2x, c2y);
+                    data.push(self, 'c', &[c1x, c1y, c2x, c2y, dx, dy])?;
+                }
+                CommandType::Arc {
+                    rx,
+                    ry,
+                    rotation,
+                    large_arc,
+                    sweep,
+                } => {
+                    let flags = [large_arc as u8 as f64, sweep as u8 as f64];
+                    data.push(self, 'a', &[rx, ry, rotation, flags[0], flags[1], dx, dy])?;
+                }
+                CommandType::Close => {
+                    data.push(self, 'z', &[])?;
+                    (x, y) = (start_x, start_y);
+                    continue;
+                }
+            }
+            (x, y) = (to_x, to_y);
+        }
+        Ok(())
+    }
+
+    /// Write a command with absolute coordinates.
+    pub fn write_absolute<W: Write + ?Sized>(&self, out: &mut W, command: &Command) -> fmt::Result {
+        let (letter, numbers) = match command.command_type {
+            CommandType::Move => ('M', vec![command.x, command.y]),
+            CommandType::LineTo => ('L', vec![command.x, command.y]),
+            CommandType::Close => return out.write_char('Z'),
+            CommandType::QuadTo(cx, cy) => ('Q', vec![cx, cy, command.x, command.y]),
+            CommandType::CubicTo(c1x, c1y, c2x, c2y) => {
+                ('C', vec![c1x, c1y, c2x, c2y, command.x, command.y])
+            }
+            CommandType::Arc {
+                rx,
+                ry,
+                rotation,
+                large_arc,
+                sweep,
+            } => {
+                let flags = [large_arc as u8 as f64, sweep as u8 as f64];
+                let numbers = vec![rx, ry, rotation, flags[0], flags[1], command.x, command.y];
+                ('A', numbers)
+            }
+        };
+        out.write_char(letter)?;
+        for number in numbers {
+            out.write_char(' ')?;
+            self.write_number(out, number)?;
+        }
+        Ok(())
+    }
+
+    /// Write a number like `number` without allocating at full precision.
+    pub fn write_number<W: Write + ?Sized>(&self, out: &mut W, value: f64) -> fmt::Result {
+        match self.precision {
+            None => write!(out, "{}", value),
+            Some(_) => out.write_str(&self.number(value)),
+        }
+    }
+
+    /// Replace runs of lines in the same direction by one line, a point is
+    /// dropped when it is off the merged line by less than the rounding.
+    fn merge_lines(&self, commands: &[Command]) -> Vec<Command> {
+        let tolerance = self
+            .precision
+            .map_or(0.0, |p| 0.5 * 10f64.powi(-(p as i32)));
+        let mut merged: Vec<Command> = Vec::with_capacity(commands.len());
+        // the current point before each merged command
+        let mut before: Vec<(f64, f64)> = Vec::with_capacity(commands.len());
+        for command in commands {
+            let current = merged.last().map_or((0.0, 0.0), |last| (last.x, last.y));
+            if let (Some(last), Some(&(x0, y0))) = (merged.last_mut(), before.last()) {
+                let lines = last.command_type == CommandType::LineTo
+                    && command.command_type == CommandType::LineTo;
+                let (dx1, dy1) = (last.x - x0, last.y - y0);
+                let (dx2, dy2) = (command.x - x0, command.y - y0);
+                let length = dx2.hypot(dy2);
+                let forward = dx1 * (command.x - last.x) + dy1 * (command.y - last.y) >= 0.0;
+                if lines && forward && length > 0.0 {
+                    let distance = (dx1 * dy2 - dy1 * dx2).abs() / length;
+                    if distance <= tolerance {
+                        *last = command.clone();
+                        continue;
+                    }
+                }
+            }
+            before.push(current);
+            merged.push(command.clone());
+        }
+        merged
+    }
+}
+
+/// PathData writes compact path data
+struct PathData<'a, W: Write + ?Sized> {
+    out: &'a mut W,
+    /// the last command letter written
+    command: Option<char>,
+    /// whether the last number has a decimal point
+    fraction: bool,
+}
+
+impl<W: Write + ?Sized> PathData<'_, W> {
+    /// Write a command and its numbers, the letter is left out when it
+    /// repeats the previous command or is a line after a move.
+    fn push(&mut self, format: &Format, command: char, numbers: &[f64]) -> fmt::Result {
+        let mut separate = true;
+        let implicit = match self.command {
+            Some('m') => command == 'l',
+            Some(previous) => previous == command && command != 'z',
+            None => false,
+        };
+        if !implicit {
+            self.out.write_char(command)?;
+            self.command = Some(command);
+            separate = false;
+        }
+        for number in numbers {
+            let number = format.number(*number);
+            // a sign or a second decimal point starts a new number
+            let starts_new = number.starts_with('-') || (self.fraction && number.starts_with('.'));
+            if separate && !starts_new {
+                self.out.write_char(' ')?;
+            }
+            self.fraction = number.contains('.');
+            self.out.write_str(&number)?;
+            separate = true;
+        }
+        Ok(())
+    }
+}
+
+/// Escape the xml special characters of text written into an element or an
+/// attribute value, every user supplied string goes through here or
+/// `cdata`, so it can't break out of its context.
+pub fn escape(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(text);
+    }
+    let mut escaped = String::with_capacity(text.len() + 8);
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(ch),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// Text for a `<![CDATA[...]]>` section, a `]]>` in the text is split over
+/// two sections.
+pub fn cdata(text: &str) -> Cow<'_, str> {
+    match text.contains("]]>") {
+        true => Cow::Owned(text.replace("]]>", "]]]]><![CDATA[>")),
+        false => Cow::Borrowed(text),
+    }
+}
@@ -0,0 +1,124 @@
+use std::io;
+
+use once_cell::sync::OnceCell;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::captcha::entropy_rng;
+use super::model::{BuildError, PathError};
+use super::{BiosvgBuilder, Captcha};
+
+/// generator shared by `global` and `generate`
+static GLOBAL: OnceCell<CaptchaGenerator> = OnceCell::new();
+
+/// CaptchaGenerator is a validated builder made by `BiosvgBuilder::compile`,
+/// it generates captchas through a shared reference so one generator can
+/// serve many threads
+#[derive(Debug, Clone)]
+pub struct CaptchaGenerator {
+    pub(crate) builder: BiosvgBuilder,
+    /// entropy of the answers, fixed once the builder is compiled
+    pub(crate) entropy_bits: f64,
+}
+
+impl CaptchaGenerator {
+    /// generate a captcha along with its metadata, a seeded generator
+    /// generates the same captcha every time
+    pub fn generate(&self) -> Result<Captcha, PathError> {
+        match self.builder.seed {
+            Some(seed) => self.generate_with_rng(&mut StdRng::seed_from_u64(seed)),
+            None => self.generate_with_rng(&mut entropy_rng()),
+        }
+    }
+
+    /// generate a captcha with the given random generator, the `seed`
+    /// setting is ignored
+    pub fn generate_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Captcha, PathError> {
+        let (scene, plain_answer) = self.builder.hashed_scene(rng)?;
+        Ok(Captcha::new(
+            scene.to_svg(),
+            scene,
+            plain_answer,
+            self.builder.ttl,
+            self.builder.max_attempts,
+            self.builder.case_insensitive,
+            self.entropy_bits,
+        ))
+    }
+
+    /// generate a captcha and stream its svg into `writer`, returns the
+    /// answer. wrap unbuffered writers in a `BufWriter`. like
+    /// `BiosvgBuilder::write_svg`, the content of obfuscated captchas is
+    /// built in memory first.
+    pub fn write_svg<W: io::Write>(&self, writer: W) -> Result<String, PathError> {
+        let scene = match self.builder.seed {
+            Some(seed) => self
+                .builder
+                .checked_scene(&mut StdRng::seed_from_u64(seed))?,
+            None => self.builder.checked_scene(&mut entropy_rng())?,
+        };
+        scene.write_to(writer)?;
+        Ok(scene.answer)
+    }
+
+    /// the settings the generator was compiled from
+    pub fn builder(&self) -> &BiosvgBuilder {
+        &self.builder
+    }
+}
+
+/// The generator shared by the whole program, initialized on first use from
+/// the `BIOSVG_LENGTH`, `BIOSVG_DIFFICULTY` and comma separated
+/// `BIOSVG_COLORS` environment variables unless `set_global` was called
+/// before. unset variables default to 4 characters, 6 noise lines and a
+/// palette of 5 colors.
+pub fn global() -> Result<&'static CaptchaGenerator, PathError> {
+    GLOBAL.get_or_try_init(|| from_env(|name| std::env::var(name).ok())?.compile())
+}
+
+/// Initialize the shared generator with the settings of `builder`, fails if
+/// it is already initialized.
+pub fn set_global(builder: BiosvgBuilder) -> Result<&'static CaptchaGenerator, PathError> {
+    let generator = builder.compile()?;
+    GLOBAL
+        .set(generator)
+        .map_err(|_| BuildError::GlobalInitialized)?;
+    global()
+}
+
+/// Generate a captcha with the shared generator of `global`.
+pub fn generate() -> Result<Captcha, PathError> {
+    global()?.generate()
+}
+
+/// the builder configured by the environment variables of `global`
+pub(crate) fn from_env(var: impl Fn(&str) -> Option<String>) -> Result<BiosvgBuilder, PathError> {
+    let length = match var("BIOSVG_LENGTH") {
+        Some(length) => length
+            .trim()
+            .parse()
+            .map_err(|_| BuildError::InvalidEnv("BIOSVG_LENGTH"))?,
+        None => 4,
+    };
+    let difficulty = match var("BIOSVG_DIFFICULTY") {
+        Some(difficulty) => difficulty
+            .trim()
+            .parse()
+            .map_err(|_| BuildError::InvalidEnv("BIOSVG_DIFFICULTY"))?,
+        None => 6,
+    };
+    let colors = match var("BIOSVG_COLORS") {
+        Some(colors) => colors
+            .split(',')
+            .map(|color| color.trim().to_string())
+            .collect(),
+        None => ["#0078D6", "#aa3333", "#f08012", "#33aa00", "#aa33aa"]
+            .iter()
+            .map(|color| color.to_string())
+            .collect(),
+    };
+    Ok(BiosvgBuilder::new()
+        .length(length)
+        .difficulty(difficulty)
+        .colors(colors))
+}
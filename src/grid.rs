@@ -0,0 +1,167 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use super::captcha::now;
+use super::Scene;
+
+/// GridCaptcha is an object selection captcha, a grid of icons where every
+/// cell showing the `target` icon has to be selected
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct GridCaptcha {
+    /// name of the icon to look for, e.g. `star`
+    pub target: String,
+    /// rendered svg document
+    pub svg: String,
+    /// number of rows of the grid
+    pub rows: usize,
+    /// number of columns of the grid
+    pub cols: usize,
+    /// indices of the cells showing the target, row by row from the top left,
+    /// the answer of the captcha
+    pub cells: Vec<usize>,
+    /// width of the svg canvas
+    pub width: f64,
+    /// height of the svg canvas
+    pub height: f64,
+    /// left edge of the grid in svg coordinates
+    pub grid_x: f64,
+    /// top edge of the grid in svg coordinates
+    pub grid_y: f64,
+    /// width and height of a cell in svg coordinates
+    pub cell_size: f64,
+    /// creation time in seconds since the unix epoch
+    pub created_at: u64,
+}
+
+impl GridCaptcha {
+    /// The scene has the box of the first cell as its only glyph box, which
+    /// locates the grid after the scene was fit into the output size.
+    pub(crate) fn new(
+        svg: String,
+        scene: Scene,
+        rows: usize,
+        cols: usize,
+        cells: Vec<usize>,
+    ) -> GridCaptcha {
+        let first = scene.glyphs[0];
+        GridCaptcha {
+            target: scene.answer,
+            svg,
+            rows,
+            cols,
+            cells,
+            width: scene.width,
+            height: scene.height,
+            grid_x: first.x,
+            grid_y: first.y,
+            cell_size: first.w,
+            created_at: now(),
+        }
+    }
+
+    /// question to show with the grid
+    pub fn prompt(&self) -> String {
+        let target = self.target.replace('_', " ");
+        let article = match target.starts_with(['a', 'e', 'i', 'o', 'u']) {
+            true => "an",
+            false => "a",
+        };
+        format!("select all cells with {} {}", article, target)
+    }
+
+    /// index of the cell at (`x`, `y`) in svg coordinates, if inside the grid
+    pub fn cell_at(&self, x: f64, y: f64) -> Option<usize> {
+        let col = ((x - self.grid_x) / self.cell_size).floor();
+        let row = ((y - self.grid_y) / self.cell_size).floor();
+        if (0.0..self.cols as f64).contains(&col) && (0.0..self.rows as f64).contains(&row) {
+            Some(row as usize * self.cols + col as usize)
+        } else {
+            None
+        }
+    }
+
+    /// whether exactly the cells showing the target were selected, in any
+    /// order
+    pub fn verify(&self, selected: &[usize]) -> bool {
+        let mut selected = selected.to_vec();
+        selected.sort_unstable();
+        selected.dedup();
+        selected == self.cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::resource::{confusable_icons, ICON_TABLE};
+    use crate::tests::assert_well_formed;
+    use crate::{grid_icons, BiosvgBuilder};
+
+    #[test]
+    fn grid_captcha() {
+        let builder = BiosvgBuilder::new()
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .grid(3, 4)
+            .seed(5);
+        let grid = builder.clone().build_grid().unwrap();
+        assert_eq!((grid.rows, grid.cols), (3, 4));
+        assert!(ICON_TABLE.contains(&grid.target.as_str()));
+        assert!(!grid.cells.is_empty() && grid.cells.len() <= 6);
+        assert!(grid.cells.iter().all(|cell| *cell < 12));
+        assert!(grid.prompt().ends_with(&grid.target.replace('_', " ")));
+        assert_well_formed(&grid.svg);
+        let again = builder.clone().build_grid().unwrap();
+        assert_eq!(
+            (again.svg, again.cells),
+            (grid.svg.clone(), grid.cells.clone())
+        );
+
+        let mut selected = grid.cells.clone();
+        selected.reverse();
+        assert!(grid.verify(&selected));
+        selected.pop();
+        assert!(!grid.verify(&selected));
+        assert!(!grid.verify(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]));
+
+        // cells are found from click positions, also after fitting
+        let grid = builder.width(480).padding(20).build_grid().unwrap();
+        let cell = grid.cell_size;
+        assert_eq!(
+            grid.cell_at(grid.grid_x + cell * 0.5, grid.grid_y + cell * 0.5),
+            Some(0)
+        );
+        assert_eq!(
+            grid.cell_at(grid.grid_x + cell * 3.5, grid.grid_y + cell * 2.5),
+            Some(11)
+        );
+        assert_eq!(grid.cell_at(grid.grid_x - 1.0, grid.grid_y), None);
+        assert_eq!(grid.cell_at(grid.grid_x + cell * 4.5, grid.grid_y), None);
+        assert!((grid.grid_x - 20.0).abs() < 1e-9);
+        assert!((cell * 4.0 - 440.0).abs() < 1e-9);
+
+        assert!(BiosvgBuilder::new()
+            .colors(vec!["#0078D6".to_string()])
+            .grid(1, 1)
+            .build_grid()
+            .is_err());
+
+        // other cells never show an icon confusable with the target
+        assert!(confusable_icons("diamond", "square"));
+        assert!(confusable_icons("chevrons", "arrow"));
+        assert!(!confusable_icons("square", "square"));
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut targets = std::collections::HashSet::new();
+        for _ in 0..500 {
+            let (target, names, cells) = grid_icons(&mut rng, 12);
+            targets.insert(target);
+            for (i, name) in names.iter().enumerate() {
+                assert_eq!(*name == target, cells.contains(&i));
+                assert!(!confusable_icons(target, name), "{} {}", target, name);
+            }
+        }
+        assert!(targets.contains("square") && targets.contains("arrow"));
+    }
+}
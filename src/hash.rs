@@ -0,0 +1,109 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+use super::verify::{normalize, VerifyOptions};
+
+/// HashAlgo is how `BiosvgBuilder::hash_answer` hashes the answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum HashAlgo {
+    /// hex encoded sha256 of the salt followed by the answer
+    Sha256 { salt: String },
+}
+
+impl HashAlgo {
+    /// Hash an answer normalized like in `verify` with the default
+    /// `VerifyOptions` and `case_insensitive`: trimmed, with the default
+    /// confusables and ignoring case if asked to. pass the
+    /// `case_insensitive` of the builder, which `Captcha::case_insensitive`
+    /// records, and check the user input with `verify` and the same flag.
+    pub fn hash(&self, answer: &str, case_insensitive: bool) -> String {
+        let options = VerifyOptions {
+            case_insensitive,
+            ..VerifyOptions::default()
+        };
+        let answer = normalize(answer, &options);
+        match self {
+            HashAlgo::Sha256 { salt } => Sha256::new()
+                .chain_update(salt.as_bytes())
+                .chain_update(answer.as_bytes())
+                .finalize()
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect(),
+        }
+    }
+
+    /// Check the user input against a hash of the answer, the input is
+    /// hashed like the answer with the same `case_insensitive` and the
+    /// hashes are compared in constant time.
+    pub fn verify(&self, hash: &str, user_input: &str, case_insensitive: bool) -> bool {
+        self.hash(user_input, case_insensitive)
+            .as_bytes()
+            .ct_eq(hash.as_bytes())
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BiosvgBuilder;
+
+    #[test]
+    fn hashed_answer() {
+        let algo = HashAlgo::Sha256 {
+            salt: "salt".to_string(),
+        };
+        assert_eq!(
+            HashAlgo::Sha256 {
+                salt: String::new()
+            }
+            .hash("abc", false),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        let (hash, svg) = BiosvgBuilder::new()
+            .text("AbcD")
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .hash_answer(algo.clone())
+            .build()
+            .unwrap();
+        assert_eq!(hash, algo.hash("AbcD", false));
+        assert_ne!(hash, algo.hash("abcd", false));
+        assert!(algo.verify(&hash, " AbcD ", false));
+        assert!(!algo.verify(&hash, "abcd", false));
+        assert!(!algo.verify("", "AbcD", false));
+        assert!(svg.starts_with("<svg"));
+
+        // the same rule as verify, confusables match too
+        let hash = algo.hash("SOIB", false);
+        assert!(algo.verify(&hash, "5018", false));
+        assert!(!algo.verify(&hash, "sOIB", false));
+    }
+
+    #[test]
+    fn hashed_answer_case_insensitive() {
+        let algo = HashAlgo::Sha256 {
+            salt: "salt".to_string(),
+        };
+        let captcha = BiosvgBuilder::new()
+            .text("ABCD")
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .case_insensitive(true)
+            .hash_answer(algo.clone())
+            .build_captcha()
+            .unwrap();
+        assert!(captcha.case_insensitive);
+        for input in ["ABCD", "abcd", " AbcD "] {
+            assert!(
+                algo.verify(&captcha.answer, input, captcha.case_insensitive),
+                "{}",
+                input
+            );
+        }
+        assert!(!algo.verify(&captcha.answer, "ABCE", captcha.case_insensitive));
+    }
+}
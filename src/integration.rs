@@ -0,0 +1,134 @@
+//! State and token transport shared by the axum and actix-web integrations.
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use super::model::PathError;
+use super::token::UsedTokens;
+use super::{BiosvgBuilder, CaptchaGenerator};
+
+/// header the signed token is sent in and expected back
+pub const TOKEN_HEADER: &str = "x-captcha-token";
+/// cookie the signed token is sent in, used if the token header is missing
+pub const TOKEN_COOKIE: &str = "biosvg_token";
+/// header the user input is expected in
+pub const ANSWER_HEADER: &str = "x-captcha-answer";
+/// form field the signed token is read from if the header and cookie are
+/// missing
+pub const TOKEN_FIELD: &str = "captcha_token";
+/// form field the user input is read from if the answer header is missing
+pub const ANSWER_FIELD: &str = "captcha_answer";
+
+/// CaptchaState is the generator captchas are generated with and the secret
+/// their tokens are signed with. use it as axum router state or provide it
+/// with `FromRef`, or register it as actix-web `web::Data`. clones share the
+/// generator and the `UsedTokens` the tokens are checked with, so a token
+/// passes once
+#[derive(Debug, Clone)]
+pub struct CaptchaState {
+    pub(crate) generator: Arc<CaptchaGenerator>,
+    pub(crate) secret: Arc<[u8]>,
+    pub(crate) used: Arc<UsedTokens>,
+    pub(crate) secure: bool,
+}
+
+impl CaptchaState {
+    /// compile the builder once, fails like `BiosvgBuilder::compile` if the
+    /// settings are invalid so a bad config is caught at startup
+    pub fn new(builder: BiosvgBuilder, secret: &[u8]) -> Result<CaptchaState, PathError> {
+        Ok(CaptchaState::from_generator(builder.compile()?, secret))
+    }
+
+    /// serve captchas of a compiled generator
+    pub fn from_generator(generator: CaptchaGenerator, secret: &[u8]) -> CaptchaState {
+        CaptchaState {
+            generator: Arc::new(generator),
+            secret: Arc::from(secret),
+            used: Arc::new(UsedTokens::new()),
+            secure: true,
+        }
+    }
+
+    /// set whether the token cookie is `Secure`, defaults to true. browsers
+    /// only send secure cookies over https, turn it off to serve plain http
+    /// in development.
+    pub fn secure_cookie(mut self, secure: bool) -> CaptchaState {
+        self.secure = secure;
+        self
+    }
+}
+
+/// `Set-Cookie` value of the token cookie, kept for `max_age` seconds.
+/// browsers only send `Secure` cookies over https.
+pub(crate) fn token_cookie(token: &str, max_age: u64, secure: bool) -> String {
+    format!(
+        "{}={}; Path=/; Max-Age={}; HttpOnly; SameSite=Strict{}",
+        TOKEN_COOKIE,
+        token,
+        max_age,
+        if secure { "; Secure" } else { "" }
+    )
+}
+
+/// Token and answer of a request. the token is read from the token header,
+/// the token cookie or the token field of the form, the answer from the
+/// answer header or the answer field. `header` reads a header of the
+/// request, `cookies` are the values of its `Cookie` headers and `form` is
+/// its `application/x-www-form-urlencoded` body, if any.
+pub(crate) fn credentials<'a>(
+    header: impl Fn(&str) -> Option<&'a str>,
+    cookies: impl Iterator<Item = &'a str>,
+    form: Option<&'a str>,
+) -> Option<(Cow<'a, str>, Cow<'a, str>)> {
+    let field = |name| form.and_then(|form| form_field(form, name));
+    let token = match header(TOKEN_HEADER).or_else(|| cookie(cookies, TOKEN_COOKIE)) {
+        Some(token) => Cow::Borrowed(token),
+        None => field(TOKEN_FIELD)?,
+    };
+    let answer = match header(ANSWER_HEADER) {
+        Some(answer) => Cow::Borrowed(answer),
+        None => field(ANSWER_FIELD)?,
+    };
+    Some((token, answer))
+}
+
+fn cookie<'a>(cookies: impl Iterator<Item = &'a str>, name: &str) -> Option<&'a str> {
+    cookies.flat_map(|value| value.split(';')).find_map(|pair| {
+        let (key, value) = pair.trim().split_once('=')?;
+        (key == name).then_some(value)
+    })
+}
+
+/// Value of a field of an urlencoded form, the first if it repeats.
+fn form_field<'a>(form: &'a str, name: &str) -> Option<Cow<'a, str>> {
+    form.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        (decode(key) == name).then(|| decode(value))
+    })
+}
+
+/// Decode `+` and percent escapes of a form, invalid escapes are kept.
+fn decode(text: &str) -> Cow<'_, str> {
+    if !text.contains(['+', '%']) {
+        return Cow::Borrowed(text);
+    }
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|hex| bytes[i] == b'%' && hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match (bytes[i], escaped) {
+            (_, Some(byte)) => {
+                out.push(byte);
+                i += 2;
+            }
+            (b'+', None) => out.push(b' '),
+            (byte, None) => out.push(byte),
+        }
+        i += 1;
+    }
+    Cow::Owned(String::from_utf8_lossy(&out).into_owned())
+}
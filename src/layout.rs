@@ -0,0 +1,126 @@
+//! Placement of the transformed glyphs on the canvas.
+//!
+//! Glyphs are placed by the bounding boxes of their transformed commands, so
+//! the canvas always fits them whatever their rotation and scale.
+
+use rand::Rng;
+
+use super::model::{Bounds, Path};
+
+/// How the glyphs are placed on the canvas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// side by side on a line, to be read as text
+    Line,
+    /// scattered over a higher canvas, for click captchas
+    Scatter,
+}
+
+/// Placement is where the glyph centers go and the canvas they fit in
+#[derive(Debug, Clone, PartialEq)]
+pub struct Placement {
+    /// offsets of the glyphs, in order
+    pub positions: Vec<(f64, f64)>,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// LineLayout places glyphs side by side, all lengths are relative to the
+/// glyph height
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineLayout {
+    /// gap between the boxes of adjacent glyphs
+    pub spacing: f64,
+    /// fraction of the narrower of two adjacent glyphs they may overlap by
+    pub overlap: f64,
+    /// height of the band the glyphs are randomly displaced in vertically
+    pub jitter: f64,
+}
+
+impl LineLayout {
+    /// Place the glyphs left to right, a glyph starts where the previous one
+    /// ends plus the spacing minus the allowed overlap, with the baselines of
+    /// all glyphs on one line before the jitter. a negative spacing
+    /// never makes glyphs overlap by more than `overlap`. the canvas has a
+    /// margin of 0.55 * height on both sides and is at least 1.5 * height
+    /// high.
+    pub fn place<R: Rng + ?Sized>(&self, rng: &mut R, paths: &[Path], height: f64) -> Placement {
+        let bounds = paths.iter().map(Path::bounds).collect::<Vec<Bounds>>();
+        let margin = height * 0.55;
+        let spacing = (self.spacing * height).max(0.0);
+        let band = self.jitter * height;
+
+        let mut positions = Vec::with_capacity(bounds.len());
+        let mut right = margin;
+        let mut previous: Option<&Bounds> = None;
+        for (path, bounds) in paths.iter().zip(&bounds) {
+            let left = match previous {
+                Some(previous) => {
+                    right + spacing - self.overlap * previous.width().min(bounds.width())
+                }
+                None => right,
+            };
+            let y = if band > 0.0 {
+                rng.gen_range(-band / 2.0..=band / 2.0)
+            } else {
+                0.0
+            };
+            positions.push((left - bounds.min_x, y - path.baseline));
+            right = left + bounds.width();
+            previous = Some(bounds);
+        }
+
+        // center the displaced boxes vertically, with room for the strokes
+        let top = positions
+            .iter()
+            .zip(&bounds)
+            .map(|((_, y), bounds)| y + bounds.min_y)
+            .fold(f64::INFINITY, f64::min);
+        let bottom = positions
+            .iter()
+            .zip(&bounds)
+            .map(|((_, y), bounds)| y + bounds.max_y)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let canvas_height = (height * 1.5).max(bottom - top + height * 0.1);
+        let shift = canvas_height / 2.0 - (top + bottom) / 2.0;
+        for (_, y) in &mut positions {
+            *y += shift;
+        }
+        Placement {
+            positions,
+            width: right + margin,
+            height: canvas_height,
+        }
+    }
+}
+
+/// Scatter the glyphs over a canvas 3 times as high as the glyphs, each glyph
+/// in its own column as wide as the widest glyph so no two glyphs overlap.
+pub fn scatter<R: Rng + ?Sized>(rng: &mut R, paths: &[Path], height: f64) -> Placement {
+    let bounds = paths.iter().map(Path::bounds).collect::<Vec<Bounds>>();
+    let cell = bounds.iter().map(Bounds::width).fold(0.0, f64::max);
+    let tallest = bounds.iter().map(Bounds::height).fold(0.0, f64::max);
+    let margin = height * 0.55;
+    let canvas_height = (height * 3.0).max(tallest + height * 0.1);
+    let positions = bounds
+        .iter()
+        .enumerate()
+        .map(|(i, bounds)| {
+            let x = margin + cell * (i as f64 + 0.5) - bounds.center().0;
+            // keep the whole box on the canvas
+            let min_y = height * 0.05 - bounds.min_y;
+            let max_y = canvas_height - height * 0.05 - bounds.max_y;
+            let y = if min_y < max_y {
+                rng.gen_range(min_y..=max_y)
+            } else {
+                canvas_height / 2.0 - bounds.center().1
+            };
+            (x, y)
+        })
+        .collect();
+    Placement {
+        positions,
+        width: margin * 2.0 + cell * paths.len() as f64,
+        height: canvas_height,
+    }
+}
@@ -1,14 +1,15 @@
 //! Captcha based on SVG.
-//! 
+//!
 //! ## Original idea
-//! 
+//!
 //! [SVG绘制原理与验证码](https://blog.woooo.tech/posts/svg_1/)
-//! 
+//!
 //! ## Usage
-//! 
+//!
 //! `cargo add biosvg`
-//! 
+//!
 //! ```rust
+//! # use biosvg::BiosvgBuilder;
 //! let (answer, svg) = BiosvgBuilder::new()
 //!     .length(4)
 //!     .difficulty(6)
@@ -25,20 +26,242 @@
 //! println!("svg: {}", svg);
 //! ```
 
+#[cfg(feature = "biosvg-actix")]
+pub mod actix;
+mod adaptive;
+mod aria;
+mod arithmetic;
+#[cfg(feature = "audio")]
+mod audio;
+#[cfg(feature = "biosvg-axum")]
+pub mod axum;
+mod captcha;
+mod color;
+#[cfg(feature = "compress")]
+mod compress;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod font;
+mod format;
+mod generator;
+mod grid;
+#[cfg(feature = "hash")]
+mod hash;
+#[cfg(any(feature = "biosvg-axum", feature = "biosvg-actix"))]
+mod integration;
+mod layout;
 mod model;
+mod noise;
+mod obfuscate;
+mod pool;
+mod preset;
+#[cfg(feature = "raster")]
+mod raster;
 mod resource;
-use model::Command;
+mod slider;
+#[cfg(feature = "store")]
+mod store;
+#[cfg(feature = "token")]
+mod token;
+mod verify;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub use adaptive::DifficultyController;
+pub use aria::AriaOptions;
+#[cfg(feature = "audio")]
+pub use audio::Voice;
+pub use captcha::{Captcha, CaptchaKind, ClickCaptcha, GlyphBox};
+pub use color::{Color, ColorSpec};
+#[cfg(feature = "compress")]
+pub use compress::{svgz_headers, to_svgz, SVGZ_CONTENT_ENCODING};
+pub use font::{BuiltinFont, Font};
+pub use generator::{generate, global, set_global, CaptchaGenerator};
+pub use grid::GridCaptcha;
+#[cfg(feature = "hash")]
+pub use hash::HashAlgo;
+pub use model::{AnimationStyle, BuildError, PathError, RenderMode, StrokeWidth};
+pub use noise::NoiseStyle;
+pub use pool::CaptchaPool;
+pub use preset::{AccessibilityLevel, Charset, Difficulty};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::{thread_rng, Rng};
+use rand::{Rng, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+pub use slider::SliderCaptcha;
+use std::fmt;
+use std::io;
+use std::ops::{Range, RangeInclusive};
+use std::time::Duration;
+#[cfg(feature = "redis")]
+pub use store::RedisChallengeStore;
+#[cfg(feature = "store")]
+pub use store::{ChallengeStore, MemoryChallengeStore, StoreError};
+#[cfg(feature = "token")]
+pub use token::{verify_token, TokenError, UsedTokens};
+pub use verify::{default_confusables, verify, VerifyOptions};
 
-use resource::{FONT_PATHS, FONT_TABLE};
+use captcha::entropy_rng;
+use format::Format;
+use layout::{Layout, LineLayout};
+use resource::{confusable_icons, font_glyph, AMBIGUOUS_CHARS, ICON_TABLE, WORD_TABLE};
 
-/// BiosvgBuilder is a builder for generating svg captcha with random text
-#[derive(Debug, Clone, Default)]
+/// `Content-Type` of svg documents
+pub const SVG_CONTENT_TYPE: &str = "image/svg+xml";
+
+/// names of the built-in icons of `BiosvgBuilder::build_icon` and grid
+/// captchas, e.g. `star`, `arrow` or `cat`
+pub fn icon_names() -> &'static [&'static str] {
+    ICON_TABLE
+}
+
+/// BiosvgBuilder is a builder for generating svg captcha with random text.
+/// with the `serde` feature it can be loaded from a config file, missing
+/// settings take their defaults and fonts and voices are not serialized.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct BiosvgBuilder {
     length: usize,
+    kind: CaptchaKind,
     difficulty: u16,
     colors: Vec<String>,
+    charset: Charset,
+    exclude_chars: String,
+    text: Option<String>,
+    words: Vec<String>,
+    seed: Option<u64>,
+    case_insensitive: bool,
+    homoglyph_safe: bool,
+    min_entropy_bits: Option<f64>,
+    noise_style: NoiseStyle,
+    noise_length_range: Option<(f64, f64)>,
+    noise_stroke_width_range: Option<(f64, f64)>,
+    dots: u16,
+    noise_lines: u16,
+    noise_curves: u16,
+    decoys: u16,
+    occlusion: u16,
+    traps: u16,
+    grid: (usize, usize),
+    wave_amplitude: f64,
+    wave_frequency: f64,
+    rotation_range: (f64, f64),
+    scale_range: (f64, f64),
+    skew_range: (f64, f64),
+    split: bool,
+    scramble: bool,
+    nesting: usize,
+    obfuscate: bool,
+    split_range: (usize, usize),
+    char_spacing: Option<f64>,
+    overlap: f64,
+    vertical_jitter: f64,
+    min_glyph_size: f64,
+    output_width: Option<u32>,
+    output_height: Option<u32>,
+    padding: u32,
+    background: Vec<String>,
+    char_colors: Vec<ColorSpec>,
+    noise_colors: Vec<String>,
+    distinct_adjacent_colors: bool,
+    dark_colors: Vec<String>,
+    dark_background: Option<String>,
+    min_contrast: Option<(String, f64)>,
+    stroke_width: StrokeWidth,
+    opacity_range: (f64, f64),
+    dash: f64,
+    animation: Option<AnimationStyle>,
+    css_classes: bool,
+    css_style: Option<String>,
+    aria: Option<AriaOptions>,
+    id_prefix: String,
+    random_id_prefix: bool,
+    optimize: Option<u8>,
+    precision: Option<u8>,
+    render_mode: RenderMode,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    fonts: Vec<Font>,
+    accessibility: AccessibilityLevel,
+    ttl: Duration,
+    max_attempts: u32,
+    #[cfg(feature = "hash")]
+    hash_answer: Option<HashAlgo>,
+    #[cfg(feature = "audio")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    voice: Option<Voice>,
+}
+
+impl Default for BiosvgBuilder {
+    fn default() -> Self {
+        BiosvgBuilder {
+            length: 0,
+            kind: CaptchaKind::Text,
+            difficulty: 0,
+            colors: Vec::new(),
+            charset: Charset::Alphanumeric,
+            exclude_chars: String::new(),
+            text: None,
+            words: Vec::new(),
+            seed: None,
+            case_insensitive: false,
+            homoglyph_safe: false,
+            min_entropy_bits: None,
+            noise_style: NoiseStyle::default(),
+            noise_length_range: None,
+            noise_stroke_width_range: None,
+            dots: 0,
+            noise_lines: 0,
+            noise_curves: 0,
+            decoys: 0,
+            occlusion: 0,
+            traps: 0,
+            grid: (3, 3),
+            wave_amplitude: 0.0,
+            wave_frequency: 1.0,
+            rotation_range: (-0.2, 0.2 * std::f64::consts::PI),
+            scale_range: (0.8, 1.2),
+            skew_range: (-0.2, 0.2),
+            split: true,
+            scramble: false,
+            nesting: 0,
+            obfuscate: false,
+            split_range: (2, 4),
+            char_spacing: None,
+            overlap: 0.0,
+            vertical_jitter: 0.1,
+            min_glyph_size: 0.3,
+            output_width: None,
+            output_height: None,
+            padding: 0,
+            background: Vec::new(),
+            char_colors: Vec::new(),
+            noise_colors: Vec::new(),
+            distinct_adjacent_colors: false,
+            dark_colors: Vec::new(),
+            dark_background: None,
+            min_contrast: None,
+            stroke_width: StrokeWidth::Auto,
+            opacity_range: (1.0, 1.0),
+            dash: 0.0,
+            animation: None,
+            css_classes: false,
+            css_style: None,
+            aria: None,
+            id_prefix: "biosvg".to_string(),
+            random_id_prefix: false,
+            optimize: None,
+            precision: None,
+            render_mode: RenderMode::Stroke,
+            fonts: Vec::new(),
+            accessibility: AccessibilityLevel::Standard,
+            ttl: Duration::from_secs(300),
+            max_attempts: 0,
+            #[cfg(feature = "hash")]
+            hash_answer: None,
+            #[cfg(feature = "audio")]
+            voice: None,
+        }
+    }
 }
 
 impl BiosvgBuilder {
@@ -53,12 +276,390 @@ impl BiosvgBuilder {
         self
     }
 
-    /// set difficulty of captcha, `difficulty` number of noise lines will be added
+    /// set what the captcha asks for, defaults to `CaptchaKind::Text`
+    pub fn kind(mut self, kind: CaptchaKind) -> BiosvgBuilder {
+        self.kind = kind;
+        self
+    }
+
+    /// set difficulty of captcha, `difficulty` noise strokes in the
+    /// `noise_style` will be added. `noise_lines`, `noise_curves` and
+    /// `noise_dots` add noise independently of it.
     pub fn difficulty(mut self, difficulty: u16) -> BiosvgBuilder {
         self.difficulty = difficulty;
         self
     }
 
+    /// set the shape of the noise strokes, defaults to `NoiseStyle::Curves`
+    pub fn noise_style(mut self, noise_style: NoiseStyle) -> BiosvgBuilder {
+        self.noise_style = noise_style;
+        self
+    }
+
+    /// set the range of the noise stroke lengths relative to the character
+    /// height, the larger diameter for `NoiseStyle::Arcs`. strokes longer
+    /// than the canvas cross all of it. defaults to `0.5..1.5` for lines,
+    /// `1.0..2.5` for curves, `0.6..2.4` for arcs and `0.5..1.5` for mimic
+    /// strokes.
+    pub fn noise_line_length_range(mut self, range: Range<f64>) -> BiosvgBuilder {
+        self.noise_length_range = Some((range.start, range.end));
+        self
+    }
+
+    /// set the range of the noise stroke widths relative to the character
+    /// stroke width, defaults to 2/3 and to 1 for `NoiseStyle::Mimic`.
+    pub fn noise_stroke_width_range(mut self, range: Range<f64>) -> BiosvgBuilder {
+        self.noise_stroke_width_range = Some((range.start, range.end));
+        self
+    }
+
+    /// sprinkle `count` small dots in random colors across the canvas
+    pub fn dots(mut self, count: u16) -> BiosvgBuilder {
+        self.dots = count;
+        self
+    }
+
+    /// add `count` straight noise lines on top of the `difficulty` noise,
+    /// defaults to 0.
+    pub fn noise_lines(mut self, count: u16) -> BiosvgBuilder {
+        self.noise_lines = count;
+        self
+    }
+
+    /// add `count` curved noise strokes on top of the `difficulty` noise,
+    /// defaults to 0.
+    pub fn noise_curves(mut self, count: u16) -> BiosvgBuilder {
+        self.noise_curves = count;
+        self
+    }
+
+    /// sprinkle `count` small dots across the canvas, the same as `dots`.
+    /// defaults to 0.
+    pub fn noise_dots(self, count: u16) -> BiosvgBuilder {
+        self.dots(count)
+    }
+
+    /// draw `count` extra characters that aren't part of the answer over the
+    /// text, like the text but in the noise colors. the answer keeps its
+    /// length. `AccessibilityLevel::High` draws no decoys.
+    pub fn decoys(mut self, count: u16) -> BiosvgBuilder {
+        self.decoys = count;
+        self
+    }
+
+    /// draw `count` small bars and blobs in the background color over the
+    /// text, each hides a bit of a stroke that people fill in easily but OCR
+    /// doesn't. needs a single `background` color, `AccessibilityLevel::High`
+    /// draws no occluders.
+    pub fn occlusion(mut self, count: u16) -> BiosvgBuilder {
+        self.occlusion = count;
+        self
+    }
+
+    /// draw `count` invisible characters that aren't part of the answer over
+    /// the text, to poison bots that read the path data instead of the
+    /// image. traps have zero opacity, or the background color under the
+    /// other paths if there's a single `background` color and no dots.
+    pub fn traps(mut self, count: u16) -> BiosvgBuilder {
+        self.traps = count;
+        self
+    }
+
+    /// bend each character along a sine wave with a random phase, `amplitude`
+    /// is relative to the character height and `frequency` is the number of
+    /// periods across the character width. disabled when `amplitude` is 0.
+    /// the amplitude must be finite and not negative, the frequency finite
+    /// and positive.
+    pub fn wave(mut self, amplitude: f64, frequency: f64) -> BiosvgBuilder {
+        self.wave_amplitude = amplitude;
+        self.wave_frequency = frequency;
+        self
+    }
+
+    /// set the range of the random rotation of each character in radians,
+    /// defaults to `-0.2..=0.2π`. use a small range for nearly upright glyphs.
+    pub fn rotation_range(mut self, min_rad: f64, max_rad: f64) -> BiosvgBuilder {
+        self.rotation_range = (min_rad, max_rad);
+        self
+    }
+
+    /// set the range of the random scale of each character, defaults to
+    /// `0.8..1.2`. the range must be positive so glyphs never collapse or flip.
+    pub fn scale_range(mut self, range: Range<f64>) -> BiosvgBuilder {
+        self.scale_range = (range.start, range.end);
+        self
+    }
+
+    /// set the range of the random shear of each character, x moves by the
+    /// factor times y and y by another factor times x. defaults to
+    /// `-0.2..0.2`, an empty range like `0.0..0.0` disables shearing.
+    pub fn skew_range(mut self, range: Range<f64>) -> BiosvgBuilder {
+        self.skew_range = (range.start, range.end);
+        self
+    }
+
+    /// set the gap between characters relative to the character height,
+    /// defaults to `0.4 / length`
+    pub fn char_spacing(mut self, spacing: f64) -> BiosvgBuilder {
+        self.char_spacing = Some(spacing);
+        self
+    }
+
+    /// let adjacent characters overlap by a fraction of their width in
+    /// `0.0..1.0`, overlapping strokes make segmentation harder. defaults to 0.
+    pub fn overlap(mut self, overlap: f64) -> BiosvgBuilder {
+        self.overlap = overlap;
+        self
+    }
+
+    /// displace every character vertically at random within a band of this
+    /// height relative to the character height, so the text doesn't sit on
+    /// one baseline. the canvas grows to keep the characters inside.
+    /// defaults to 0.1.
+    pub fn vertical_jitter(mut self, jitter: f64) -> BiosvgBuilder {
+        self.vertical_jitter = jitter;
+        self
+    }
+
+    /// scale up characters that are smaller than this fraction of the em
+    /// height of the font in both directions, e.g. `-` or `.` of a ttf font,
+    /// so they stay readable. the em height is the typical height of the
+    /// characters of the charset, and the text is laid out at least as high.
+    /// defaults to 0.3.
+    pub fn min_glyph_size(mut self, min_glyph_size: f64) -> BiosvgBuilder {
+        self.min_glyph_size = min_glyph_size;
+        self
+    }
+
+    /// scale the captcha to the given width, the height keeps the aspect
+    /// ratio unless it is set too. must not be 0
+    pub fn width(mut self, width: u32) -> BiosvgBuilder {
+        self.output_width = Some(width);
+        self
+    }
+
+    /// scale the captcha to the given height, the width keeps the aspect
+    /// ratio unless it is set too. must not be 0
+    pub fn height(mut self, height: u32) -> BiosvgBuilder {
+        self.output_height = Some(height);
+        self
+    }
+
+    /// add padding around the captcha, inside the requested width and height.
+    /// it must leave room for the captcha, less than half of each of them
+    pub fn padding(mut self, padding: u32) -> BiosvgBuilder {
+        self.padding = padding;
+        self
+    }
+
+    /// fill the canvas with an opaque background color instead of leaving it
+    /// transparent
+    pub fn background(mut self, color: &str) -> BiosvgBuilder {
+        self.background = vec![color.to_string()];
+        self
+    }
+
+    /// fill the canvas with a linear gradient of the colors in a random
+    /// direction, a subtle gradient makes background removal harder
+    pub fn background_gradient(mut self, colors: Vec<String>) -> BiosvgBuilder {
+        self.background = colors;
+        self
+    }
+
+    /// set the stroke width of the characters, noise strokes are 2/3 as thick
+    /// unless `noise_stroke_width_range` is set or the noise mimics glyphs.
+    /// defaults to `StrokeWidth::Auto`
+    pub fn stroke_width(mut self, stroke_width: StrokeWidth) -> BiosvgBuilder {
+        self.stroke_width = stroke_width;
+        self
+    }
+
+    /// set the range of the random opacity of every stroke in `0.0..=1.0`,
+    /// varying intensity makes thresholding the image less effective.
+    /// defaults to opaque strokes.
+    pub fn opacity_range(mut self, range: Range<f64>) -> BiosvgBuilder {
+        self.opacity_range = (range.start, range.end);
+        self
+    }
+
+    /// split every character stroke into pieces of a random number of
+    /// segments, so the glyphs aren't drawn as one path each. disable it for
+    /// whole glyph paths, e.g. to animate them with css. defaults to true.
+    pub fn split(mut self, split: bool) -> BiosvgBuilder {
+        self.split = split;
+        self
+    }
+
+    /// reverse random strokes and pack the strokes of different characters
+    /// into the same `<path>`, so the order of the elements doesn't tell
+    /// which strokes belong to a character. only stroked paths are
+    /// scrambled. defaults to false.
+    pub fn scramble(mut self, scramble: bool) -> BiosvgBuilder {
+        self.scramble = scramble;
+        self
+    }
+
+    /// write the paths in runs inside up to `depth` nested `<g>` elements
+    /// with random transforms that mostly cancel out. the path data is in
+    /// the coordinates inside the groups, so it doesn't give the real
+    /// positions without composing the transforms. defaults to 0, no groups.
+    pub fn nested_groups(mut self, depth: usize) -> BiosvgBuilder {
+        self.nesting = depth;
+        self
+    }
+
+    /// shuffle the attributes of every element, add random junk attributes
+    /// and ids and write the numbers of every element in another style, so
+    /// the markup can't be fingerprinted or diffed to find the glyph paths.
+    /// the numbers keep their values. defaults to false. the pass needs the
+    /// whole markup to keep the added ids unique, so `write_svg` buffers the
+    /// content of obfuscated captchas in memory before writing it.
+    pub fn obfuscate(mut self, obfuscate: bool) -> BiosvgBuilder {
+        self.obfuscate = obfuscate;
+        self
+    }
+
+    /// set the range of the number of segments of the split pieces, fewer
+    /// segments split the glyphs more. defaults to `2..=4`.
+    pub fn split_segments_range(mut self, range: RangeInclusive<usize>) -> BiosvgBuilder {
+        self.split_range = (*range.start(), *range.end());
+        self
+    }
+
+    /// dash a random `fraction` of the character and noise strokes with a
+    /// random pattern, fragmenting them beyond the splitting. filled
+    /// characters are never dashed. defaults to 0.
+    pub fn dash(mut self, fraction: f64) -> BiosvgBuilder {
+        self.dash = fraction;
+        self
+    }
+
+    /// animate the strokes with SMIL, a single frame of an animated captcha
+    /// isn't enough to read it. `AnimationStyle::DrawOn` only draws stroked
+    /// paths, png rendering shows the finished drawing.
+    pub fn animate(mut self, style: AnimationStyle) -> BiosvgBuilder {
+        self.animation = Some(style);
+        self
+    }
+
+    /// add the `biosvg-glyph` class to the character paths and decoys and
+    /// `biosvg-noise` to the noise paths and dots, to style or animate them
+    /// with css. the classes tell the noise apart, so they make the captcha
+    /// easier to solve from its markup. defaults to false.
+    pub fn css_classes(mut self, css_classes: bool) -> BiosvgBuilder {
+        self.css_classes = css_classes;
+        self
+    }
+
+    /// embed a `<style>` block with `css` in the svg, e.g. rules for the
+    /// classes of `css_classes`.
+    pub fn css_style(mut self, css: &str) -> BiosvgBuilder {
+        self.css_style = Some(css.to_string());
+        self
+    }
+
+    /// give the svg `role="img"`, an `aria-label` and `<title>`/`<desc>`
+    /// elements, so screen readers announce it and accessibility audits pass.
+    /// off by default.
+    pub fn aria(mut self, aria: AriaOptions) -> BiosvgBuilder {
+        self.aria = Some(aria);
+        self
+    }
+
+    /// prefix of the ids of the gradients and clip paths in the svg, so
+    /// captchas with different prefixes can be embedded in one html
+    /// document. must be a valid xml name, defaults to `biosvg`.
+    pub fn id_prefix(mut self, prefix: &str) -> BiosvgBuilder {
+        self.id_prefix = prefix.to_string();
+        self
+    }
+
+    /// append a random suffix to the id prefix of every captcha, so any
+    /// number of captchas can be embedded in one html document. the suffix
+    /// is drawn from the seed when it is set. defaults to false.
+    pub fn random_id_prefix(mut self, random: bool) -> BiosvgBuilder {
+        self.random_id_prefix = random;
+        self
+    }
+
+    /// shrink the svg: numbers are rounded to `precision` decimal places,
+    /// lines in the same direction are merged and the path data is written
+    /// with relative commands and as few separators as possible. full
+    /// precision output is 3 to 5 times larger.
+    pub fn optimize(mut self, precision: u8) -> BiosvgBuilder {
+        self.optimize = Some(precision);
+        self
+    }
+
+    /// round the numbers of the path data, the other attributes and the root
+    /// width and height to `precision` decimal places, without the rest of
+    /// `optimize`. `optimize` rounds to its own precision.
+    pub fn precision(mut self, precision: u8) -> BiosvgBuilder {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// set how the characters are painted, defaults to `RenderMode::Stroke`.
+    /// filled characters are closed shapes and are never split.
+    pub fn render_mode(mut self, render_mode: RenderMode) -> BiosvgBuilder {
+        self.render_mode = render_mode;
+        self
+    }
+
+    /// tune noise count, rotation, scaling, shearing, splitting and warping
+    /// together, settings called after the preset override it
+    pub fn preset(mut self, preset: Difficulty) -> BiosvgBuilder {
+        let (noise, rotation, scale, skew, split, wave) = match preset {
+            Difficulty::Easy => (4, 0.1, 0.1, 0.05, (4, 8), 0.0),
+            Difficulty::Medium => (8, 0.3, 0.2, 0.2, (2, 4), 0.0),
+            Difficulty::Hard => (12, 0.5, 0.3, 0.3, (2, 3), 0.05),
+            Difficulty::Extreme => (20, 0.7, 0.4, 0.4, (2, 2), 0.1),
+        };
+        self.difficulty = noise;
+        self.rotation_range = (-rotation, rotation);
+        self.scale_range = (1.0 - scale, 1.0 + scale);
+        self.skew_range = (-skew, skew);
+        self.split_range = split;
+        self.wave_amplitude = wave;
+        self
+    }
+
+    /// trade security for readability, `AccessibilityLevel::High` draws the
+    /// glyphs unsplit, halves the noise, keeps the rotation within 0.1
+    /// radians and the shear within 0.1 and only uses text colors with a WCAG
+    /// contrast ratio of at least 4.5:1 to the background (white if none is
+    /// set). black or white is used if no color has enough contrast.
+    pub fn accessibility(mut self, level: AccessibilityLevel) -> BiosvgBuilder {
+        self.accessibility = level;
+        self
+    }
+
+    /// return a hash of the answer instead of the answer, for deployments
+    /// where the plaintext answer must not leave the generator. check the
+    /// user input with `HashAlgo::verify` and the `case_insensitive` of the
+    /// builder. `Captcha::sign` and the challenge stores still check the
+    /// input against the plaintext answer, which the captcha keeps privately
+    /// and never serializes or prints.
+    #[cfg(feature = "hash")]
+    pub fn hash_answer(mut self, algo: HashAlgo) -> BiosvgBuilder {
+        self.hash_answer = Some(algo);
+        self
+    }
+
+    /// set how long captchas are valid, sets `Captcha::expires_at`. defaults
+    /// to 5 minutes
+    pub fn ttl(mut self, ttl: Duration) -> BiosvgBuilder {
+        self.ttl = ttl;
+        self
+    }
+
+    /// set how many wrong guesses a signed token allows, sets
+    /// `Captcha::max_attempts`. defaults to 0 for no limit
+    pub fn max_attempts(mut self, max_attempts: u32) -> BiosvgBuilder {
+        self.max_attempts = max_attempts;
+        self
+    }
+
     /// set colors of captcha text and noise lines, each color will be used randomly,
     /// please add at least 4 colors.
     /// the result of captcha will have a transparent background,
@@ -68,136 +669,4192 @@ impl BiosvgBuilder {
         self
     }
 
-    /// build and generate svg captcha
-    pub fn build(self) -> Result<(String, String), model::PathError> {
+    /// set `n` colors of evenly spread hues that contrast with `background`,
+    /// instead of picking the colors by hand. the background is not drawn,
+    /// call `background` as well to fill the canvas with it.
+    pub fn auto_colors(mut self, background: &str, n: usize) -> BiosvgBuilder {
+        self.colors = match background.parse::<Color>() {
+            Ok(background) => color::palette(background, n)
+                .iter()
+                .map(Color::to_string)
+                .collect(),
+            // keep the invalid color so the build reports it
+            Err(_) => vec![background.to_string()],
+        };
+        self
+    }
+
+    /// draw the text with a custom font instead of the built-in one, when
+    /// called multiple times each character is drawn with a random font
+    /// that has its glyph
+    pub fn font(mut self, font: Font) -> BiosvgBuilder {
+        self.fonts.push(font);
+        self
+    }
+
+    /// add one of the embedded glyph sets, same as
+    /// `font(Font::from_builtin(builtin))`
+    pub fn builtin_font(self, builtin: BuiltinFont) -> BiosvgBuilder {
+        self.font(Font::from_builtin(builtin))
+    }
+
+    /// draw each character with a random font out of `fonts` that has its
+    /// glyph, mixing typefaces makes OCR much harder
+    pub fn fonts(mut self, fonts: Vec<Font>) -> BiosvgBuilder {
+        self.fonts = fonts;
+        self
+    }
+
+    /// set the characters the answer is generated from, defaults to all characters
+    /// of the built-in font table available in the font. every character must have
+    /// a glyph in the font, otherwise `build` will fail.
+    pub fn charset(mut self, charset: &str) -> BiosvgBuilder {
+        self.charset = Charset::Custom(charset.to_string());
+        self
+    }
+
+    /// pick the characters the answer is generated from out of common sets,
+    /// characters the font has no glyph for are left out. `Charset::Lower`
+    /// and `Charset::Upper` also make the answer case insensitive.
+    pub fn charset_kind(mut self, charset: Charset) -> BiosvgBuilder {
+        self.case_insensitive |= charset.case_insensitive();
+        self.charset = charset;
+        self
+    }
+
+    /// set the characters the answer is generated from as a list of unicode
+    /// characters. the built-in font has Cyrillic letters with the `cyrillic`
+    /// feature, other scripts such as CJK need a font with their glyphs, e.g.
+    /// `Font::from_ttf_bytes_with_chars()` with the `ttf` feature.
+    pub fn charset_unicode(self, charset: Vec<char>) -> BiosvgBuilder {
+        let charset = charset.into_iter().collect::<String>();
+        self.charset(&charset)
+    }
+
+    /// exclude characters from the generated answer, characters that are not
+    /// in the charset are ignored. can be called multiple times.
+    pub fn exclude_chars(mut self, chars: &str) -> BiosvgBuilder {
+        self.exclude_chars.push_str(chars);
+        self
+    }
+
+    /// exclude characters whose upper and lower case glyphs look the same after
+    /// scaling (e.g. `x`/`X`, `w`/`W`), only one case of each pair will be used.
+    /// `0`/`O`, `1`/`l`/`I` are never generated anyway.
+    pub fn unambiguous(self) -> BiosvgBuilder {
+        self.exclude_chars(AMBIGUOUS_CHARS)
+    }
+
+    /// render a fixed answer text instead of generating a random one, `length`,
+    /// `charset` and `exclude_chars` are ignored when the text is set.
+    /// every character must have a glyph in the font, an empty text fails
+    /// with `BuildError::LengthZero`.
+    pub fn text(mut self, text: &str) -> BiosvgBuilder {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// pick the answer out of a list of words instead of random characters,
+    /// sets the kind to `CaptchaKind::Words`. every character must have a
+    /// glyph in the font, words with excluded characters are skipped.
+    pub fn words(mut self, words: Vec<String>) -> BiosvgBuilder {
+        self.words = words;
+        self.kind = CaptchaKind::Words;
+        self
+    }
+
+    /// only render one case of each letter and return the answer in lowercase,
+    /// so the server side verification can ignore case
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> BiosvgBuilder {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// never put characters of the same group of the built-in confusables
+    /// into one answer, e.g. `S` and `5` or `I` and `l`, the same table
+    /// `verify` uses. words with such pairs are skipped.
+    pub fn homoglyph_safe(mut self, homoglyph_safe: bool) -> BiosvgBuilder {
+        self.homoglyph_safe = homoglyph_safe;
+        self
+    }
+
+    /// fail to build when the answer has less than `bits` of entropy, e.g.
+    /// a short length or a small charset, so weak settings are caught in
+    /// review. the entropy is reported by `Captcha::entropy_bits()`.
+    pub fn min_entropy_bits(mut self, bits: f64) -> BiosvgBuilder {
+        self.min_entropy_bits = Some(bits);
+        self
+    }
+
+    /// whether the character is confusable with a character of `text` and
+    /// homoglyphs are to be avoided
+    fn confusable_with(&self, ch: char, text: &str) -> bool {
+        self.homoglyph_safe && text.chars().any(|other| verify::confusable(ch, other))
+    }
+
+    /// whether any of the configured fonts has a glyph for the character
+    fn has_glyph(&self, ch: char) -> bool {
+        if self.fonts.is_empty() {
+            return font_glyph(ch).is_some();
+        }
+        self.fonts.iter().any(|font| font.contains(ch))
+    }
+
+    /// Em height of the fonts, the median height of the glyphs of the charset
+    /// and the text, so a few tall or flat characters don't change it.
+    fn em_height(&self, text: &str) -> f64 {
+        let mut heights = self
+            .charset_chars()
+            .unwrap_or_default()
+            .into_iter()
+            .chain(text.chars())
+            .filter_map(|ch| match self.fonts.is_empty() {
+                true => font_glyph(ch).map(model::Glyph::height),
+                false => self
+                    .fonts
+                    .iter()
+                    .find_map(|font| font.glyph(ch))
+                    .map(|glyph| glyph.height),
+            })
+            .collect::<Vec<f64>>();
+        if heights.is_empty() {
+            return 0.0;
+        }
+        heights.sort_by(f64::total_cmp);
+        heights[heights.len() / 2]
+    }
+
+    /// glyph of a character in a random font out of the configured fonts
+    fn glyph<R: Rng + ?Sized>(&self, ch: char, rng: &mut R) -> Option<model::Path> {
+        if self.fonts.is_empty() {
+            return font_glyph(ch).map(model::Glyph::path);
+        }
+        let fonts = self
+            .fonts
+            .iter()
+            .filter(|font| font.contains(ch))
+            .collect::<Vec<&Font>>();
+        fonts.choose(rng)?.glyph(ch).cloned()
+    }
+
+    /// generate the answer text from the charset, or validate the fixed text
+    fn answer<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<String, model::PathError> {
+        if let Some(text) = &self.text {
+            if let Some(ch) = text.chars().find(|ch| !self.has_glyph(*ch)) {
+                return Err(BuildError::UnsupportedCharacter(ch).into());
+            }
+            return Ok(text.clone());
+        }
+
         // generate random text with length
+        let charset = self.charset_chars()?;
         let mut answer = String::new();
-        let mut rng = thread_rng();
         for _ in 0..self.length {
-            let index = rng.gen_range(0..FONT_TABLE.len());
-            answer.push(String::from(FONT_TABLE).chars().nth(index).unwrap());
+            let candidates = charset
+                .iter()
+                .filter(|ch| !self.confusable_with(**ch, &answer))
+                .collect::<Vec<&char>>();
+            // the first character never conflicts, so there is a candidate
+            answer.push(**candidates.choose(rng).unwrap());
         }
+        Ok(answer)
+    }
 
-        // split colors
-        let mut char_colors = Vec::new();
-        let mut line_colors = Vec::new();
-        
-        // randomly split colors in self.colors, but keep the last one gives to the one who have less
-        // colors
-        let mut colors = self.colors.clone();
-        let last_color = colors.pop().unwrap();
-        for color in colors {
-            if rng.gen_bool(0.5) {
-                char_colors.push(color);
+    /// characters the answer is generated from, after exclusions
+    fn charset_chars(&self) -> Result<Vec<char>, model::PathError> {
+        let mut charset = Vec::new();
+        let custom = matches!(self.charset, Charset::Custom(_));
+        for ch in self.charset.chars() {
+            if !self.has_glyph(ch) {
+                // the built-in sets only use what the font has
+                if !custom {
+                    continue;
+                }
+                return Err(BuildError::UnsupportedCharacter(ch).into());
+            }
+            if self.exclude_chars.contains(ch) {
+                continue;
+            }
+            // keep only the first case met of each letter
+            let duplicated = if self.case_insensitive {
+                charset
+                    .iter()
+                    .any(|c: &char| c.to_lowercase().eq(ch.to_lowercase()))
             } else {
-                line_colors.push(color);
+                charset.contains(&ch)
+            };
+            if !duplicated {
+                charset.push(ch);
             }
         }
-        if char_colors.len() > line_colors.len() {
-            line_colors.push(last_color);
-        } else {
-            char_colors.push(last_color);
+        if charset.is_empty() {
+            return Err(model::PathError::EmptyCharset);
         }
+        Ok(charset)
+    }
 
-        let mut font_paths = Vec::new();
-        for ch in answer.chars() {
-            FONT_PATHS.get(ch.to_string().as_str()).map(|path| {
-                let random_angle = rng.gen_range(-0.2..0.2 * std::f64::consts::PI);
-                // let random_angle = random_angle + std::f64::consts::PI * 1.0;
-                let random_offset = rng.gen_range(0.0..0.1 * path.width);
-                let random_color = char_colors.choose(&mut rng).unwrap();
-                let random_scale_x = rng.gen_range(0.8..1.2);
-                let random_scale_y = rng.gen_range(0.8..1.2);
-                let path = path
-                    .with_color(&random_color)
-                    .scale(random_scale_x, random_scale_y)
-                    .rotate(random_angle)
-                    .offset(0.0, random_offset);
+    /// pick a random word out of the word list. words of the built-in list
+    /// that the fonts can't draw are skipped, like the default charset.
+    fn word<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<String, model::PathError> {
+        self.word_list()?
+            .choose(rng)
+            .cloned()
+            .ok_or(model::PathError::EmptyCharset)
+    }
 
-                font_paths.push(path.clone())
-            });
-        }
-        let mut width = 0.0;
-        let mut height = 0.0;
-        for path in &font_paths {
-            width += path.width;
-            // height = max height of all paths
-            if path.height > height {
-                height = path.height;
+    /// the words answers are picked from, after exclusions
+    fn word_list(&self) -> Result<Vec<String>, model::PathError> {
+        let mut words = Vec::new();
+        if self.words.is_empty() {
+            words.extend(
+                WORD_TABLE
+                    .iter()
+                    .filter(|word| word.chars().all(|ch| self.has_glyph(ch)))
+                    .map(|word| word.to_string()),
+            );
+        } else {
+            for word in &self.words {
+                if let Some(ch) = word.chars().find(|ch| !self.has_glyph(*ch)) {
+                    return Err(BuildError::UnsupportedCharacter(ch).into());
+                }
             }
+            words.extend(self.words.iter().cloned());
         }
-        width += 1.5 * height;
-        let mut start_point = height * 0.55;
-        let mut paths = Vec::new();
-        for path in font_paths {
-            let offset_x = start_point + path.width / 2.0;
-            let offset_y = (height * 1.5) / 2.0;
-            let mut random_splited_path = path.offset(offset_x, offset_y).random_split();
-            paths.append(random_splited_path.as_mut());
-            start_point += path.width + height * 0.4 / self.length as f64;
-        }
-        for _ in 1..self.difficulty {
-            let start_x = rng.gen_range(0.0..width);
-            let end_x = rng.gen_range(start_x..start_x + height);
-            let start_y = rng.gen_range(0.0..height);
-            let end_y = rng.gen_range(start_y..start_y + height);
-            let color = line_colors.choose(&mut rng).unwrap();
-            let start_command = Command {
-                x: start_x,
-                y: start_y,
-                command_type: model::CommandType::Move,
-            };
-            let end_command = Command {
-                x: end_x,
-                y: end_y,
-                command_type: model::CommandType::LineTo,
-            };
-            paths.push(model::Path {
-                commands: vec![start_command, end_command],
-                width,
-                height: height / 1.5,
-                color: color.clone(),
-            });
+        words.retain(|word| {
+            !word.is_empty()
+                && !word.chars().any(|ch| self.exclude_chars.contains(ch))
+                && !word.chars().any(|ch| self.confusable_with(ch, word))
+        });
+        Ok(words)
+    }
+
+    /// Entropy of the answer in bits, log2 of the number of answers the
+    /// configuration can generate. it is a lower bound when `homoglyph_safe`
+    /// narrows the characters, and 0 for a fixed `text`.
+    fn entropy_bits(&self) -> Result<f64, model::PathError> {
+        if self.text.is_some() {
+            return Ok(0.0);
         }
-        paths.shuffle(&mut rng);
-        let svg_content = paths
-            .iter()
-            .map(|path| path.to_string())
-            .collect::<Vec<String>>()
-            .join("");
-        Ok((
-            answer,
-            format!(
-                r#"<svg width="{}" height="{}" viewBox="0 0 {} {}" xmlns="http://www.w3.org/2000/svg" version="1.1">{}</svg>"#,
-                width,
-                height * 1.5,
-                width,
-                height * 1.5,
-                svg_content
-            ),
-        ))
+        Ok(match self.kind {
+            CaptchaKind::Text => {
+                let charset = self.charset_chars()?;
+                let size = charset.len() as f64;
+                if !self.homoglyph_safe || self.length == 0 {
+                    self.length as f64 * size.log2()
+                } else {
+                    // after the first character its confusables are left out
+                    let confusable = charset
+                        .iter()
+                        .filter(|ch| {
+                            self.confusable_with(**ch, &charset.iter().collect::<String>())
+                        })
+                        .count() as f64;
+                    size.log2() + (self.length - 1) as f64 * (size - confusable).max(1.0).log2()
+                }
+            }
+            CaptchaKind::Arithmetic => (arithmetic::value_count(self.length) as f64).log2(),
+            CaptchaKind::Words => {
+                let mut words = self.word_list()?;
+                words.sort();
+                words.dedup();
+                (words.len().max(1) as f64).log2()
+            }
+        })
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// generate an arithmetic expression, returns `(expression, value)`
+    fn expression<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<(String, String), model::PathError> {
+        let expression = arithmetic::Expression::random(rng, self.length);
+        let text = expression.to_string();
+        if let Some(ch) = text.chars().find(|ch| !self.has_glyph(*ch)) {
+            return Err(BuildError::UnsupportedCharacter(ch).into());
+        }
+        Ok((text, expression.value().to_string()))
+    }
 
-    #[test]
-    fn it_works() {
-        let (answer, svg) = BiosvgBuilder::new()
-            .length(4)
-            .difficulty(6)
-            .colors(vec![
-                "#0078D6".to_string(),
-                "#aa3333".to_string(),
-                "#f08012".to_string(),
-                "#33aa00".to_string(),
-                "#aa33aa".to_string(),
-            ])
-            .build()
-            .unwrap();
-        println!("answer: {}", answer);
-        println!("svg: {}", svg);
+    /// seed the random generator, builders with the same settings and seed
+    /// always generate the same answer and byte-identical svg
+    pub fn seed(mut self, seed: u64) -> BiosvgBuilder {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// set colors of captcha text, solid colors or gradients, instead of
+    /// giving the text a random share of `colors`. all `colors` are then used
+    /// for noise unless `noise_colors` is set.
+    pub fn char_colors<C: Into<ColorSpec>>(mut self, colors: Vec<C>) -> BiosvgBuilder {
+        self.char_colors = colors.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// never give two adjacent characters the same color, which helps
+    /// people tell overlapping glyphs apart. needs at least two distinct text
+    /// colors to have an effect, defaults to false.
+    pub fn distinct_adjacent_colors(mut self, distinct: bool) -> BiosvgBuilder {
+        self.distinct_adjacent_colors = distinct;
+        self
+    }
+
+    /// set colors of noise lines, decoys and dots instead of giving the noise
+    /// a random share of `colors`. all `colors` are then used for the text
+    /// unless `char_colors` is set.
+    pub fn noise_colors(mut self, colors: Vec<String>) -> BiosvgBuilder {
+        self.noise_colors = colors;
+        self
+    }
+
+    /// set colors of the dark themed svg of `build_themed`, each color
+    /// replaces the color at the same position in `colors`
+    pub fn dark_colors(mut self, colors: Vec<String>) -> BiosvgBuilder {
+        self.dark_colors = colors;
+        self
+    }
+
+    /// require a WCAG contrast ratio of at least `min_ratio` between every
+    /// text color and the declared `background`, the build fails with
+    /// `BuildError::LowContrast` naming the first illegible color. 4.5 is
+    /// the ratio for normal text in WCAG 2, the background is not drawn.
+    /// translucent colors and `currentColor` have no contrast and fail too,
+    /// the background must be an opaque `Color`.
+    pub fn ensure_contrast(mut self, background: &str, min_ratio: f64) -> BiosvgBuilder {
+        self.min_contrast = Some((background.to_string(), min_ratio));
+        self
+    }
+
+    /// set the background of the dark themed svg of `build_themed`
+    pub fn dark_background(mut self, color: &str) -> BiosvgBuilder {
+        self.dark_background = Some(color.to_string());
+        self
+    }
+
+    /// build and generate svg captcha, returns `(answer, svg)`
+    pub fn build(self) -> Result<(String, String), model::PathError> {
+        let captcha = self.build_captcha()?;
+        Ok((captcha.answer, captcha.svg))
+    }
+
+    /// build and generate svg captcha with the given random generator,
+    /// the `seed` setting is ignored
+    pub fn build_with_rng<R: Rng + ?Sized>(
+        self,
+        rng: &mut R,
+    ) -> Result<(String, String), model::PathError> {
+        let captcha = self.build_captcha_with_rng(rng)?;
+        Ok((captcha.answer, captcha.svg))
+    }
+
+    /// build and generate svg captcha along with its metadata
+    pub fn build_captcha(self) -> Result<Captcha, model::PathError> {
+        self.compile()?.generate()
+    }
+
+    /// build and generate svg captcha along with its metadata with the given
+    /// random generator, the `seed` setting is ignored
+    pub fn build_captcha_with_rng<R: Rng + ?Sized>(
+        self,
+        rng: &mut R,
+    ) -> Result<Captcha, model::PathError> {
+        self.compile()?.generate_with_rng(rng)
+    }
+
+    /// generate a captcha and stream its svg into `writer`, e.g. a response
+    /// body, without building the document in memory. returns the answer.
+    /// wrap unbuffered writers in a `BufWriter`. with `obfuscate` the content
+    /// is built in memory first and written in one piece.
+    pub fn write_svg<W: io::Write>(&self, writer: W) -> Result<String, model::PathError> {
+        let scene = self.seeded_scene()?;
+        scene.write_to(writer)?;
+        Ok(scene.answer)
+    }
+
+    /// build `n` captchas at once for pre-generating captcha pools, they
+    /// share one random generator and the parsed glyphs. a seeded batch is
+    /// reproducible as a whole while its captchas differ.
+    pub fn build_batch(self, n: usize) -> Result<Vec<Captcha>, model::PathError> {
+        match self.seed {
+            Some(seed) => self.batch(&mut StdRng::seed_from_u64(seed), n),
+            None => self.batch(&mut entropy_rng(), n),
+        }
+    }
+
+    /// build `n` captchas at once with the given random generator, the
+    /// `seed` setting is ignored
+    pub fn build_batch_with_rng<R: Rng + ?Sized>(
+        self,
+        rng: &mut R,
+        n: usize,
+    ) -> Result<Vec<Captcha>, model::PathError> {
+        self.batch(rng, n)
+    }
+
+    /// check the settings once and turn the builder into a generator that
+    /// makes any number of captchas without being cloned
+    pub fn compile(self) -> Result<CaptchaGenerator, model::PathError> {
+        self.validate()?;
+        let entropy_bits = self.entropy_bits()?;
+        Ok(CaptchaGenerator {
+            builder: self,
+            entropy_bits,
+        })
+    }
+
+    /// build and generate svg captcha on the blocking thread pool of tokio,
+    /// so async handlers don't hold up the executor. a panic of the
+    /// generation is resumed, it fails with `Cancelled` if the runtime shuts
+    /// down before generating.
+    #[cfg(feature = "tokio")]
+    pub async fn build_async(self) -> Result<Captcha, model::PathError> {
+        match tokio::task::spawn_blocking(move || self.build_captcha()).await {
+            Ok(captcha) => captcha,
+            Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+            Err(_) => Err(model::PathError::Cancelled),
+        }
+    }
+
+    /// build a click captcha: `length` distinct characters scattered over the
+    /// canvas, the user has to click the `target` one. `text` and `kind` are
+    /// ignored.
+    pub fn build_click(self) -> Result<ClickCaptcha, model::PathError> {
+        match self.seed {
+            Some(seed) => self.build_click_with_rng(&mut StdRng::seed_from_u64(seed)),
+            None => self.build_click_with_rng(&mut entropy_rng()),
+        }
+    }
+
+    /// build a click captcha with the given random generator, the `seed`
+    /// setting is ignored
+    pub fn build_click_with_rng<R: Rng + ?Sized>(
+        self,
+        rng: &mut R,
+    ) -> Result<ClickCaptcha, model::PathError> {
+        let (scene, target) = self.click_scene(rng)?;
+        Ok(ClickCaptcha::new(scene.to_svg(), scene, target))
+    }
+
+    /// set the rows and columns of grid captchas, defaults to 3 x 3
+    pub fn grid(mut self, rows: usize, cols: usize) -> BiosvgBuilder {
+        self.grid = (rows, cols);
+        self
+    }
+
+    /// build an object selection captcha: a grid of icons, the user has to
+    /// select every cell showing the `target` icon. `colors`, the transform
+    /// ranges, the noise and the output size apply, `text` and `kind` are
+    /// ignored.
+    pub fn build_grid(self) -> Result<GridCaptcha, model::PathError> {
+        match self.seed {
+            Some(seed) => self.build_grid_with_rng(&mut StdRng::seed_from_u64(seed)),
+            None => self.build_grid_with_rng(&mut entropy_rng()),
+        }
+    }
+
+    /// build a grid captcha with the given random generator, the `seed`
+    /// setting is ignored
+    pub fn build_grid_with_rng<R: Rng + ?Sized>(
+        self,
+        rng: &mut R,
+    ) -> Result<GridCaptcha, model::PathError> {
+        let (scene, cells) = self.grid_scene(rng)?;
+        let (rows, cols) = self.grid;
+        Ok(GridCaptcha::new(scene.to_svg(), scene, rows, cols, cells))
+    }
+
+    /// Render a built-in icon by name with the random transforms, colors and
+    /// noise of the text, returns the svg. see `icon_names()` for the icons.
+    pub fn build_icon(self, name: &str) -> Result<String, model::PathError> {
+        match self.seed {
+            Some(seed) => self.build_icon_with_rng(name, &mut StdRng::seed_from_u64(seed)),
+            None => self.build_icon_with_rng(name, &mut entropy_rng()),
+        }
+    }
+
+    /// render a built-in icon with the given random generator, the `seed`
+    /// setting is ignored
+    pub fn build_icon_with_rng<R: Rng + ?Sized>(
+        self,
+        name: &str,
+        rng: &mut R,
+    ) -> Result<String, model::PathError> {
+        self.validate_style()?;
+        Ok(self.icon_scene(rng, &[name], 1, name)?.to_svg())
+    }
+
+    /// set the voice audio captchas of `build_audio` are read with
+    #[cfg(feature = "audio")]
+    pub fn voice(mut self, voice: Voice) -> BiosvgBuilder {
+        self.voice = Some(voice);
+        self
+    }
+
+    /// build and generate svg captcha with an audio version for visually
+    /// impaired users, returns `(answer, svg, wav)`. the answer is read out
+    /// with the clips of the `voice`, which must have a clip for every
+    /// character of the charset.
+    #[cfg(feature = "audio")]
+    pub fn build_audio(self) -> Result<(String, String, Vec<u8>), model::PathError> {
+        match self.seed {
+            Some(seed) => self.audio(&mut StdRng::seed_from_u64(seed)),
+            None => self.audio(&mut entropy_rng()),
+        }
+    }
+
+    /// build a slider puzzle captcha, a piece is cut out of the captcha image
+    /// and has to be slid back into place. the answer is the `x` offset of
+    /// the cutout.
+    pub fn build_slider(self) -> Result<SliderCaptcha, model::PathError> {
+        match self.seed {
+            Some(seed) => self.slider(&mut StdRng::seed_from_u64(seed)),
+            None => self.slider(&mut entropy_rng()),
+        }
+    }
+
+    /// build a slider puzzle captcha with the given random generator, the
+    /// `seed` setting is ignored
+    pub fn build_slider_with_rng<R: Rng + ?Sized>(
+        self,
+        rng: &mut R,
+    ) -> Result<SliderCaptcha, model::PathError> {
+        self.slider(rng)
+    }
+
+    /// build and generate captcha as png image bytes, for clients that can't
+    /// display svg. the `background` is painted like in the svg, the image is
+    /// transparent where there is none.
+    #[cfg(feature = "raster")]
+    pub fn build_png(self) -> Result<(String, Vec<u8>), model::PathError> {
+        let scene = self.seeded_scene()?;
+        // the layout is already scaled to the requested size
+        let scale = if self.output_width.is_some() || self.output_height.is_some() {
+            1.0
+        } else {
+            raster::PNG_HEIGHT / scene.height
+        };
+        let png = raster::render_png(&scene, scale)?;
+        Ok((scene.answer, png))
+    }
+
+    /// build and generate a light and a dark themed svg of the same captcha,
+    /// returns `(answer, light_svg, dark_svg)`. the dark svg uses
+    /// `dark_colors` instead of `colors`, matched by position, and
+    /// `dark_background` if it's set.
+    pub fn build_themed(self) -> Result<(String, String, String), model::PathError> {
+        let scene = self.seeded_scene()?;
+        let light = scene.to_svg();
+        let mut dark = scene.recolor(|color| {
+            let dark = self
+                .colors
+                .iter()
+                .position(|light| light == color)
+                .and_then(|i| self.dark_colors.get(i % self.dark_colors.len().max(1)));
+            // occluders take the color of the background
+            let background = match (self.background.as_slice(), &self.dark_background) {
+                ([light], Some(dark)) if light == color => Some(dark),
+                _ => None,
+            };
+            dark.or(background)
+                .map_or_else(|| color.to_string(), |dark| dark.clone())
+        });
+        if let Some(background) = &self.dark_background {
+            dark.background = Some(model::Background {
+                id: format!("{}-background", dark.id_prefix),
+                colors: vec![background.clone()],
+                angle: 0.0,
+            });
+        }
+        Ok((scene.answer, light, dark.to_svg()))
+    }
+
+    /// generate `n` captchas with the same random generator
+    fn batch<R: Rng + ?Sized>(
+        self,
+        rng: &mut R,
+        n: usize,
+    ) -> Result<Vec<Captcha>, model::PathError> {
+        let generator = self.compile()?;
+        (0..n).map(|_| generator.generate_with_rng(rng)).collect()
+    }
+
+    /// generate the scene with the configured seed, or a thread local generator
+    fn seeded_scene(&self) -> Result<Scene, model::PathError> {
+        match self.seed {
+            Some(seed) => self.scene(&mut StdRng::seed_from_u64(seed)),
+            None => self.scene(&mut entropy_rng()),
+        }
+    }
+
+    /// generate the scene of a text captcha, with the answer hashed if
+    /// `hash_answer` is set
+    fn scene<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Scene, model::PathError> {
+        self.validate()?;
+        self.checked_scene(rng)
+    }
+
+    /// generate the scene of a text captcha of a validated builder
+    fn checked_scene<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Scene, model::PathError> {
+        Ok(self.hashed_scene(rng)?.0)
+    }
+
+    /// generate the scene of a validated builder, along with the plaintext
+    /// answer if `hash_answer` replaced it by its hash
+    fn hashed_scene<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<(Scene, Option<String>), model::PathError> {
+        let scene = self.text_scene(rng)?;
+        #[cfg(feature = "hash")]
+        if let Some(algo) = &self.hash_answer {
+            let mut scene = scene;
+            let hash = algo.hash(&scene.answer, self.case_insensitive);
+            let answer = std::mem::replace(&mut scene.answer, hash);
+            return Ok((scene, Some(answer)));
+        }
+        Ok((scene, None))
+    }
+
+    /// generate the answer and the transformed paths of the captcha
+    fn text_scene<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Scene, model::PathError> {
+        // the text drawn in the image and the answer of the captcha
+        let (text, answer) = match self.kind {
+            CaptchaKind::Text => {
+                let answer = self.answer(rng)?;
+                (answer.clone(), answer)
+            }
+            CaptchaKind::Arithmetic => self.expression(rng)?,
+            CaptchaKind::Words => {
+                let answer = self.word(rng)?;
+                (answer.clone(), answer)
+            }
+        };
+        self.draw(rng, &text, answer, Layout::Line)
+    }
+
+    /// generate the scene and read its answer
+    #[cfg(feature = "audio")]
+    fn audio<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<(String, String, Vec<u8>), model::PathError> {
+        let voice = self
+            .voice
+            .as_ref()
+            .ok_or_else(|| model::PathError::AudioError("no voice set".to_string()))?;
+        self.validate()?;
+        let scene = self.text_scene(rng)?;
+        let wav = voice.speak(rng, &scene.answer)?;
+        #[cfg(feature = "hash")]
+        if let Some(algo) = &self.hash_answer {
+            return Ok((
+                algo.hash(&scene.answer, self.case_insensitive),
+                scene.to_svg(),
+                wav,
+            ));
+        }
+        Ok((scene.answer.clone(), scene.to_svg(), wav))
+    }
+
+    /// generate the scene of a slider captcha and cut the piece out of it
+    fn slider<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<SliderCaptcha, model::PathError> {
+        let scene = self.scene(rng)?;
+        Ok(SliderCaptcha::new(rng, scene, self.nesting))
+    }
+
+    /// generate a click captcha with distinct characters scattered over the
+    /// canvas, returns the scene and the character to click
+    fn click_scene<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<(Scene, char), model::PathError> {
+        self.validate()?;
+        let mut text = String::new();
+        if self.homoglyph_safe {
+            let mut charset = self.charset_chars()?;
+            charset.shuffle(rng);
+            for ch in charset {
+                if text.chars().count() < self.length.max(1) && !self.confusable_with(ch, &text) {
+                    text.push(ch);
+                }
+            }
+        } else {
+            text.extend(
+                self.charset_chars()?
+                    .choose_multiple(rng, self.length.max(1)),
+            );
+        }
+        let target = text
+            .chars()
+            .collect::<Vec<char>>()
+            .choose(rng)
+            .copied()
+            .ok_or(model::PathError::EmptyCharset)?;
+        let scene = self.draw(rng, &text, target.to_string(), Layout::Scatter)?;
+        Ok((scene, target))
+    }
+
+    /// Pick the icons of a grid captcha, returns the scene and the sorted
+    /// cells showing the target, at least one and at most half of them.
+    fn grid_scene<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<(Scene, Vec<usize>), model::PathError> {
+        self.validate_style()?;
+        let (rows, cols) = self.grid;
+        let count = rows * cols;
+        if count < 2 {
+            return Err(model::PathError::InvalidRange("grid"));
+        }
+        let (target, names, cells) = grid_icons(rng, count);
+        let scene = self.icon_scene(rng, &names, cols, target)?;
+        Ok((scene, cells))
+    }
+
+    /// Draw icons row by row in `cols` columns of square cells 1.25 times
+    /// the icon size, with the random transforms, colors and noise of the
+    /// text. more than one icon are separated by grid lines.
+    fn icon_scene<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        names: &[&str],
+        cols: usize,
+        answer: &str,
+    ) -> Result<Scene, model::PathError> {
+        let id_prefix = self.scene_id_prefix(rng);
+        let rows = names.len().div_ceil(cols);
+        let cell = 125.0;
+        let (width, height) = (cell * cols as f64, cell * rows as f64);
+        let stroke = match self.stroke_width {
+            StrokeWidth::Auto => cell / 15.0,
+            StrokeWidth::Relative(ratio) => cell * ratio,
+            StrokeWidth::Fixed(stroke) => {
+                stroke
+                    / fit_scale(
+                        width,
+                        height,
+                        self.output_width,
+                        self.output_height,
+                        self.padding,
+                    )
+            }
+        };
+        let line_colors = match self.noise_colors.is_empty() {
+            true => &self.colors,
+            false => &self.noise_colors,
+        };
+        let (min_angle, max_angle) = self.rotation_range;
+        let (min_scale, max_scale) = self.scale_range;
+        let (min_skew, max_skew) = self.skew_range;
+        let mut paths = Vec::new();
+        for (i, name) in names.iter().enumerate() {
+            let icon =
+                resource::icon(name).ok_or_else(|| BuildError::UnknownIcon(name.to_string()))?;
+            let scale = rng.gen_range(min_scale..=max_scale);
+            let icon = icon
+                .path()
+                .with_color(self.colors.choose(rng).unwrap())
+                .scale(scale, scale)
+                .skew(rng.gen_range(min_skew..=max_skew), 0.0)
+                .rotate(rng.gen_range(min_angle..=max_angle));
+            // shrink icons that grew out of their cell
+            let bounds = icon.bounds();
+            let fit = (cell * 0.8 / bounds.width().max(bounds.height())).min(1.0);
+            let icon = icon.scale(fit, fit);
+            let (center_x, center_y) = icon.bounds().center();
+            let x = ((i % cols) as f64 + 0.5) * cell - center_x;
+            let y = ((i / cols) as f64 + 0.5) * cell - center_y;
+            let icon = icon.offset(x, y).with_stroke_width(stroke);
+            paths.append(&mut self.render_glyph(rng, icon, false));
+        }
+
+        if names.len() > 1 {
+            let mut lines = String::new();
+            for col in 0..=cols {
+                let x = col as f64 * cell;
+                lines.push_str(&format!("M {} 0 L {} {} ", x, x, height));
+            }
+            for row in 0..=rows {
+                let y = row as f64 * cell;
+                lines.push_str(&format!("M 0 {} L {} {} ", y, width, y));
+            }
+            let lines = model::Path::parse(&lines)?;
+            paths.push(
+                lines
+                    .offset(width / 2.0, height / 2.0)
+                    .with_color(line_colors.choose(rng).unwrap())
+                    .with_stroke_width(stroke / 3.0),
+            );
+        }
+        for (style, count) in self.noise_strokes(false) {
+            let noise_length = self.noise_length_range.unwrap_or(style.length_range());
+            let (min_stroke, max_stroke) = self
+                .noise_stroke_width_range
+                .unwrap_or(style.stroke_width_range());
+            for _ in 0..count {
+                let color = line_colors.choose(rng).unwrap();
+                paths.push(
+                    noise::generate(rng, style, width, height, noise_length, &[], color)
+                        .with_stroke_width(stroke * rng.gen_range(min_stroke..=max_stroke))
+                        .with_class(self.css_classes.then_some("biosvg-noise")),
+                );
+            }
+        }
+        let mut dots = Vec::new();
+        for _ in 0..self.dots {
+            let color = line_colors.choose(rng).unwrap();
+            let mut dot = noise::dot(rng, width, height / 1.5, color);
+            dot.class = self.css_classes.then_some("biosvg-noise");
+            dots.push(dot);
+        }
+        let background = match self.background.len() {
+            0 => None,
+            _ => Some(model::Background {
+                id: format!("{}-background", id_prefix),
+                colors: self.background.clone(),
+                angle: rng.gen_range(0.0..std::f64::consts::TAU),
+            }),
+        };
+        Ok(Scene {
+            answer: answer.to_string(),
+            paths,
+            groups: Vec::new(),
+            dots,
+            gradients: Vec::new(),
+            background,
+            // the first cell, to find the grid after fitting the scene
+            glyphs: vec![GlyphBox {
+                ch: ' ',
+                x: 0.0,
+                y: 0.0,
+                w: cell,
+                h: cell,
+                angle: 0.0,
+            }],
+            style: self.css_style.clone(),
+            aria: self.aria.clone(),
+            id_prefix,
+            format: self.format(),
+            obfuscation: self.obfuscate.then(|| rng.gen()),
+            width,
+            height,
+        }
+        .fit(self.output_width, self.output_height, self.padding))
+    }
+
+    /// the id prefix of a scene, with a random suffix if `random_id_prefix`
+    /// is set
+    fn scene_id_prefix<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        match self.random_id_prefix {
+            true => format!("{}-{:08x}", self.id_prefix, rng.gen::<u32>()),
+            false => self.id_prefix.clone(),
+        }
+    }
+
+    /// check the length and the configured ranges
+    fn validate(&self) -> Result<(), model::PathError> {
+        let fixed =
+            self.text.is_some() || !self.words.is_empty() || self.kind == CaptchaKind::Words;
+        // an empty text would make the empty input a right answer
+        if (self.length == 0 && !fixed) || self.text.as_deref() == Some("") {
+            return Err(BuildError::LengthZero.into());
+        }
+        if self.kind == CaptchaKind::Arithmetic && self.length > arithmetic::MAX_TERMS && !fixed {
+            return Err(BuildError::TooManyTerms(self.length).into());
+        }
+        self.validate_style()
+    }
+
+    /// check the colors and the configured ranges, which apply to captchas
+    /// without text too
+    fn validate_style(&self) -> Result<(), model::PathError> {
+        if self.colors.is_empty() {
+            return Err(BuildError::NoColors.into());
+        }
+        if self.occlusion > 0 && self.background.len() != 1 {
+            return Err(BuildError::OcclusionWithoutBackground.into());
+        }
+        let char_colors = self.char_colors.iter().flat_map(|color| match color {
+            ColorSpec::Solid(color) => std::slice::from_ref(color),
+            ColorSpec::Gradient(colors) => colors.as_slice(),
+        });
+        self.colors
+            .iter()
+            .chain(&self.background)
+            .chain(&self.dark_colors)
+            .chain(&self.dark_background)
+            .chain(&self.noise_colors)
+            .chain(self.min_contrast.iter().map(|(background, _)| background))
+            .chain(char_colors.clone())
+            .try_for_each(|color| color::check(color))?;
+        if let Some((background, min_ratio)) = &self.min_contrast {
+            if !(1.0..=21.0).contains(min_ratio) {
+                return Err(model::PathError::InvalidRange("contrast"));
+            }
+            // the contrast is only defined against an opaque background
+            background.parse::<Color>()?;
+            let low = self.colors.iter().chain(char_colors).find(|color| {
+                !color::contrast_ratio(color, background).is_some_and(|ratio| ratio >= *min_ratio)
+            });
+            if let Some(color) = low {
+                return Err(BuildError::LowContrast(color.clone()).into());
+            }
+        }
+        let (min_angle, max_angle) = self.rotation_range;
+        if !(min_angle.is_finite() && max_angle.is_finite() && min_angle <= max_angle) {
+            return Err(model::PathError::InvalidRange("rotation"));
+        }
+        let (min_scale, max_scale) = self.scale_range;
+        if !(min_scale > 0.0 && max_scale.is_finite() && min_scale <= max_scale) {
+            return Err(model::PathError::InvalidRange("scale"));
+        }
+        let (min_skew, max_skew) = self.skew_range;
+        if !(min_skew.is_finite() && max_skew.is_finite() && min_skew <= max_skew) {
+            return Err(model::PathError::InvalidRange("skew"));
+        }
+        let (min_length, max_length) = self
+            .noise_length_range
+            .unwrap_or(self.noise_style.length_range());
+        if !(min_length > 0.0 && max_length.is_finite() && min_length <= max_length) {
+            return Err(model::PathError::InvalidRange("noise line length"));
+        }
+        let (min_stroke, max_stroke) = self
+            .noise_stroke_width_range
+            .unwrap_or(self.noise_style.stroke_width_range());
+        if !(min_stroke > 0.0 && max_stroke.is_finite() && min_stroke <= max_stroke) {
+            return Err(model::PathError::InvalidRange("noise stroke width"));
+        }
+        let (min_opacity, max_opacity) = self.opacity_range;
+        if !(min_opacity > 0.0 && min_opacity <= max_opacity && max_opacity <= 1.0) {
+            return Err(model::PathError::InvalidRange("opacity"));
+        }
+        let (min_split, max_split) = self.split_range;
+        if !(min_split >= 1 && min_split <= max_split) {
+            return Err(model::PathError::InvalidRange("split segments"));
+        }
+        if !(0.0..=1.0).contains(&self.dash) {
+            return Err(model::PathError::InvalidRange("dash"));
+        }
+        if !(0.0..1.0).contains(&self.overlap) {
+            return Err(model::PathError::InvalidRange("overlap"));
+        }
+        if !(self.vertical_jitter.is_finite() && self.vertical_jitter >= 0.0) {
+            return Err(model::PathError::InvalidRange("vertical jitter"));
+        }
+        if !(self.wave_amplitude.is_finite()
+            && self.wave_amplitude >= 0.0
+            && self.wave_frequency.is_finite()
+            && self.wave_frequency > 0.0)
+        {
+            return Err(model::PathError::InvalidRange("wave"));
+        }
+        for (size, name) in [(self.output_width, "width"), (self.output_height, "height")] {
+            match size {
+                Some(0) => return Err(model::PathError::InvalidRange(name)),
+                Some(size) if u64::from(self.padding) * 2 >= u64::from(size) => {
+                    return Err(model::PathError::InvalidRange("padding"))
+                }
+                _ => {}
+            }
+        }
+        let mut id_chars = self.id_prefix.chars();
+        let valid_id = id_chars
+            .next()
+            .is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '_')
+            && id_chars.all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.'));
+        if !valid_id {
+            return Err(BuildError::InvalidIdPrefix(self.id_prefix.clone()).into());
+        }
+        if let Some(min_bits) = self.min_entropy_bits {
+            if !(min_bits.is_finite() && min_bits >= 0.0) {
+                return Err(model::PathError::InvalidRange("entropy"));
+            }
+            let bits = self.entropy_bits()?;
+            if bits < min_bits {
+                return Err(BuildError::LowEntropy(bits.floor() as u32).into());
+            }
+        }
+        if !(0.0..=1.0).contains(&self.min_glyph_size) {
+            return Err(model::PathError::InvalidRange("min glyph size"));
+        }
+        if let StrokeWidth::Fixed(stroke) | StrokeWidth::Relative(stroke) = self.stroke_width {
+            if !(stroke.is_finite() && stroke > 0.0) {
+                return Err(model::PathError::InvalidRange("stroke width"));
+            }
+        }
+        if self
+            .char_spacing
+            .is_some_and(|spacing| !spacing.is_finite())
+        {
+            return Err(model::PathError::InvalidRange("spacing"));
+        }
+        Ok(())
+    }
+
+    /// draw the text with random transforms, noise and background
+    fn draw<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        text: &str,
+        answer: String,
+        layout: Layout,
+    ) -> Result<Scene, model::PathError> {
+        let id_prefix = self.scene_id_prefix(rng);
+        let high_access = self.accessibility == AccessibilityLevel::High;
+        let (mut min_angle, mut max_angle) = self.rotation_range;
+        if high_access {
+            min_angle = min_angle.clamp(-0.1, 0.1);
+            max_angle = max_angle.clamp(-0.1, 0.1);
+        }
+        let (min_scale, max_scale) = self.scale_range;
+        let (mut min_skew, mut max_skew) = self.skew_range;
+        if high_access {
+            min_skew = min_skew.clamp(-0.1, 0.1);
+            max_skew = max_skew.clamp(-0.1, 0.1);
+        }
+
+        // split colors
+        let solid = |colors: &[String]| colors.iter().cloned().map(ColorSpec::Solid).collect();
+        let (mut char_colors, line_colors) =
+            match (self.char_colors.is_empty(), self.noise_colors.is_empty()) {
+                (true, true) => {
+                    // shuffle the colors and cut them in two non-empty pools,
+                    // a single color is shared by the text and the noise
+                    let mut colors = self.colors.clone();
+                    colors.shuffle(rng);
+                    let line_colors = if colors.len() > 1 {
+                        let cut = rng.gen_range(1..colors.len());
+                        colors.split_off(cut)
+                    } else {
+                        colors.clone()
+                    };
+                    (solid(&colors), line_colors)
+                }
+                (true, false) => (solid(&self.colors), self.noise_colors.clone()),
+                (false, true) => (self.char_colors.clone(), self.colors.clone()),
+                (false, false) => (self.char_colors.clone(), self.noise_colors.clone()),
+            };
+        if high_access {
+            char_colors = self.contrasting_colors(char_colors);
+        }
+
+        let em = self.em_height(text);
+        let min_size = em * self.min_glyph_size;
+        let mut font_paths = Vec::new();
+        let mut font_colors = Vec::new();
+        let mut font_glyphs = Vec::new();
+        for ch in text.chars() {
+            if let Some(path) = self.glyph(ch, rng) {
+                let random_angle = rng.gen_range(min_angle..=max_angle);
+                let random_color = match font_colors.last() {
+                    // pick among the colors unlike the previous one, if any
+                    Some(&previous) if self.distinct_adjacent_colors => char_colors
+                        .iter()
+                        .filter(|color| *color != previous)
+                        .collect::<Vec<&ColorSpec>>()
+                        .choose(rng)
+                        .copied()
+                        .unwrap_or(previous),
+                    _ => char_colors.choose(rng).unwrap(),
+                };
+                font_colors.push(random_color);
+                // gradients are resolved after the layout
+                let random_color = match random_color {
+                    ColorSpec::Solid(color) => color.as_str(),
+                    ColorSpec::Gradient(_) => "none",
+                };
+                let random_scale_x = rng.gen_range(min_scale..=max_scale);
+                let random_scale_y = rng.gen_range(min_scale..=max_scale);
+                let random_skew_x = rng.gen_range(min_skew..=max_skew);
+                let random_skew_y = rng.gen_range(min_skew..=max_skew);
+                // grow tiny glyphs to the minimum size, keeping their shape
+                let size = path.width.max(path.height);
+                let grow = if size > 0.0 && size < min_size {
+                    min_size / size
+                } else {
+                    1.0
+                };
+                let path = path
+                    .with_color(random_color)
+                    .scale(random_scale_x * grow, random_scale_y * grow)
+                    .skew(random_skew_x, random_skew_y)
+                    .rotate(random_angle);
+
+                font_paths.push(path);
+                font_glyphs.push((ch, random_angle));
+            }
+        }
+        // at least the em height, so flat text like `--` keeps its size
+        let mut height = em;
+        for path in &font_paths {
+            // height = max height of all paths
+            if path.height > height {
+                height = path.height;
+            }
+        }
+        let placement = match layout {
+            Layout::Line => LineLayout {
+                spacing: self
+                    .char_spacing
+                    .unwrap_or(0.4 / font_paths.len().max(1) as f64),
+                overlap: self.overlap,
+                jitter: self.vertical_jitter,
+            }
+            .place(rng, &font_paths, height),
+            Layout::Scatter => layout::scatter(rng, &font_paths, height),
+        };
+        let (width, canvas_height) = (placement.width, placement.height);
+        // noise is sized for a canvas 1.5 times as high as the glyphs
+        let noise_height = canvas_height / 1.5;
+        let glyph_stroke = match self.stroke_width {
+            StrokeWidth::Auto => height / 12.0,
+            StrokeWidth::Relative(ratio) => height * ratio,
+            StrokeWidth::Fixed(stroke) => {
+                stroke
+                    / fit_scale(
+                        width,
+                        canvas_height,
+                        self.output_width,
+                        self.output_height,
+                        self.padding,
+                    )
+            }
+        };
+        let mut paths = Vec::new();
+        let mut gradients = Vec::new();
+        let mut glyphs = Vec::new();
+        // points on the glyph strokes, where occluders go
+        let mut stroke_points = Vec::new();
+        let mut offsets = Vec::new();
+        let placed = font_paths
+            .into_iter()
+            .zip(font_colors)
+            .zip(placement.positions);
+        for (((path, color), (offset_x, offset_y)), (ch, angle)) in placed.zip(font_glyphs) {
+            offsets.push((offset_x, offset_y));
+            let mut path = path
+                .offset(offset_x, offset_y)
+                .with_stroke_width(glyph_stroke);
+            glyphs.push(glyph_box(ch, angle, &path));
+            if let ColorSpec::Gradient(colors) = color {
+                // run the gradient across the glyph in a random direction
+                let (sin, cos) = rng.gen_range(0.0..std::f64::consts::TAU).sin_cos();
+                let gradient = model::Gradient {
+                    id: format!("{}-gradient-{}", id_prefix, gradients.len()),
+                    colors: colors.clone(),
+                    x1: offset_x - cos * path.width / 2.0,
+                    y1: offset_y - sin * path.height / 2.0,
+                    x2: offset_x + cos * path.width / 2.0,
+                    y2: offset_y + sin * path.height / 2.0,
+                };
+                path = path.with_color(&format!("url(#{})", gradient.id));
+                gradients.push(gradient);
+            }
+            if self.wave_amplitude > 0.0 {
+                let phase = rng.gen_range(0.0..std::f64::consts::TAU);
+                path = path.wave(
+                    self.wave_amplitude * path.height,
+                    std::f64::consts::TAU * self.wave_frequency / path.width,
+                    phase,
+                );
+            }
+            stroke_points.extend(path.commands.iter().map(|command| (command.x, command.y)));
+            paths.append(&mut self.render_glyph(rng, path, high_access));
+        }
+        if !high_access {
+            let unused = self.unused_chars(text);
+            for _ in 0..self.decoys {
+                let Some(path) = unused.choose(rng).and_then(|ch| self.glyph(*ch, rng)) else {
+                    break;
+                };
+                let scale = rng.gen_range(min_scale..=max_scale);
+                let decoy = path
+                    .with_color(line_colors.choose(rng).unwrap())
+                    .scale(scale, scale)
+                    .skew(rng.gen_range(min_skew..=max_skew), 0.0)
+                    .rotate(rng.gen_range(min_angle..=max_angle))
+                    .with_stroke_width(glyph_stroke);
+                // anywhere over the text, but inside the canvas
+                let bounds = decoy.bounds();
+                let place = |rng: &mut R, min: f64, max: f64, size: f64| {
+                    if -min < size - max {
+                        rng.gen_range(-min..=size - max)
+                    } else {
+                        (size - min - max) / 2.0
+                    }
+                };
+                let x = place(rng, bounds.min_x, bounds.max_x, width);
+                let y = place(rng, bounds.min_y, bounds.max_y, canvas_height);
+                let decoy = decoy.offset(x, y);
+                paths.append(&mut self.render_glyph(rng, decoy, false));
+            }
+        }
+        if self.scramble {
+            paths = scramble(rng, paths);
+        }
+        let noise_strokes = self.noise_strokes(high_access);
+        let dot_count = if high_access {
+            self.dots / 2
+        } else {
+            self.dots
+        };
+        // mimic strokes are cut out of characters that aren't drawn
+        let mut unused_glyphs = Vec::new();
+        if self.noise_style == NoiseStyle::Mimic {
+            let unused = self.unused_chars(text);
+            for ch in unused.choose_multiple(rng, noise_strokes[0].1 as usize) {
+                if let Some(glyph) = self.glyph(*ch, rng) {
+                    unused_glyphs.push(glyph);
+                }
+            }
+        }
+        for (style, count) in noise_strokes {
+            let noise_length = self.noise_length_range.unwrap_or(style.length_range());
+            let (min_stroke, max_stroke) = self
+                .noise_stroke_width_range
+                .unwrap_or(style.stroke_width_range());
+            for _ in 0..count {
+                let color = line_colors.choose(rng).unwrap();
+                let noise_stroke = glyph_stroke * rng.gen_range(min_stroke..=max_stroke);
+                paths.push(
+                    noise::generate(
+                        rng,
+                        style,
+                        width,
+                        noise_height,
+                        noise_length,
+                        &unused_glyphs,
+                        color,
+                    )
+                    .with_stroke_width(noise_stroke)
+                    .with_class(self.css_classes.then_some("biosvg-noise")),
+                );
+            }
+        }
+        let (min_opacity, max_opacity) = self.opacity_range;
+        if min_opacity < 1.0 {
+            for path in &mut paths {
+                path.opacity = rng.gen_range(min_opacity..=max_opacity);
+            }
+        }
+        if self.dash > 0.0 {
+            for path in &mut paths {
+                if path.render_mode == RenderMode::Fill || !rng.gen_bool(self.dash) {
+                    continue;
+                }
+                // long dashes and short gaps keep the strokes readable
+                let mut dash = Vec::new();
+                for _ in 0..rng.gen_range(1..=2) {
+                    dash.push(rng.gen_range(0.1..0.4) * height);
+                    dash.push(rng.gen_range(0.03..0.1) * height);
+                }
+                path.dash = dash;
+            }
+        }
+        if let Some(style) = self.animation {
+            for path in &mut paths {
+                let animation = match style {
+                    AnimationStyle::DrawOn if path.render_mode == RenderMode::Fill => continue,
+                    AnimationStyle::DrawOn => model::Animation::DrawOn {
+                        delay: rng.gen_range(0.0..1.5),
+                        duration: rng.gen_range(0.4..1.0),
+                    },
+                    AnimationStyle::Wiggle => model::Animation::Wiggle {
+                        dx: rng.gen_range(-0.04..0.04) * height,
+                        dy: rng.gen_range(-0.04..0.04) * height,
+                        duration: rng.gen_range(0.2..0.6),
+                    },
+                };
+                path.animation = Some(animation);
+            }
+        }
+        // after the opacity, dashes and animations, which would show them
+        let mut hidden_traps = Vec::new();
+        if self.traps > 0 {
+            let unused = self.unused_chars(text);
+            for _ in 0..self.traps {
+                let Some(path) = unused.choose(rng).and_then(|ch| self.glyph(*ch, rng)) else {
+                    break;
+                };
+                let Some(&(x, y)) = offsets.choose(rng) else {
+                    break;
+                };
+                // colored like the text, so colors don't give them away
+                let color = match char_colors.choose(rng) {
+                    Some(ColorSpec::Solid(color)) => color,
+                    _ => line_colors.choose(rng).unwrap(),
+                };
+                let scale = rng.gen_range(min_scale..=max_scale);
+                let trap = path
+                    .with_color(color)
+                    .scale(scale, scale)
+                    .skew(rng.gen_range(min_skew..=max_skew), 0.0)
+                    .rotate(rng.gen_range(min_angle..=max_angle))
+                    .with_stroke_width(glyph_stroke)
+                    .offset(x, y);
+                let hidden = self.background.len() == 1 && dot_count == 0 && rng.gen_bool(0.5);
+                for piece in self.render_glyph(rng, trap, high_access) {
+                    match hidden {
+                        true => hidden_traps.push(piece.with_color(&self.background[0])),
+                        false => paths.push(piece.with_opacity(0.0)),
+                    }
+                }
+            }
+        }
+        paths.shuffle(rng);
+        // traps in the background color are only invisible under everything
+        paths.splice(0..0, hidden_traps);
+        if !high_access && self.occlusion > 0 {
+            // drawn last so they cover the glyphs, centered on a stroke
+            let color = &self.background[0];
+            for _ in 0..self.occlusion {
+                let Some(&(x, y)) = stroke_points.choose(rng) else {
+                    break;
+                };
+                let size = height * rng.gen_range(0.15..0.3);
+                paths.push(
+                    noise::occluder(rng, x, y, size, color)
+                        .with_class(self.css_classes.then_some("biosvg-noise")),
+                );
+            }
+        }
+        let mut dots = Vec::new();
+        for _ in 0..dot_count {
+            let color = line_colors.choose(rng).unwrap();
+            let mut dot = noise::dot(rng, width, noise_height, color);
+            dot.class = self.css_classes.then_some("biosvg-noise");
+            dots.push(dot);
+        }
+        let answer = if self.case_insensitive {
+            answer.to_lowercase()
+        } else {
+            answer
+        };
+        let background = match self.background.len() {
+            0 => None,
+            _ => Some(model::Background {
+                id: format!("{}-background", id_prefix),
+                colors: self.background.clone(),
+                angle: rng.gen_range(0.0..std::f64::consts::TAU),
+            }),
+        };
+        let mut scene = Scene {
+            answer,
+            paths,
+            groups: Vec::new(),
+            dots,
+            gradients,
+            background,
+            glyphs,
+            style: self.css_style.clone(),
+            aria: self.aria.clone(),
+            id_prefix,
+            format: self.format(),
+            obfuscation: self.obfuscate.then(|| rng.gen()),
+            width,
+            height: canvas_height,
+        }
+        .fit(self.output_width, self.output_height, self.padding);
+        if self.nesting > 0 {
+            scene.groups = nest(rng, &scene, self.nesting);
+        }
+        Ok(scene)
+    }
+
+    /// styles and counts of the noise strokes, the `difficulty` strokes in
+    /// the noise style first, halved for high accessibility
+    fn noise_strokes(&self, high_access: bool) -> [(NoiseStyle, u16); 3] {
+        [
+            (self.noise_style, self.difficulty),
+            (NoiseStyle::Lines, self.noise_lines),
+            (NoiseStyle::Curves, self.noise_curves),
+        ]
+        .map(|(style, count)| match high_access {
+            true => (style, count / 2),
+            false => (style, count),
+        })
+    }
+
+    /// how the numbers and path data of the svg are written
+    fn format(&self) -> Format {
+        Format {
+            precision: self.optimize.or(self.precision),
+            compact: self.optimize.is_some(),
+        }
+    }
+
+    /// characters of the charset that aren't in `text`
+    fn unused_chars(&self, text: &str) -> Vec<char> {
+        self.charset_chars()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|ch| !text.contains(*ch))
+            .collect()
+    }
+
+    /// split a placed glyph into random strokes, or close it for the fill
+    /// modes, with the glyph class if `css_classes` is set. glyphs are kept
+    /// whole if splitting is disabled or for high accessibility.
+    fn render_glyph<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        path: model::Path,
+        high_access: bool,
+    ) -> Vec<model::Path> {
+        let path = path.with_class(self.css_classes.then_some("biosvg-glyph"));
+        if self.render_mode == RenderMode::Stroke && (high_access || !self.split) {
+            vec![path]
+        } else if self.render_mode == RenderMode::Stroke {
+            let (min_split, max_split) = self.split_range;
+            path.random_split(rng, min_split..=max_split)
+        } else {
+            // splitting would break the closed shapes
+            vec![path.close().with_render_mode(self.render_mode)]
+        }
+    }
+
+    /// keep the text colors with enough contrast to all background colors,
+    /// or fall back to black or white
+    fn contrasting_colors(&self, colors: Vec<ColorSpec>) -> Vec<ColorSpec> {
+        let background = if self.background.is_empty() {
+            vec!["#ffffff".to_string()]
+        } else {
+            self.background.clone()
+        };
+        let contrast = |color: &ColorSpec| {
+            background
+                .iter()
+                .map(|background| color.contrast(background).unwrap_or(0.0))
+                .fold(f64::INFINITY, f64::min)
+        };
+        let colors = colors
+            .into_iter()
+            .filter(|color| contrast(color) >= color::MIN_CONTRAST)
+            .collect::<Vec<ColorSpec>>();
+        if !colors.is_empty() {
+            return colors;
+        }
+        let black = ColorSpec::from("#000000");
+        let white = ColorSpec::from("#ffffff");
+        if contrast(&black) >= contrast(&white) {
+            vec![black]
+        } else {
+            vec![white]
+        }
+    }
+}
+
+/// Reverse random sub-paths of the stroked paths and join sub-paths of the
+/// same color and stroke width, from different characters, into paths of a
+/// few sub-paths each. filled paths are kept as they are, reversing their
+/// sub-paths could turn holes into shapes.
+fn scramble<R: Rng + ?Sized>(rng: &mut R, paths: Vec<model::Path>) -> Vec<model::Path> {
+    let (mut scrambled, stroked): (Vec<model::Path>, Vec<model::Path>) = paths
+        .into_iter()
+        .partition(|path| path.render_mode != RenderMode::Stroke);
+    // grouped in order of appearance, so seeded captchas are reproducible
+    let mut groups: Vec<Vec<model::Path>> = Vec::new();
+    for sub_path in stroked.iter().flat_map(|path| path.sub_paths()) {
+        let sub_path = match rng.gen_bool(0.5) {
+            true => sub_path.reverse(),
+            false => sub_path,
+        };
+        let group = groups.iter_mut().find(|group| {
+            group[0].color == sub_path.color
+                && group[0].stroke_width == sub_path.stroke_width
+                && group[0].class == sub_path.class
+        });
+        match group {
+            Some(group) => group.push(sub_path),
+            None => groups.push(vec![sub_path]),
+        }
+    }
+    for mut group in groups {
+        group.shuffle(rng);
+        let mut rest = group.as_slice();
+        while !rest.is_empty() {
+            let (chunk, tail) = rest.split_at(rng.gen_range(2..=4).min(rest.len()));
+            scrambled.extend(model::Path::merge(chunk));
+            rest = tail;
+        }
+    }
+    scrambled
+}
+
+/// Axis aligned bounding box of a placed glyph from its end and control
+/// points.
+fn glyph_box(ch: char, angle: f64, path: &model::Path) -> GlyphBox {
+    let bounds = path.bounds();
+    GlyphBox {
+        ch,
+        x: bounds.min_x,
+        y: bounds.min_y,
+        w: bounds.width(),
+        h: bounds.height(),
+        angle,
+    }
+}
+
+/// Pick the target icon of a grid, the names of its `count` cells and the
+/// sorted cells showing the target. the other cells show icons that can't
+/// be confused with the target.
+fn grid_icons<R: Rng + ?Sized>(
+    rng: &mut R,
+    count: usize,
+) -> (&'static str, Vec<&'static str>, Vec<usize>) {
+    let target = *ICON_TABLE.choose(rng).unwrap();
+    let others = ICON_TABLE
+        .iter()
+        .copied()
+        .filter(|name| *name != target && !confusable_icons(target, name))
+        .collect::<Vec<&str>>();
+    let matches = rng.gen_range(1..=count / 2);
+    let mut cells = rand::seq::index::sample(rng, count, matches).into_vec();
+    cells.sort_unstable();
+    let names = (0..count)
+        .map(|i| match cells.binary_search(&i) {
+            Ok(_) => target,
+            Err(_) => others.choose(rng).copied().unwrap_or(target),
+        })
+        .collect::<Vec<&str>>();
+    (target, names, cells)
+}
+
+/// Scale factor that fits a `width` x `height` layout into the requested
+/// output size minus the padding, which `validate_style` keeps smaller than
+/// half the output size.
+fn fit_scale(
+    width: f64,
+    height: f64,
+    output_width: Option<u32>,
+    output_height: Option<u32>,
+    padding: u32,
+) -> f64 {
+    let inner = |size: Option<u32>| size.map(|size| size as f64 - 2.0 * padding as f64);
+    match (inner(output_width), inner(output_height)) {
+        (Some(w), Some(h)) => (w / width).min(h / height),
+        (Some(w), None) => w / width,
+        (None, Some(h)) => h / height,
+        (None, None) => 1.0,
+    }
+}
+
+/// Group is a run of consecutive paths of the scene written inside nested
+/// `<g>` elements, outermost transform first
+#[derive(Debug, Clone)]
+struct Group {
+    transforms: Vec<model::Transform>,
+    len: usize,
+}
+
+/// Split the paths of a fitted scene into runs of a few paths in 1 to
+/// `depth` nested groups. the last transform of a group cancels the ones
+/// before it up to a small random transform, so the path data is close to the
+/// drawn positions but off. paths with a gradient color stay outside, the
+/// gradient vector is in the coordinates of the path.
+fn nest<R: Rng + ?Sized>(rng: &mut R, scene: &Scene, depth: usize) -> Vec<Group> {
+    let (width, height) = (scene.width, scene.height);
+    let mut groups = Vec::new();
+    let mut paths = scene.paths.as_slice();
+    while let Some(path) = paths.first() {
+        if path.color.starts_with("url(") {
+            groups.push(Group {
+                transforms: Vec::new(),
+                len: 1,
+            });
+            paths = &paths[1..];
+            continue;
+        }
+        let run = paths
+            .iter()
+            .take_while(|path| !path.color.starts_with("url("))
+            .count();
+        let len = rng.gen_range(1..=4).min(run);
+        let mut transforms = Vec::new();
+        let mut outer = model::Transform::IDENTITY;
+        for _ in 1..rng.gen_range(1..=depth) {
+            let transform = model::Transform {
+                scale: rng.gen_range(0.5..2.0),
+                angle: rng.gen_range(-std::f64::consts::PI..std::f64::consts::PI),
+                x: rng.gen_range(-width..width),
+                y: rng.gen_range(-height..height),
+            };
+            outer = outer.then(&transform);
+            transforms.push(transform);
+        }
+        let offset = model::Transform {
+            scale: rng.gen_range(0.9..1.1),
+            angle: rng.gen_range(-0.3..0.3),
+            x: rng.gen_range(-0.2..0.2) * width,
+            y: rng.gen_range(-0.2..0.2) * height,
+        };
+        transforms.push(outer.inverse().then(&offset));
+        groups.push(Group { transforms, len });
+        paths = &paths[len..];
+    }
+    groups
+}
+
+/// generated captcha, ready to be rendered
+#[derive(Clone)]
+struct Scene {
+    answer: String,
+    paths: Vec<model::Path>,
+    dots: Vec<model::Dot>,
+    gradients: Vec<model::Gradient>,
+    background: Option<model::Background>,
+    /// runs of paths written in nested groups, the rest is written as it is
+    groups: Vec<Group>,
+    glyphs: Vec<GlyphBox>,
+    /// css of the `<style>` block
+    style: Option<String>,
+    /// accessibility attributes of the root
+    aria: Option<AriaOptions>,
+    /// prefix of the element ids
+    id_prefix: String,
+    format: Format,
+    /// seed of the obfuscation pass over the elements, if any
+    obfuscation: Option<u64>,
+    width: f64,
+    height: f64,
+}
+
+impl Scene {
+    /// Copy the scene with every color replaced by `map`.
+    fn recolor(&self, map: impl Fn(&str) -> String) -> Scene {
+        Scene {
+            answer: self.answer.clone(),
+            paths: self
+                .paths
+                .iter()
+                .map(|path| path.clone().with_color(&map(&path.color)))
+                .collect(),
+            groups: self.groups.clone(),
+            dots: self
+                .dots
+                .iter()
+                .map(|dot| model::Dot {
+                    color: map(&dot.color),
+                    ..dot.clone()
+                })
+                .collect(),
+            gradients: self
+                .gradients
+                .iter()
+                .map(|gradient| model::Gradient {
+                    colors: gradient.colors.iter().map(|color| map(color)).collect(),
+                    ..gradient.clone()
+                })
+                .collect(),
+            background: self
+                .background
+                .as_ref()
+                .map(|background| model::Background {
+                    id: background.id.clone(),
+                    colors: background.colors.iter().map(|color| map(color)).collect(),
+                    angle: background.angle,
+                }),
+            glyphs: self.glyphs.clone(),
+            style: self.style.clone(),
+            aria: self.aria.clone(),
+            id_prefix: self.id_prefix.clone(),
+            format: self.format,
+            obfuscation: self.obfuscation,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Scale the scene to the requested output size and surround it with
+    /// `padding`. when only one dimension is given the other one keeps the
+    /// aspect ratio, when both are given the layout is centered.
+    fn fit(self, width: Option<u32>, height: Option<u32>, padding: u32) -> Scene {
+        let scale = fit_scale(self.width, self.height, width, height, padding);
+        let padding = padding as f64;
+        let canvas_width = width.map_or(self.width * scale + 2.0 * padding, |w| w as f64);
+        let canvas_height = height.map_or(self.height * scale + 2.0 * padding, |h| h as f64);
+        if scale == 1.0 && padding == 0.0 {
+            return self;
+        }
+        let fit = model::Transform {
+            scale,
+            angle: 0.0,
+            x: (canvas_width - self.width * scale) / 2.0,
+            y: (canvas_height - self.height * scale) / 2.0,
+        };
+        self.place(&fit, canvas_width, canvas_height)
+    }
+
+    /// Scale and move everything drawn with `fit`, which must not rotate,
+    /// onto a `width` x `height` canvas.
+    fn place(self, fit: &model::Transform, width: f64, height: f64) -> Scene {
+        let (scale, offset_x, offset_y) = (fit.scale, fit.x, fit.y);
+        Scene {
+            answer: self.answer,
+            paths: self
+                .paths
+                .into_iter()
+                .map(|path| path.transform(fit))
+                .collect(),
+            // the paths inside the groups keep their coordinates
+            groups: self
+                .groups
+                .iter()
+                .map(|group| {
+                    let mut transforms = group.transforms.clone();
+                    if let Some(outer) = transforms.first_mut() {
+                        *outer = fit.then(outer);
+                    }
+                    Group {
+                        transforms,
+                        len: group.len,
+                    }
+                })
+                .collect(),
+            dots: self
+                .dots
+                .iter()
+                .map(|dot| dot.scale_offset(scale, offset_x, offset_y))
+                .collect(),
+            gradients: self
+                .gradients
+                .iter()
+                .map(|gradient| gradient.scale_offset(scale, offset_x, offset_y))
+                .collect(),
+            background: self.background,
+            glyphs: self
+                .glyphs
+                .iter()
+                .map(|glyph| GlyphBox {
+                    x: glyph.x * scale + offset_x,
+                    y: glyph.y * scale + offset_y,
+                    w: glyph.w * scale,
+                    h: glyph.h * scale,
+                    ..*glyph
+                })
+                .collect(),
+            style: self.style,
+            aria: self.aria,
+            id_prefix: self.id_prefix,
+            format: self.format,
+            obfuscation: self.obfuscation,
+            width,
+            height,
+        }
+    }
+
+    /// Write the svg elements of the scene without the root element.
+    /// obfuscated elements are buffered, the pass checks the ids it adds
+    /// against the whole content.
+    fn write_content<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        let Some(seed) = self.obfuscation else {
+            return self.write_elements(out);
+        };
+        let mut content = String::new();
+        self.write_elements(&mut content)?;
+        out.write_str(&obfuscate::obfuscate(
+            &mut StdRng::seed_from_u64(seed),
+            &content,
+        ))
+    }
+
+    /// Write the svg elements of the scene, as they are.
+    fn write_elements<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        if let Some(css) = &self.style {
+            write!(out, "<style><![CDATA[{}]]></style>", format::cdata(css))?;
+        }
+        if let Some(background) = &self.background {
+            background.write_svg(out, self.width, self.height, &self.format)?;
+        }
+        for gradient in &self.gradients {
+            gradient.write_svg(out, &self.format)?;
+        }
+        // dots are drawn below the paths as a background layer
+        for dot in &self.dots {
+            dot.write_svg(out, &self.format)?;
+        }
+        let mut paths = self.paths.iter();
+        for group in &self.groups {
+            let inverse = group
+                .transforms
+                .iter()
+                .fold(model::Transform::IDENTITY, |outer, transform| {
+                    outer.then(transform)
+                })
+                .inverse();
+            for transform in &group.transforms {
+                out.write_str("<g transform=\"")?;
+                transform.write_svg(out, &self.format)?;
+                out.write_str("\">")?;
+            }
+            for path in paths.by_ref().take(group.len) {
+                match group.transforms.is_empty() {
+                    true => path.write_svg(out, &self.format)?,
+                    false => path
+                        .clone()
+                        .transform(&inverse)
+                        .write_svg(out, &self.format)?,
+                }
+            }
+            for _ in &group.transforms {
+                out.write_str("</g>")?;
+            }
+        }
+        for path in paths {
+            path.write_svg(out, &self.format)?;
+        }
+        Ok(())
+    }
+
+    /// svg elements of the scene without the root element
+    fn content(&self) -> String {
+        let mut content = String::new();
+        // writing to a string doesn't fail
+        let _ = self.write_content(&mut content);
+        content
+    }
+
+    /// Write the svg document.
+    fn write_svg<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        let width = self.format.number(self.width);
+        let height = self.format.number(self.height);
+        write!(
+            out,
+            r#"<svg width="{}" height="{}" viewBox="0 0 {} {}" xmlns="http://www.w3.org/2000/svg" version="1.1""#,
+            width, height, width, height
+        )?;
+        if let Some(aria) = &self.aria {
+            aria.write_attributes(out)?;
+            out.write_char('>')?;
+            aria.write_elements(out)?;
+        } else {
+            out.write_char('>')?;
+        }
+        self.write_content(out)?;
+        out.write_str("</svg>")
+    }
+
+    fn to_svg(&self) -> String {
+        let mut svg = String::new();
+        // writing to a string doesn't fail
+        let _ = self.write_svg(&mut svg);
+        svg
+    }
+
+    /// Stream the svg document into an `io::Write`.
+    fn write_to<W: io::Write>(&self, writer: W) -> Result<(), model::PathError> {
+        let mut out = IoWriter {
+            writer,
+            error: None,
+        };
+        match self.write_svg(&mut out) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(out
+                .error
+                .map_or(model::PathError::Unknown, model::PathError::Io)),
+        }
+    }
+}
+
+/// IoWriter writes formatted text into an `io::Write`, keeping the io error
+/// that `fmt::Error` can't carry
+struct IoWriter<W> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.writer.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let (answer, svg) = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(6)
+            .colors(vec![
+                "#0078D6".to_string(),
+                "#aa3333".to_string(),
+                "#f08012".to_string(),
+                "#33aa00".to_string(),
+                "#aa33aa".to_string(),
+            ])
+            .build()
+            .unwrap();
+        println!("answer: {}", answer);
+        println!("svg: {}", svg);
+    }
+
+    #[test]
+    fn exclude_chars() {
+        for _ in 0..20 {
+            let (answer, _) = BiosvgBuilder::new()
+                .length(8)
+                .difficulty(2)
+                .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+                .unambiguous()
+                .exclude_chars("23456789")
+                .build()
+                .unwrap();
+            assert!(!answer.chars().any(|ch| AMBIGUOUS_CHARS.contains(ch)));
+            assert!(!answer.chars().any(|ch| ch.is_ascii_digit()));
+        }
+        let result = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .exclude_chars(&resource::FONT_TABLE.iter().collect::<String>())
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn custom_charset() {
+        let (answer, _) = BiosvgBuilder::new()
+            .length(6)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .charset("2345")
+            .build()
+            .unwrap();
+        assert!(answer.chars().all(|ch| "2345".contains(ch)));
+        let result = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .charset("AB0")
+            .build();
+        assert!(matches!(
+            result,
+            Err(model::PathError::Build(BuildError::UnsupportedCharacter(
+                '0'
+            )))
+        ));
+    }
+
+    #[test]
+    fn fixed_text() {
+        let (answer, svg) = BiosvgBuilder::new()
+            .length(8)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .text("AB23")
+            .build()
+            .unwrap();
+        assert_eq!(answer, "AB23");
+        assert!(svg.starts_with("<svg"));
+        let result = BiosvgBuilder::new()
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .text("A B")
+            .build();
+        assert!(matches!(
+            result,
+            Err(model::PathError::Build(BuildError::UnsupportedCharacter(
+                ' '
+            )))
+        ));
+    }
+
+    #[test]
+    fn seeded_output_is_reproducible() {
+        let builder = BiosvgBuilder::new()
+            .length(5)
+            .difficulty(8)
+            .colors(vec![
+                "#0078D6".to_string(),
+                "#aa3333".to_string(),
+                "#f08012".to_string(),
+            ])
+            .seed(42);
+        let first = builder.clone().build().unwrap();
+        let second = builder.clone().build().unwrap();
+        assert_eq!(first, second);
+        let third = builder
+            .build_with_rng(&mut StdRng::seed_from_u64(42))
+            .unwrap();
+        assert_eq!(first, third);
+    }
+
+    #[test]
+    fn build_captcha() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(3)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .seed(7);
+        let captcha = builder.clone().build_captcha().unwrap();
+        let (answer, svg) = builder.clone().build().unwrap();
+        assert_eq!(captcha.answer, answer);
+        assert_eq!(captcha.svg, svg);
+        assert!(captcha.width > 0.0 && captcha.height > 0.0);
+        assert!(svg.contains(&format!(r#"width="{}""#, captcha.width)));
+        assert!(captcha.created_at > 0);
+        assert_eq!(captcha.expires_at, captcha.created_at + 300);
+        assert!(!captcha.is_expired());
+
+        // seeded captchas are identical but for their nonce
+        let builder = builder.ttl(Duration::from_secs(10));
+        let first = builder.clone().build_captcha().unwrap();
+        let second = builder.build_captcha().unwrap();
+        assert_eq!(first.expires_at, first.created_at + 10);
+        assert_eq!(first.svg, second.svg);
+        assert_eq!(first.nonce.len(), 32);
+        assert_ne!(first.nonce, second.nonce);
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let (answer, _) = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .text("AbXy")
+            .case_insensitive(true)
+            .build()
+            .unwrap();
+        assert_eq!(answer, "abxy");
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .charset("aAbB")
+            .case_insensitive(true);
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let answer = builder.answer(&mut rng).unwrap();
+            assert_eq!(answer.len(), 4);
+            assert!(!answer.contains('A') && !answer.contains('B'));
+        }
+    }
+
+    #[test]
+    fn charset_kind() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let cases = [
+            (
+                Charset::Lower,
+                char::is_ascii_lowercase as fn(&char) -> bool,
+            ),
+            (Charset::Upper, char::is_ascii_uppercase),
+            (Charset::Digits, char::is_ascii_digit),
+        ];
+        for (charset, expected) in cases {
+            let builder = BiosvgBuilder::new().length(8).charset_kind(charset.clone());
+            assert_eq!(builder.case_insensitive, charset.case_insensitive());
+            let chars = builder.charset_chars().unwrap();
+            assert!(chars.iter().all(expected));
+            // the built-in font has no `o`, `l` or `i`
+            assert!(!chars.contains(&'o') && !chars.contains(&'l'));
+            assert_eq!(builder.answer(&mut rng).unwrap().len(), 8);
+        }
+        // the single case sets return the answer in lowercase
+        let (answer, _) = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string()])
+            .charset_kind(Charset::Upper)
+            .build_with_rng(&mut rng)
+            .unwrap();
+        assert!(answer.chars().all(|ch| ch.is_ascii_lowercase()));
+        assert_eq!(
+            BiosvgBuilder::new()
+                .charset_kind(Charset::Alphanumeric)
+                .charset_chars()
+                .unwrap(),
+            BiosvgBuilder::new().charset_chars().unwrap()
+        );
+        assert!(BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string()])
+            .charset_kind(Charset::Custom("o".to_string()))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn homoglyph_safe() {
+        let builder = BiosvgBuilder::new()
+            .length(6)
+            .charset("S5Z2B8")
+            .homoglyph_safe(true);
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let answer = builder.answer(&mut rng).unwrap();
+            assert_eq!(answer.chars().count(), 6);
+            for a in answer.chars() {
+                assert!(!answer.chars().any(|b| verify::confusable(a, b)));
+            }
+        }
+        assert!(verify::confusable('I', 'l') && verify::confusable('o', '0'));
+        assert!(!verify::confusable('S', 'S') && !verify::confusable('S', 'Z'));
+
+        let words = vec!["S5".to_string(), "ZZ".to_string()];
+        let builder = BiosvgBuilder::new().words(words).homoglyph_safe(true);
+        assert_eq!(builder.word(&mut rng).unwrap(), "ZZ");
+    }
+
+    #[test]
+    fn min_glyph_size() {
+        let font = Font::builtin()
+            .with_glyph('.', "M 0 0 L 10 0 L 10 10 L 0 10 Z")
+            .unwrap();
+        let builder = BiosvgBuilder::new()
+            .font(font)
+            .text("A.")
+            .colors(vec!["#0078D6".to_string()])
+            .rotation_range(0.0, 0.0)
+            .scale_range(1.0..1.0);
+        let sizes = |builder: BiosvgBuilder| {
+            let scene = builder.scene(&mut StdRng::seed_from_u64(0)).unwrap();
+            let size = |ch| {
+                let glyph = scene.glyphs.iter().find(|glyph| glyph.ch == ch).unwrap();
+                glyph.w.max(glyph.h)
+            };
+            size('.') / size('A')
+        };
+        // grown to 0.3 of the em height, about the height of `A`
+        let grown = sizes(builder.clone());
+        assert!(grown > 0.25 && grown < 0.35, "{}", grown);
+        assert!(sizes(builder.clone().min_glyph_size(0.0)) < 0.05);
+        assert!(builder.min_glyph_size(1.5).build().is_err());
+    }
+
+    #[test]
+    fn baseline_alignment() {
+        // `p` hangs below the baseline `x` sits on, `x` on its bottom
+        let (p, x) = (
+            font_glyph('p').unwrap().path(),
+            font_glyph('x').unwrap().path(),
+        );
+        assert!(p.baseline < p.height / 2.0 - p.height * 0.2);
+        assert!((x.baseline - x.height / 2.0).abs() < 1e-9);
+
+        let scene = BiosvgBuilder::new()
+            .text("xpx")
+            .colors(vec!["#0078D6".to_string()])
+            .rotation_range(0.0, 0.0)
+            .scale_range(1.0..1.0)
+            .skew_range(0.0..0.0)
+            .vertical_jitter(0.0)
+            .scene(&mut StdRng::seed_from_u64(0))
+            .unwrap();
+        let bottom = |ch| {
+            let glyph = scene.glyphs.iter().find(|glyph| glyph.ch == ch).unwrap();
+            glyph.y + glyph.h
+        };
+        assert!(bottom('p') > bottom('x') + p.height * 0.2);
+        #[cfg(feature = "font-serif")]
+        {
+            let serif = Font::from_builtin(BuiltinFont::Serif);
+            let n = serif.glyph('n').unwrap();
+            assert!((n.baseline - n.height / 2.0).abs() < 1.0);
+        }
+    }
+
+    #[test]
+    fn aria() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string()]);
+        let (_, svg) = builder.clone().build().unwrap();
+        assert!(!svg.contains("role=") && !svg.contains("<title>"));
+
+        let (_, svg) = builder
+            .clone()
+            .aria(AriaOptions::default())
+            .build()
+            .unwrap();
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains(r#" role="img" aria-label="captcha challenge">"#));
+        assert!(svg.contains("><title>captcha challenge</title>"));
+        assert_well_formed(&svg);
+
+        let aria = AriaOptions {
+            title: None,
+            desc: Some("type the <4> characters & submit".to_string()),
+            label: Some("\"quoted\"".to_string()),
+        };
+        let (_, svg) = builder.aria(aria).build().unwrap();
+        assert!(!svg.contains("<title>"));
+        assert!(svg.contains("<desc>type the &lt;4&gt; characters &amp; submit</desc>"));
+        assert!(svg.contains(r#"aria-label="&quot;quoted&quot;""#));
+        assert_well_formed(&svg);
+        // the texts read back as they were given
+        let document = roxmltree::Document::parse(&svg).unwrap();
+        let root = document.root_element();
+        assert_eq!(root.attribute("aria-label"), Some("\"quoted\""));
+        let desc = root.children().find(|node| node.has_tag_name("desc"));
+        assert_eq!(
+            desc.and_then(|desc| desc.text()),
+            Some("type the <4> characters & submit")
+        );
+    }
+
+    #[test]
+    fn id_prefix() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string()])
+            .char_colors(vec![ColorSpec::Gradient(vec![
+                "#aa3333".to_string(),
+                "#33aa00".to_string(),
+            ])])
+            .background_gradient(vec!["#ffffff".to_string(), "#eeeeee".to_string()]);
+        let (_, svg) = builder.clone().build().unwrap();
+        assert!(svg.contains(r#"id="biosvg-gradient-0""#));
+        assert!(svg.contains("url(#biosvg-background)"));
+
+        let (_, svg) = builder.clone().id_prefix("cap2").build().unwrap();
+        assert!(svg.contains(r#"id="cap2-gradient-0""#) && svg.contains("url(#cap2-gradient-0)"));
+        assert!(svg.contains(r#"id="cap2-background""#) && !svg.contains("biosvg-"));
+        let slider = builder.clone().id_prefix("cap2").build_slider().unwrap();
+        assert!(slider.piece.contains("url(#cap2-piece)"));
+
+        // random prefixes differ between captchas and follow the seed
+        let random = builder.clone().random_id_prefix(true);
+        let ids = |seed| {
+            let (_, svg) = random.clone().seed(seed).build().unwrap();
+            let start = svg.find(r#"id=""#).unwrap() + 4;
+            svg[start..start + svg[start..].find('"').unwrap()].to_string()
+        };
+        assert!(ids(1).starts_with("biosvg-"));
+        assert_ne!(ids(1), ids(2));
+        assert_eq!(ids(1), ids(1));
+
+        for prefix in ["", "1x", "a b", "a\"b"] {
+            assert!(matches!(
+                builder.clone().id_prefix(prefix).build(),
+                Err(model::PathError::Build(BuildError::InvalidIdPrefix(_)))
+            ));
+        }
+    }
+
+    /// Check that `xml` parses as a well-formed xml document with an svg
+    /// root element.
+    pub(crate) fn assert_well_formed(xml: &str) {
+        let document = match roxmltree::Document::parse(xml) {
+            Ok(document) => document,
+            Err(err) => panic!("{} in {}", err, xml),
+        };
+        let root = document.root_element();
+        assert_eq!(root.tag_name().name(), "svg", "{}", xml);
+    }
+
+    #[test]
+    fn well_formed() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(8)
+            .dots(5)
+            .colors(vec!["#0078D6".to_string(), "rgb(170, 51, 51)".to_string()])
+            .char_colors(vec![ColorSpec::Gradient(vec![
+                "red".to_string(),
+                "hsl(120, 50%, 40%)".to_string(),
+            ])])
+            .background_gradient(vec!["#ffffff".to_string(), "#eeeeee".to_string()])
+            .css_classes(true)
+            .css_style(".biosvg-noise { content: \"]]><script>&\" }")
+            .aria(AriaOptions {
+                title: Some("<b>captcha</b> & \"friends\"".to_string()),
+                desc: Some("]]>".to_string()),
+                label: Some("'&<>".to_string()),
+            })
+            .animate(AnimationStyle::DrawOn);
+        for builder in [builder.clone(), builder.clone().optimize(2)] {
+            let (_, svg) = builder.clone().build().unwrap();
+            assert_well_formed(&svg);
+            let (_, light, dark) = builder
+                .clone()
+                .dark_colors(vec!["#ffffff".to_string()])
+                .build_themed()
+                .unwrap();
+            assert_well_formed(&light);
+            assert_well_formed(&dark);
+            let slider = builder.clone().build_slider().unwrap();
+            assert_well_formed(&slider.background);
+            assert_well_formed(&slider.piece);
+        }
+
+        // colors are validated, and escaped if they get in another way
+        assert!(matches!(
+            builder
+                .colors(vec!["red\" onload=\"alert(1)".to_string()])
+                .build(),
+            Err(model::PathError::Build(BuildError::InvalidColor(_)))
+        ));
+        let path = model::Path::parse("M 0 0 L 10 10")
+            .unwrap()
+            .with_color("\"/><script>alert(1)</script>");
+        let svg = format!("<svg>{}</svg>", path);
+        assert_well_formed(&svg);
+        assert!(svg.contains("&quot;/&gt;&lt;script&gt;"));
+        assert_eq!(format::escape("plain"), "plain");
+    }
+
+    #[test]
+    fn entropy() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string()]);
+        let captcha = builder.clone().charset("2345").build_captcha().unwrap();
+        assert!((captcha.entropy_bits() - 8.0).abs() < 1e-9);
+        let captcha = builder.clone().text("ABCD").build_captcha().unwrap();
+        assert_eq!(captcha.entropy_bits(), 0.0);
+        let captcha = builder
+            .clone()
+            .kind(CaptchaKind::Arithmetic)
+            .length(2)
+            .build_captcha()
+            .unwrap();
+        assert!((captcha.entropy_bits() - 40f64.log2()).abs() < 1e-9);
+        let words = vec!["bread".to_string(), "cake".to_string(), "cake".to_string()];
+        let captcha = builder.clone().words(words).build_captcha().unwrap();
+        assert!((captcha.entropy_bits() - 1.0).abs() < 1e-9);
+        // `S` and `5` can't both be in the answer
+        let safe = builder.clone().charset("S5AB").homoglyph_safe(true);
+        assert!((safe.entropy_bits().unwrap() - (2.0 + 3.0)).abs() < 1e-9);
+        for terms in 2..=4 {
+            assert_eq!(
+                arithmetic::value_count(terms),
+                arithmetic::count_values(terms)
+            );
+        }
+        // longer expressions would take minutes to count and overflow
+        let arithmetic = builder.clone().kind(CaptchaKind::Arithmetic);
+        assert!(arithmetic.clone().length(8).build_captcha().is_ok());
+        assert!(matches!(
+            arithmetic.length(9).build_captcha(),
+            Err(model::PathError::Build(BuildError::TooManyTerms(9)))
+        ));
+
+        assert!(builder.clone().min_entropy_bits(20.0).build().is_ok());
+        assert!(matches!(
+            builder
+                .clone()
+                .charset("2345")
+                .min_entropy_bits(20.0)
+                .build(),
+            Err(model::PathError::Build(BuildError::LowEntropy(8)))
+        ));
+        assert!(builder.min_entropy_bits(f64::NAN).build().is_err());
+    }
+
+    #[test]
+    fn glyph_tables() {
+        // lookups binary search the tables, which only works while they are
+        // sorted
+        for (ch, glyph) in resource::font_glyphs() {
+            assert!(std::ptr::eq(font_glyph(*ch).unwrap(), glyph), "{}", ch);
+        }
+        assert!(font_glyph('~').is_none());
+        for name in icon_names() {
+            assert!(resource::icon(name).is_some(), "{}", name);
+        }
+        assert!(resource::icon("unicorn").is_none());
+    }
+
+    #[test]
+    fn icons() {
+        assert!(icon_names().len() >= 30);
+        let builder = BiosvgBuilder::new()
+            .colors(vec!["#0078D6".to_string()])
+            .seed(2);
+        for name in icon_names() {
+            let path = resource::icon(name).unwrap().path();
+            // flat icons like arrows are less than half as high
+            assert!(path.width.max(path.height) > 50.0, "{}", name);
+            assert!(path.width <= 100.0 && path.height <= 100.0, "{}", name);
+            let svg = builder.clone().build_icon(name).unwrap();
+            assert_well_formed(&svg);
+        }
+        assert_eq!(
+            builder.clone().build_icon("star").unwrap(),
+            builder.clone().build_icon("star").unwrap()
+        );
+        assert!(matches!(
+            builder.build_icon("unicorn"),
+            Err(model::PathError::Build(BuildError::UnknownIcon(name))) if name == "unicorn"
+        ));
+    }
+
+    #[test]
+    fn occlusion() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(2)
+            .colors(vec!["#0078D6".to_string()]);
+        assert!(matches!(
+            builder
+                .clone()
+                .occlusion(5)
+                .scene(&mut StdRng::seed_from_u64(0)),
+            Err(model::PathError::Build(
+                BuildError::OcclusionWithoutBackground
+            ))
+        ));
+        let builder = builder.background("#ffffff");
+        let plain = builder
+            .clone()
+            .scene(&mut StdRng::seed_from_u64(3))
+            .unwrap();
+        let scene = builder
+            .clone()
+            .occlusion(5)
+            .scene(&mut StdRng::seed_from_u64(3))
+            .unwrap();
+        assert_eq!(scene.paths.len(), plain.paths.len() + 5);
+        let occluders = scene
+            .paths
+            .iter()
+            .filter(|path| path.color == "#ffffff" && path.render_mode == RenderMode::Fill);
+        assert_eq!(occluders.count(), 5);
+        let (_, svg) = builder
+            .clone()
+            .occlusion(5)
+            .build_with_rng(&mut StdRng::seed_from_u64(3))
+            .unwrap();
+        assert_well_formed(&svg);
+        let scene = builder
+            .occlusion(5)
+            .accessibility(AccessibilityLevel::High)
+            .scene(&mut StdRng::seed_from_u64(3))
+            .unwrap();
+        assert!(scene.paths.iter().all(|path| path.color != "#ffffff"));
+    }
+
+    #[test]
+    fn scramble() {
+        let path =
+            model::Path::parse("M -10 -10 L 0 -10 C 0 -5 -5 0 -10 0 Z M 0 0 L 10 10").unwrap();
+        let reversed = path.reverse();
+        assert_eq!(reversed.sub_paths().len(), 2);
+        let points = |path: &model::Path| {
+            path.commands
+                .iter()
+                .map(|command| (command.x, command.y))
+                .collect::<Vec<(f64, f64)>>()
+        };
+        assert_eq!(points(&reversed)[..2], [(-10.0, -10.0), (-10.0, 0.0)]);
+        assert!(matches!(
+            reversed.commands[2].command_type,
+            model::CommandType::CubicTo(c1x, c1y, c2x, c2y) if (c1x, c1y, c2x, c2y) == (-5.0, 0.0, 0.0, -5.0)
+        ));
+        assert_eq!(
+            points(&reversed.reverse().sub_paths()[1]),
+            points(&path.sub_paths()[1])
+        );
+
+        let builder = BiosvgBuilder::new()
+            .length(5)
+            .char_colors(vec!["#111111"])
+            .colors(vec!["#eeeeee".to_string()]);
+        let sub_paths = |scene: &Scene| {
+            scene
+                .paths
+                .iter()
+                .filter(|path| path.color == "#111111")
+                .map(|path| path.sub_paths().len())
+                .collect::<Vec<usize>>()
+        };
+        let plain = builder
+            .clone()
+            .scene(&mut StdRng::seed_from_u64(4))
+            .unwrap();
+        let scene = builder
+            .clone()
+            .scramble(true)
+            .scene(&mut StdRng::seed_from_u64(4))
+            .unwrap();
+        assert_eq!(scene.answer, plain.answer);
+        let (plain_counts, counts) = (sub_paths(&plain), sub_paths(&scene));
+        assert_eq!(
+            counts.iter().sum::<usize>(),
+            plain_counts.iter().sum::<usize>()
+        );
+        assert!(counts.len() < plain_counts.len());
+        // packed sub-paths come from more than one character
+        let mixed = scene
+            .paths
+            .iter()
+            .filter(|path| path.color == "#111111")
+            .any(|path| {
+                let inside = |glyph: &GlyphBox| {
+                    path.sub_paths().iter().all(|sub_path| {
+                        let bounds = sub_path.bounds();
+                        bounds.min_x >= glyph.x - 1e-6 && bounds.max_x <= glyph.x + glyph.w + 1e-6
+                    })
+                };
+                !scene.glyphs.iter().any(inside)
+            });
+        assert!(mixed);
+        let (_, svg) = builder
+            .scramble(true)
+            .build_with_rng(&mut StdRng::seed_from_u64(4))
+            .unwrap();
+        assert_well_formed(&svg);
+    }
+
+    #[test]
+    fn nested_groups() {
+        let transform = model::Transform {
+            scale: 2.0,
+            angle: 0.5,
+            x: 3.0,
+            y: -4.0,
+        };
+        let inner = model::Transform {
+            scale: 0.5,
+            angle: -1.5,
+            x: 7.0,
+            y: 1.0,
+        };
+        let close = |(x1, y1): (f64, f64), (x2, y2): (f64, f64)| {
+            (x1 - x2).abs() < 1e-6 && (y1 - y2).abs() < 1e-6
+        };
+        let (x, y) = inner.apply(1.0, 2.0);
+        assert!(close(
+            transform.then(&inner).apply(1.0, 2.0),
+            transform.apply(x, y)
+        ));
+        let (moved_x, moved_y) = transform.apply(x, y);
+        assert!(close(transform.inverse().apply(moved_x, moved_y), (x, y)));
+        assert!(close(
+            transform.then(&transform.inverse()).apply(x, y),
+            (x, y)
+        ));
+
+        // compose the transforms written around every path and compare its
+        // start with the drawn geometry
+        let scene = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(3)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .nested_groups(3)
+            .scene(&mut StdRng::seed_from_u64(5))
+            .unwrap();
+        let svg = scene.to_svg();
+        assert_well_formed(&svg);
+        assert!(svg.contains("<g transform=\"translate("));
+        let numbers = |text: &str| {
+            text.split(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == 'e'))
+                .filter_map(|number| number.parse::<f64>().ok())
+                .collect::<Vec<f64>>()
+        };
+        let mut stack = vec![model::Transform::IDENTITY];
+        let mut starts = Vec::new();
+        for element in svg.split('<').skip(1) {
+            if let Some(transform) = element.strip_prefix("g transform=\"") {
+                let values = numbers(&transform[..transform.find('"').unwrap()]);
+                let inner = model::Transform {
+                    x: values[0],
+                    y: values[1],
+                    angle: values[2].to_radians(),
+                    scale: values[3],
+                };
+                stack.push(stack.last().unwrap().then(&inner));
+            } else if element.starts_with("/g") {
+                stack.pop();
+            } else if let Some(path) = element.split_once("d=\"M") {
+                let values = numbers(&path.1[..path.1.find(['L', 'Q', 'C', 'A']).unwrap()]);
+                starts.push((values[0], values[1], *stack.last().unwrap()));
+            }
+        }
+        assert_eq!(stack.len(), 1);
+        assert_eq!(starts.len(), scene.paths.len());
+        let mut moved = 0;
+        for ((x, y, transform), path) in starts.into_iter().zip(&scene.paths) {
+            let start = (path.commands[0].x, path.commands[0].y);
+            assert!(close(transform.apply(x, y), start));
+            if !close((x, y), start) {
+                moved += 1;
+            }
+        }
+        // scraped path data doesn't give the drawn positions
+        assert!(moved > scene.paths.len() / 2);
+    }
+
+    #[test]
+    fn traps() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .char_colors(vec!["#111111"])
+            .colors(vec!["#eeeeee".to_string()]);
+        let plain = builder
+            .clone()
+            .scene(&mut StdRng::seed_from_u64(2))
+            .unwrap();
+        let scene = builder
+            .clone()
+            .traps(3)
+            .scene(&mut StdRng::seed_from_u64(2))
+            .unwrap();
+        assert_eq!(scene.answer, plain.answer);
+        assert_eq!(scene.glyphs, plain.glyphs);
+        let traps = scene.paths.iter().filter(|path| path.opacity == 0.0);
+        assert!(traps.clone().count() >= 3);
+        assert!(traps.clone().all(|path| path.color == "#111111"));
+        assert!(scene.to_svg().contains("stroke-opacity=\"0\""));
+
+        // background colored traps are drawn before everything else
+        let builder = builder.background("#ffffff");
+        let mut hidden = 0;
+        for seed in 0..10 {
+            let scene = builder
+                .clone()
+                .traps(2)
+                .scene(&mut StdRng::seed_from_u64(seed))
+                .unwrap();
+            let first_visible = scene
+                .paths
+                .iter()
+                .position(|path| path.color != "#ffffff")
+                .unwrap();
+            let last_hidden = scene.paths.iter().rposition(|path| path.color == "#ffffff");
+            assert!(last_hidden.is_none_or(|last| last < first_visible));
+            hidden += first_visible;
+            let scene = builder
+                .clone()
+                .traps(2)
+                .dots(5)
+                .scene(&mut StdRng::seed_from_u64(seed))
+                .unwrap();
+            assert!(scene.paths.iter().all(|path| path.color != "#ffffff"));
+        }
+        assert!(hidden > 0);
+    }
+
+    #[cfg(feature = "raster")]
+    #[test]
+    fn traps_are_invisible() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .background("#ffffff")
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .traps(4);
+        for seed in 0..4 {
+            let mut scene = builder.scene(&mut StdRng::seed_from_u64(seed)).unwrap();
+            let png = raster::render_png(&scene, 0.1).unwrap();
+            let count = scene.paths.len();
+            scene
+                .paths
+                .retain(|path| path.opacity > 0.0 && path.color != "#ffffff");
+            assert!(scene.paths.len() < count);
+            assert_eq!(raster::render_png(&scene, 0.1).unwrap(), png);
+        }
+    }
+
+    #[test]
+    fn noise_counts() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .char_colors(vec!["#111111"])
+            .colors(vec!["#eeeeee".to_string()]);
+        let noise = |builder: BiosvgBuilder| {
+            let scene = builder.scene(&mut StdRng::seed_from_u64(1)).unwrap();
+            let noise = scene.paths.iter().filter(|path| path.color == "#eeeeee");
+            let lines = noise.clone().filter(|path| {
+                path.commands.iter().all(|command| {
+                    matches!(
+                        command.command_type,
+                        model::CommandType::Move | model::CommandType::LineTo
+                    )
+                })
+            });
+            (noise.count(), lines.count(), scene.dots.len())
+        };
+        assert_eq!(noise(builder.clone()), (0, 0, 0));
+        assert_eq!(noise(builder.clone().difficulty(3)).0, 3);
+        assert_eq!(
+            noise(builder.clone().noise_lines(2).noise_curves(3).noise_dots(4)),
+            (5, 2, 4)
+        );
+        assert_eq!(noise(builder.clone().difficulty(2).noise_lines(1)).0, 3);
+        assert_eq!(
+            noise(
+                builder
+                    .noise_lines(4)
+                    .noise_curves(2)
+                    .accessibility(AccessibilityLevel::High)
+            ),
+            (3, 2, 0)
+        );
+    }
+
+    #[test]
+    fn noise_is_curved() {
+        let (_, svg) = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(10)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .build()
+            .unwrap();
+        assert!(svg.contains(" Q ") || svg.contains(" C "));
+    }
+
+    #[test]
+    fn noise_styles() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(10)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let (_, svg) = builder
+            .clone()
+            .noise_style(NoiseStyle::Arcs)
+            .build()
+            .unwrap();
+        assert!(svg.contains(" A "));
+        let (_, svg) = builder.noise_style(NoiseStyle::Lines).build().unwrap();
+        assert!(!svg.contains(" A ") && !svg.contains(" Q ") && !svg.contains(" C "));
+    }
+
+    #[test]
+    fn noise_length_and_stroke_width() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..20 {
+            let path = noise::generate(
+                &mut rng,
+                NoiseStyle::Lines,
+                100.0,
+                10.0,
+                (2.0, 2.0),
+                &[],
+                "#000",
+            );
+            let (start, end) = (&path.commands[0], &path.commands[1]);
+            assert!(((end.x - start.x).hypot(end.y - start.y) - 20.0).abs() < 1e-9);
+            assert!(start.x >= 0.0 && end.x <= 100.0);
+            // longer than the canvas, so it crosses all of it
+            let path = noise::curve(&mut rng, 100.0, 10.0, 150.0, "#000");
+            assert!(path.commands[0].x <= 0.0 && path.commands[1].x >= 100.0);
+        }
+
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(6)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let strokes = |builder: BiosvgBuilder| {
+            let scene = builder.scene(&mut StdRng::seed_from_u64(1)).unwrap();
+            let mut strokes = scene
+                .paths
+                .iter()
+                .map(|path| path.stroke_width)
+                .collect::<Vec<f64>>();
+            strokes.sort_by(f64::total_cmp);
+            strokes
+        };
+        let thick = strokes(builder.clone().noise_stroke_width_range(3.0..3.0));
+        let thin = strokes(builder.clone().noise_stroke_width_range(0.1..0.1));
+        assert!(thick.last() > thin.last());
+        assert!(thin.first() < thick.first());
+        assert!(builder
+            .clone()
+            .noise_line_length_range(0.2..20.0)
+            .build()
+            .is_ok());
+        assert!(matches!(
+            builder.clone().noise_line_length_range(0.0..1.0).build(),
+            Err(model::PathError::InvalidRange("noise line length"))
+        ));
+        assert!(matches!(
+            builder.noise_stroke_width_range(1.0..0.5).build(),
+            Err(model::PathError::InvalidRange("noise stroke width"))
+        ));
+    }
+
+    #[test]
+    fn mimic_noise() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let glyph = &font_glyph('8').unwrap().path();
+        for _ in 0..20 {
+            let stroke = noise::mimic(&mut rng, glyph, 100.0, 10.0, 5.0, "#000");
+            assert!(matches!(
+                stroke.commands[0].command_type,
+                model::CommandType::Move
+            ));
+            assert!(stroke.commands.len() >= 2);
+            let center = stroke.bounds().center();
+            assert!((0.0..=100.0).contains(&center.0) && (0.0..=15.0).contains(&center.1));
+        }
+        // falls back to curves without glyphs
+        let stroke = noise::generate(
+            &mut rng,
+            NoiseStyle::Mimic,
+            100.0,
+            10.0,
+            (1.0, 1.0),
+            &[],
+            "#000",
+        );
+        assert_eq!(stroke.commands.len(), 2);
+
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(8)
+            .charset("23456789")
+            .noise_style(NoiseStyle::Mimic)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let (answer, svg) = builder.seed(6).build().unwrap();
+        assert_eq!(answer.len(), 4);
+        assert!(svg.contains("<path"));
+    }
+
+    #[test]
+    fn decoys() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .render_mode(RenderMode::Fill)
+            .char_colors(vec!["#111111"])
+            .colors(vec!["#eeeeee".to_string()]);
+        for seed in 0..5 {
+            let scene = builder
+                .clone()
+                .decoys(3)
+                .scene(&mut StdRng::seed_from_u64(seed))
+                .unwrap();
+            assert_eq!(scene.answer.chars().count(), 4);
+            assert_eq!(scene.glyphs.len(), 4);
+            assert_eq!(scene.paths.len(), 7);
+            let decoys = scene.paths.iter().filter(|path| path.color == "#eeeeee");
+            assert_eq!(decoys.count(), 3);
+        }
+        let scene = builder
+            .decoys(3)
+            .accessibility(AccessibilityLevel::High)
+            .scene(&mut StdRng::seed_from_u64(0))
+            .unwrap();
+        assert_eq!(scene.paths.len(), 4);
+    }
+
+    #[test]
+    fn opacity_range() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let (_, svg) = builder.clone().seed(1).build().unwrap();
+        assert!(!svg.contains("opacity"));
+        let scene = builder
+            .clone()
+            .opacity_range(0.4..0.8)
+            .scene(&mut StdRng::seed_from_u64(1))
+            .unwrap();
+        assert!(scene
+            .paths
+            .iter()
+            .all(|path| (0.4..=0.8).contains(&path.opacity)));
+        assert!(scene.to_svg().contains("stroke-opacity=\"0."));
+        let filled = model::Path::parse("M 0 0 L 1 1")
+            .unwrap()
+            .with_render_mode(RenderMode::Fill)
+            .with_opacity(0.5);
+        assert!(filled.to_string().contains("fill-opacity=\"0.5\""));
+        for range in [0.0..1.0, 0.8..0.4, 0.5..1.5] {
+            assert!(matches!(
+                builder.clone().opacity_range(range).build(),
+                Err(model::PathError::InvalidRange("opacity"))
+            ));
+        }
+    }
+
+    #[test]
+    fn dash() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let (_, svg) = builder.clone().seed(1).build().unwrap();
+        assert!(!svg.contains("stroke-dasharray"));
+        let scene = builder
+            .clone()
+            .dash(1.0)
+            .scene(&mut StdRng::seed_from_u64(1))
+            .unwrap();
+        assert!(scene
+            .paths
+            .iter()
+            .all(|path| matches!(path.dash.len(), 2 | 4)));
+        assert!(scene.to_svg().contains("stroke-dasharray=\""));
+        let filled = builder
+            .clone()
+            .dash(1.0)
+            .render_mode(RenderMode::Fill)
+            .difficulty(0)
+            .scene(&mut StdRng::seed_from_u64(1))
+            .unwrap();
+        assert!(filled.paths.iter().all(|path| path.dash.is_empty()));
+        for fraction in [-0.1, 1.5, f64::NAN] {
+            assert!(matches!(
+                builder.clone().dash(fraction).build(),
+                Err(model::PathError::InvalidRange("dash"))
+            ));
+        }
+    }
+
+    #[test]
+    fn split() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let paths = |builder: BiosvgBuilder| {
+            builder
+                .scene(&mut StdRng::seed_from_u64(3))
+                .unwrap()
+                .paths
+                .len()
+        };
+        assert_eq!(paths(builder.clone().split(false)), 4);
+        let fine = paths(builder.clone().split_segments_range(1..=1));
+        let coarse = paths(builder.clone().split_segments_range(6..=8));
+        assert!(fine > coarse && coarse > 4);
+        for range in [0..=2, RangeInclusive::new(4, 2)] {
+            assert!(matches!(
+                builder.clone().split_segments_range(range).build(),
+                Err(model::PathError::InvalidRange("split segments"))
+            ));
+        }
+    }
+
+    #[test]
+    fn animate() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let (_, svg) = builder.clone().seed(1).build().unwrap();
+        assert!(!svg.contains("<animate"));
+
+        let (_, svg) = builder
+            .clone()
+            .seed(1)
+            .animate(AnimationStyle::DrawOn)
+            .build()
+            .unwrap();
+        let paths = svg.matches("<path").count();
+        assert_eq!(
+            svg.matches("<animate attributeName=\"stroke-dashoffset\"")
+                .count(),
+            paths
+        );
+        assert_eq!(svg.matches("</path>").count(), paths);
+        assert!(svg.contains("stroke-dashoffset=\""));
+
+        let (_, svg) = builder
+            .clone()
+            .seed(1)
+            .animate(AnimationStyle::Wiggle)
+            .width(400)
+            .build()
+            .unwrap();
+        assert_eq!(
+            svg.matches("<animateTransform").count(),
+            svg.matches("<path").count()
+        );
+        assert!(svg.contains("repeatCount=\"indefinite\""));
+
+        let (_, svg) = builder
+            .seed(1)
+            .difficulty(0)
+            .render_mode(RenderMode::Fill)
+            .animate(AnimationStyle::DrawOn)
+            .build()
+            .unwrap();
+        assert!(!svg.contains("<animate"));
+    }
+
+    #[test]
+    fn css_classes() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(4)
+            .dots(3)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .seed(2);
+        let (_, svg) = builder.clone().build().unwrap();
+        assert!(!svg.contains("class=") && !svg.contains("<style>"));
+
+        let (_, svg) = builder
+            .css_classes(true)
+            .css_style(".biosvg-noise { opacity: 0.5 }")
+            .width(300)
+            .build()
+            .unwrap();
+        assert!(svg.contains("<style><![CDATA[.biosvg-noise { opacity: 0.5 }]]></style>"));
+        assert!(svg.contains("<path class=\"biosvg-glyph\""));
+        assert_eq!(svg.matches("<path class=\"biosvg-noise\"").count(), 4);
+        assert_eq!(svg.matches("<circle class=\"biosvg-noise\"").count(), 3);
+        assert_eq!(
+            svg.matches("<path class=").count(),
+            svg.matches("<path").count()
+        );
+        assert_well_formed(&svg);
+    }
+
+    #[test]
+    fn build_errors() {
+        let colors = vec!["#0078D6".to_string(), "#aa3333".to_string()];
+        let error = |builder: BiosvgBuilder| match builder.build() {
+            Err(PathError::Build(err)) => err,
+            other => panic!("unexpected {:?}", other.map(|(answer, _)| answer)),
+        };
+        assert_eq!(error(BiosvgBuilder::new().length(4)), BuildError::NoColors);
+        assert_eq!(
+            error(BiosvgBuilder::new().colors(colors.clone())),
+            BuildError::LengthZero
+        );
+        // the length is checked whatever the colors are
+        assert_eq!(error(BiosvgBuilder::new()), BuildError::LengthZero);
+        assert_eq!(
+            error(BiosvgBuilder::new().text("").colors(colors.clone())),
+            BuildError::LengthZero
+        );
+        assert_eq!(
+            error(
+                BiosvgBuilder::new()
+                    .length(4)
+                    .text("")
+                    .colors(colors.clone())
+            ),
+            BuildError::LengthZero
+        );
+        assert_eq!(
+            error(BiosvgBuilder::new().length(4).colors(vec![
+                "#0078D6".to_string(),
+                "red\" onload=\"alert(1)".to_string(),
+            ])),
+            BuildError::InvalidColor("red\" onload=\"alert(1)".to_string())
+        );
+        assert_eq!(
+            error(
+                BiosvgBuilder::new()
+                    .length(4)
+                    .colors(colors.clone())
+                    .background("")
+            ),
+            BuildError::InvalidColor(String::new())
+        );
+        assert_eq!(
+            error(BiosvgBuilder::new().colors(colors.clone()).text("a~")),
+            BuildError::UnsupportedCharacter('~')
+        );
+        assert_eq!(
+            BuildError::NoColors.to_string(),
+            PathError::from(BuildError::NoColors).to_string()
+        );
+        // fixed text and words don't need a length
+        assert!(BiosvgBuilder::new()
+            .colors(colors.clone())
+            .text("abc")
+            .build()
+            .is_ok());
+        assert!(BiosvgBuilder::new()
+            .colors(colors)
+            .kind(CaptchaKind::Words)
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn color_pools() {
+        let colors = ["#0078D6", "#aa3333", "#f08012"];
+        for n in 1..=colors.len() {
+            let colors = colors[..n].iter().map(|color| color.to_string()).collect();
+            let builder = BiosvgBuilder::new()
+                .length(4)
+                .difficulty(6)
+                .css_classes(true)
+                .colors(colors);
+            for seed in 0..20 {
+                let scene = builder.scene(&mut StdRng::seed_from_u64(seed)).unwrap();
+                let pool = |class: &str| {
+                    scene
+                        .paths
+                        .iter()
+                        .filter(|path| path.class == Some(class))
+                        .map(|path| path.color.as_str())
+                        .collect::<Vec<&str>>()
+                };
+                let (glyph_colors, noise_colors) = (pool("biosvg-glyph"), pool("biosvg-noise"));
+                assert!(!glyph_colors.is_empty());
+                assert_eq!(noise_colors.len(), 6);
+                if n > 1 {
+                    assert!(glyph_colors
+                        .iter()
+                        .all(|color| !noise_colors.contains(color)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dots() {
+        let (_, svg) = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .dots(30)
+            .build()
+            .unwrap();
+        assert_eq!(svg.matches("<circle").count(), 30);
+    }
+
+    #[test]
+    fn parse_path() {
+        use model::CommandType;
+
+        let path = model::Path::parse("M 10 20 L 5,6 H 30 V -4e1 Q 1 2 3 4 C 1 2 3 4 .5 -1.5E+1 Z")
+            .unwrap();
+        let commands = path
+            .commands
+            .iter()
+            // undo the centering of the path
+            .map(|command| command.offset(15.25, -10.0))
+            .map(|command| (command.x, command.y, command.command_type))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            commands,
+            vec![
+                (10.0, 20.0, CommandType::Move),
+                (5.0, 6.0, CommandType::LineTo),
+                (30.0, 6.0, CommandType::LineTo),
+                (30.0, -40.0, CommandType::LineTo),
+                (3.0, 4.0, CommandType::QuadTo(1.0, 2.0)),
+                (0.5, -15.0, CommandType::CubicTo(1.0, 2.0, 3.0, 4.0)),
+                (10.0, 20.0, CommandType::Close),
+            ]
+        );
+        // the letter of a repeated command can be left out
+        let path = model::Path::parse("M 0 0 10 0 10 10").unwrap();
+        assert_eq!(path.commands[2].command_type, CommandType::LineTo);
+        // relative, smooth and arc commands
+        let path =
+            model::Path::parse("m 10 10 l 5 0 c 0 5 5 5 5 0 s 5 -5 5 0 t 5 0 a 5 5 0 0110 0z")
+                .unwrap();
+        let ends = path
+            .commands
+            .iter()
+            .map(|command| command.x - path.commands[0].x)
+            .collect::<Vec<f64>>();
+        assert_eq!(ends, vec![0.0, 5.0, 10.0, 15.0, 20.0, 30.0, 0.0]);
+        let model::CommandType::CubicTo(c1x, c1y, _, _) = path.commands[3].command_type else {
+            panic!("s is a cubic curve");
+        };
+        assert_eq!(
+            (c1x - path.commands[2].x, c1y),
+            (0.0, path.commands[2].y - 5.0)
+        );
+        assert!(matches!(
+            path.commands[5].command_type,
+            model::CommandType::Arc {
+                large_arc: false,
+                sweep: true,
+                ..
+            }
+        ));
+        assert!(model::Path::parse("M 0 0 L 10").is_err());
+        assert!(model::Path::parse("M 0 0 X 10 10").is_err());
+        assert!(model::Path::parse("Z 1 1").is_err());
+
+        let font = Font::default()
+            .with_glyph('A', "M 0 100 L 50 0 L 100 100 M 25 50 H 75")
+            .unwrap();
+        let (answer, _) = BiosvgBuilder::new()
+            .length(3)
+            .font(font)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .build()
+            .unwrap();
+        assert_eq!(answer, "AAA");
+        assert!(Font::default().with_glyph('A', "M 0 0 Y").is_err());
+        // nothing to draw
+        for data in ["", "  ", "M 10 10", "M 0 0 m 5 5"] {
+            assert!(matches!(
+                Font::default().with_glyph('#', data),
+                Err(PathError::ParseError)
+            ));
+        }
+        // centered on the box of the data, wherever the origin is
+        let glyph = model::Path::parse("M 100 200 L 300 200 L 300 600").unwrap();
+        assert_eq!((glyph.width, glyph.height), (200.0, 400.0));
+        let bounds = glyph.bounds();
+        assert_eq!((bounds.min_x, bounds.max_x), (-100.0, 100.0));
+        assert_eq!((bounds.min_y, bounds.max_y), (-200.0, 200.0));
+        assert_eq!(glyph.baseline, 200.0);
+        let empty = model::Path::parse("").unwrap();
+        let pieces = empty.random_split(&mut rand::thread_rng(), 1..=3);
+        assert_eq!(pieces.len(), 1);
+        assert!(pieces[0].commands.is_empty());
+    }
+
+    #[test]
+    fn flatten() {
+        use model::CommandType;
+
+        let path = model::Path::parse("M 0 0 Q 50 100 100 0 A 50 50 0 0 1 200 0 L 200 50").unwrap();
+        let flat = path.flatten(0.1);
+        assert!(flat.commands.iter().all(|command| matches!(
+            command.command_type,
+            CommandType::Move | CommandType::LineTo
+        )));
+        // the quadratic curve peaks at half its control point height
+        let (x0, y0) = (flat.commands[0].x, flat.commands[0].y);
+        let peak = flat
+            .commands
+            .iter()
+            .filter(|command| command.x - x0 <= 100.0)
+            .map(|command| command.y - y0)
+            .fold(0.0, f64::max);
+        assert!((peak - 50.0).abs() < 0.1);
+        // the arc stays on its circle
+        assert!(flat
+            .commands
+            .iter()
+            .filter(|command| command.x - x0 > 100.0 && command.y - y0 <= 0.0)
+            .all(|command| ((command.x - x0 - 150.0).hypot(command.y - y0) - 50.0).abs() < 0.1));
+        assert_eq!(flat.commands.last().unwrap().y - y0, 50.0);
+        assert!(flat.commands.len() > 10);
+        assert_eq!(path.flatten(1000.0).commands.len(), 5);
+    }
+
+    #[test]
+    fn rotated_bounds() {
+        let path = model::Path::parse("M -10 -50 L 10 50").unwrap();
+        let rotated = path.rotate(std::f64::consts::FRAC_PI_2);
+        let bounds = rotated.bounds();
+        assert!((bounds.width() - 100.0).abs() < 1e-9);
+        assert!((bounds.height() - 20.0).abs() < 1e-9);
+        assert_eq!(rotated.height, 100.0);
+
+        // strongly rotated glyphs stay on the canvas
+        let builder = BiosvgBuilder::new()
+            .length(6)
+            .rotation_range(-1.2, 1.2)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        for seed in 0..10 {
+            let scene = builder.scene(&mut StdRng::seed_from_u64(seed)).unwrap();
+            for glyph in &scene.glyphs {
+                assert!(glyph.x >= 0.0 && glyph.x + glyph.w <= scene.width);
+                assert!(glyph.y >= 0.0 && glyph.y + glyph.h <= scene.height);
+            }
+            for pair in scene.glyphs.windows(2) {
+                assert!(pair[0].x + pair[0].w <= pair[1].x + 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn layout() {
+        let builder = BiosvgBuilder::new()
+            .length(6)
+            .rotation_range(-0.8, 0.8)
+            .scale_range(0.5..2.0)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let inside = |glyph: &GlyphBox, width: f64, height: f64| {
+            glyph.x >= 0.0
+                && glyph.x + glyph.w <= width
+                && glyph.y >= 0.0
+                && glyph.y + glyph.h <= height
+        };
+        for seed in 0..10 {
+            let packed = builder.clone().overlap(0.5).char_spacing(-1.0).seed(seed);
+            let scene = packed.scene(&mut StdRng::seed_from_u64(seed)).unwrap();
+            for pair in scene.glyphs.windows(2) {
+                // never more than half of the narrower glyph
+                let covered = pair[0].x + pair[0].w - pair[1].x;
+                assert!(covered <= pair[0].w.min(pair[1].w) * 0.5 + 1e-9);
+            }
+            assert!(scene
+                .glyphs
+                .iter()
+                .all(|glyph| inside(glyph, scene.width, scene.height)));
+
+            let click = builder.clone().seed(seed).build_click().unwrap();
+            assert!(click
+                .glyphs
+                .iter()
+                .all(|glyph| inside(glyph, click.width, click.height)));
+            for pair in click.glyphs.windows(2) {
+                assert!(pair[0].x + pair[0].w <= pair[1].x + 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn vertical_jitter() {
+        let builder = BiosvgBuilder::new()
+            .length(6)
+            .rotation_range(0.0, 0.0)
+            .scale_range(1.0..1.0)
+            .skew_range(0.0..0.0)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        // the glyphs are centered on their box, so this is the baseline
+        let centers = |builder: BiosvgBuilder| {
+            let scene = builder.scene(&mut StdRng::seed_from_u64(5)).unwrap();
+            let centers = scene
+                .glyphs
+                .iter()
+                .map(|glyph| {
+                    glyph.y + glyph.h / 2.0 + font_glyph(glyph.ch).unwrap().path().baseline
+                })
+                .collect::<Vec<f64>>();
+            (scene, centers)
+        };
+        let (_, flat) = centers(builder.clone().vertical_jitter(0.0));
+        assert!(flat.windows(2).all(|pair| (pair[0] - pair[1]).abs() < 1e-9));
+
+        let (scene, jittered) = centers(builder.clone().vertical_jitter(1.0));
+        let spread = jittered.iter().fold(f64::NEG_INFINITY, |a, b| a.max(*b))
+            - jittered.iter().fold(f64::INFINITY, |a, b| a.min(*b));
+        assert!(spread > 1.0);
+        assert!(scene
+            .glyphs
+            .iter()
+            .all(|glyph| glyph.y >= 0.0 && glyph.y + glyph.h <= scene.height));
+
+        for jitter in [-0.1, f64::NAN] {
+            assert!(matches!(
+                builder.clone().vertical_jitter(jitter).build(),
+                Err(model::PathError::InvalidRange("vertical jitter"))
+            ));
+        }
+    }
+
+    #[test]
+    fn wave() {
+        let path = model::Path::parse("M 0.0 0.0 L 100.0 0.0").unwrap();
+        let waved = path.wave(10.0, std::f64::consts::TAU / 100.0, 0.0);
+        assert!(waved.commands.len() > path.commands.len());
+        assert!(waved
+            .commands
+            .iter()
+            .any(|command| (command.y - path.commands[0].y).abs() > 5.0));
+        let (_, svg) = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .wave(0.1, 1.0)
+            .build()
+            .unwrap();
+        assert!(svg.starts_with("<svg"));
+        for (amplitude, frequency) in [
+            (0.1, f64::NAN),
+            (f64::INFINITY, 1.0),
+            (-0.1, 1.0),
+            (0.1, 0.0),
+            (0.1, f64::INFINITY),
+        ] {
+            assert!(matches!(
+                BiosvgBuilder::new()
+                    .length(4)
+                    .colors(vec!["#0078D6".to_string()])
+                    .wave(amplitude, frequency)
+                    .build(),
+                Err(model::PathError::InvalidRange("wave"))
+            ));
+        }
+    }
+
+    #[test]
+    fn rotation_range() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        assert!(builder.clone().rotation_range(0.0, 0.0).build().is_ok());
+        assert!(matches!(
+            builder.rotation_range(0.5, -0.5).build(),
+            Err(model::PathError::InvalidRange("rotation"))
+        ));
+    }
+
+    #[test]
+    fn scale_range() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        assert!(builder.clone().scale_range(1.0..1.0).build().is_ok());
+        for range in [0.0..1.0, -1.0..1.0, 1.2..0.8, 1.0..f64::INFINITY] {
+            assert!(matches!(
+                builder.clone().scale_range(range).build(),
+                Err(model::PathError::InvalidRange("scale"))
+            ));
+        }
+    }
+
+    #[test]
+    fn skew() {
+        let path = model::Path::parse("M 0 0 L 0 10 Q 10 10 10 0 A 5 5 0 0 1 0 0").unwrap();
+        let skewed = path.skew(0.5, 0.0);
+        assert!(skewed
+            .commands
+            .iter()
+            .all(|command| !matches!(command.command_type, model::CommandType::Arc { .. })));
+        let command = model::Command::new(2.0, 4.0, model::CommandType::QuadTo(1.0, 2.0));
+        let command = command.skew(0.5, -1.0);
+        assert_eq!((command.x, command.y), (4.0, 2.0));
+        assert!(matches!(
+            command.command_type,
+            model::CommandType::QuadTo(x, y) if x == 2.0 && y == 1.0
+        ));
+
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        assert!(builder.clone().skew_range(0.0..0.0).build().is_ok());
+        assert!(builder.clone().skew_range(-0.6..0.6).build().is_ok());
+        for range in [0.5..-0.5, f64::NAN..0.0] {
+            assert!(matches!(
+                builder.clone().skew_range(range).build(),
+                Err(model::PathError::InvalidRange("skew"))
+            ));
+        }
+    }
+
+    #[test]
+    fn spacing_and_overlap() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .seed(3);
+        let default = builder.clone().build_captcha().unwrap();
+        let packed = builder.clone().overlap(0.3).build_captcha().unwrap();
+        let spread = builder.clone().char_spacing(1.0).build_captcha().unwrap();
+        assert!(packed.width < default.width);
+        assert!(spread.width > default.width);
+        assert!(matches!(
+            builder.overlap(1.0).build(),
+            Err(model::PathError::InvalidRange("overlap"))
+        ));
+    }
+
+    #[test]
+    fn output_size() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let captcha = builder
+            .clone()
+            .width(200)
+            .height(80)
+            .padding(5)
+            .build_captcha()
+            .unwrap();
+        assert_eq!((captcha.width, captcha.height), (200.0, 80.0));
+        assert!(captcha.svg.contains(r#"viewBox="0 0 200 80""#));
+        let captcha = builder.clone().height(60).build_captcha().unwrap();
+        assert_eq!(captcha.height, 60.0);
+        assert!(captcha.width > 60.0);
+
+        // no room left for the captcha
+        assert!(matches!(
+            builder.clone().width(0).build(),
+            Err(model::PathError::InvalidRange("width"))
+        ));
+        assert!(matches!(
+            builder.clone().height(0).build(),
+            Err(model::PathError::InvalidRange("height"))
+        ));
+        assert!(matches!(
+            builder.clone().height(60).padding(30).build(),
+            Err(model::PathError::InvalidRange("padding"))
+        ));
+        assert!(matches!(
+            builder
+                .clone()
+                .width(200)
+                .height(80)
+                .padding(u32::MAX)
+                .build(),
+            Err(model::PathError::InvalidRange("padding"))
+        ));
+        assert!(builder.height(60).padding(29).build().is_ok());
+    }
+
+    #[test]
+    fn background() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let (_, svg) = builder.clone().background("#ffffff").build().unwrap();
+        assert!(svg.contains(r##"fill="#ffffff""##));
+        let (_, svg) = builder
+            .background_gradient(vec!["#ffffff".to_string(), "#eeeeff".to_string()])
+            .build()
+            .unwrap();
+        assert!(svg.contains("<linearGradient"));
+        assert!(svg.contains(r##"fill="url(#biosvg-background)""##));
+    }
+
+    #[test]
+    fn gradient_char_colors() {
+        let (_, svg) = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#aa3333".to_string()])
+            .char_colors(vec![ColorSpec::Gradient(vec![
+                "#0078D6".to_string(),
+                "#33aa00".to_string(),
+            ])])
+            .build()
+            .unwrap();
+        assert_eq!(svg.matches("<linearGradient").count(), 4);
+        assert!(svg.contains(r##"stroke="url(#biosvg-gradient-0)""##));
+    }
+
+    #[test]
+    fn build_themed() {
+        let (answer, light, dark) = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .dark_colors(vec!["#66bbff".to_string(), "#ff8888".to_string()])
+            .dark_background("#111111")
+            .build_themed()
+            .unwrap();
+        assert_eq!(answer.len(), 4);
+        assert!(!light.contains("#66bbff") && !light.contains("#111111"));
+        assert!(!dark.contains("#0078D6") && !dark.contains("#aa3333"));
+        assert!(dark.contains("#111111"));
+        // same layout, only the colors differ
+        let strip = |svg: &str| {
+            svg.replace("#0078D6", "a")
+                .replace("#66bbff", "a")
+                .replace("#aa3333", "b")
+                .replace("#ff8888", "b")
+        };
+        let (light, dark) = (strip(&light), strip(&dark));
+        assert!(dark.ends_with(&light[light.find("<path").unwrap()..]));
+    }
+
+    #[test]
+    fn stroke_width() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .noise_style(NoiseStyle::Lines);
+        let widths = |svg: &str| {
+            svg.split(r#"stroke-width=""#)
+                .skip(1)
+                .map(|s| s[..s.find('"').unwrap()].parse::<f64>().unwrap())
+                .collect::<Vec<f64>>()
+        };
+        let (_, svg) = builder.clone().build().unwrap();
+        let mut auto = widths(&svg);
+        auto.sort_by(f64::total_cmp);
+        auto.dedup();
+        // one weight for glyphs and one for noise
+        assert_eq!(auto.len(), 2);
+        let (_, svg) = builder
+            .clone()
+            .height(100)
+            .stroke_width(StrokeWidth::Fixed(3.0))
+            .build()
+            .unwrap();
+        assert!(widths(&svg).iter().any(|width| (width - 3.0).abs() < 1e-9));
+        assert!(matches!(
+            builder.stroke_width(StrokeWidth::Fixed(-1.0)).build(),
+            Err(model::PathError::InvalidRange("stroke width"))
+        ));
+    }
+
+    #[test]
+    fn render_mode() {
+        let path = model::Path::parse("M 0.0 0.0 L 10.0 0.0 L 10.0 10.0 M 20.0 0.0 L 30.0 0.0")
+            .unwrap()
+            .close();
+        assert_eq!(
+            path.commands
+                .iter()
+                .filter(|command| command.command_type == model::CommandType::Close)
+                .count(),
+            2
+        );
+        let (_, svg) = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string()])
+            .render_mode(RenderMode::Fill)
+            .build()
+            .unwrap();
+        assert!(svg.contains(" Z"));
+        assert!(svg.contains(r##"fill="#0078D6""##));
+    }
+
+    #[test]
+    fn custom_font() {
+        let font = Font::builtin();
+        assert!(font.contains('A'));
+        let (answer, _) = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .font(font)
+            .build()
+            .unwrap();
+        assert_eq!(answer.len(), 4);
+        let result = BiosvgBuilder::new()
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .font(Font::default())
+            .text("A")
+            .build();
+        assert!(matches!(
+            result,
+            Err(model::PathError::Build(BuildError::UnsupportedCharacter(
+                'A'
+            )))
+        ));
+    }
+
+    #[test]
+    fn mixed_fonts() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .font(Font::default())
+            .font(Font::builtin());
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!(builder.has_glyph('A'));
+        assert!(builder.glyph('A', &mut rng).is_some());
+        assert_eq!(builder.build().unwrap().0.len(), 4);
+    }
+
+    #[cfg(feature = "font-serif")]
+    #[test]
+    fn builtin_fonts() {
+        for builtin in [BuiltinFont::Mono, BuiltinFont::Serif] {
+            let font = Font::from_builtin(builtin);
+            assert!(resource::FONT_TABLE.iter().all(|ch| font.contains(*ch)));
+        }
+        let (answer, _) = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .builtin_font(BuiltinFont::Serif)
+            .builtin_font(BuiltinFont::Mono)
+            .build()
+            .unwrap();
+        assert_eq!(answer.len(), 4);
+    }
+
+    #[test]
+    fn unicode_charset() {
+        let err = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .charset_unicode(vec!['验', '证'])
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            model::PathError::Build(BuildError::UnsupportedCharacter('验'))
+        ));
+
+        #[cfg(feature = "cyrillic")]
+        {
+            let charset = vec!['Ж', 'Щ', 'ф', 'ю'];
+            let (answer, _) = BiosvgBuilder::new()
+                .length(4)
+                .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+                .charset_unicode(charset.clone())
+                .build()
+                .unwrap();
+            assert_eq!(answer.chars().count(), 4);
+            assert!(answer.chars().all(|ch| charset.contains(&ch)));
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        use arithmetic::{Expression, Operator};
+
+        let expression = Expression {
+            operands: vec![3, 7, 2, 4],
+            operators: vec![Operator::Add, Operator::Mul, Operator::Sub],
+        };
+        assert_eq!(expression.to_string(), "3+7×2-4");
+        assert_eq!(expression.value(), 13);
+
+        let (answer, svg) = BiosvgBuilder::new()
+            .length(3)
+            .kind(CaptchaKind::Arithmetic)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .seed(7)
+            .build()
+            .unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+        let expression = Expression::random(&mut rng, 3);
+        assert_eq!(answer, expression.value().to_string());
+        assert!(expression.value() >= 0);
+        assert!(svg.starts_with("<svg"));
+    }
+
+    #[test]
+    fn word_list() {
+        let words = vec!["garden".to_string(), "zebra".to_string()];
+        let (answer, _) = BiosvgBuilder::new()
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .words(words.clone())
+            .exclude_chars("z")
+            .build()
+            .unwrap();
+        assert_eq!(answer, "garden");
+
+        let (answer, _) = BiosvgBuilder::new()
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .kind(CaptchaKind::Words)
+            .build()
+            .unwrap();
+        assert!(WORD_TABLE.contains(&answer.as_str()));
+
+        let err = BiosvgBuilder::new()
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .words(vec!["horse".to_string()])
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            model::PathError::Build(BuildError::UnsupportedCharacter('o'))
+        ));
+    }
+
+    #[test]
+    fn click_captcha() {
+        let click = BiosvgBuilder::new()
+            .length(5)
+            .difficulty(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .width(400)
+            .seed(3)
+            .build_click()
+            .unwrap();
+        assert_eq!(click.glyphs.len(), 5);
+        let mut chars = click
+            .glyphs
+            .iter()
+            .map(|glyph| glyph.ch)
+            .collect::<Vec<char>>();
+        chars.sort();
+        chars.dedup();
+        assert_eq!(chars.len(), 5);
+        for glyph in &click.glyphs {
+            assert!(glyph.x >= 0.0 && glyph.x + glyph.w <= click.width);
+            assert!(glyph.y >= 0.0 && glyph.y + glyph.h <= click.height);
+        }
+        let target = click.target_box().unwrap();
+        assert!(click.is_hit(target.x + target.w / 2.0, target.y + target.h / 2.0));
+        assert!(!click.is_hit(-1.0, -1.0));
+    }
+
+    #[test]
+    fn ensure_contrast() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        assert!(builder
+            .clone()
+            .ensure_contrast("white", 4.5)
+            .build()
+            .is_ok());
+        let err = builder
+            .clone()
+            .char_colors(vec!["#eeeeee"])
+            .ensure_contrast("#ffffff", 4.5)
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            PathError::Build(BuildError::LowContrast(color)) if color == "#eeeeee"
+        ));
+        assert!(matches!(
+            builder.clone().ensure_contrast("#1e1e1e", 4.5).build(),
+            Err(PathError::Build(BuildError::LowContrast(_)))
+        ));
+        assert!(matches!(
+            builder.clone().ensure_contrast("#ffffff", 30.0).build(),
+            Err(PathError::InvalidRange("contrast"))
+        ));
+        assert!(matches!(
+            builder.clone().ensure_contrast("nope", 4.5).build(),
+            Err(PathError::Build(BuildError::InvalidColor(_)))
+        ));
+        assert!(matches!(
+            builder.clone().ensure_contrast("transparent", 4.5).build(),
+            Err(PathError::Build(BuildError::InvalidColor(_)))
+        ));
+        // colors without a contrast can't be checked
+        assert!(matches!(
+            builder
+                .char_colors(vec!["currentColor"])
+                .ensure_contrast("white", 4.5)
+                .build(),
+            Err(PathError::Build(BuildError::LowContrast(color))) if color == "currentColor"
+        ));
+    }
+
+    #[test]
+    fn noise_colors() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(6)
+            .dots(3)
+            .css_classes(true)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .noise_colors(vec!["#999999".to_string()]);
+        let scene = builder.scene(&mut StdRng::seed_from_u64(7)).unwrap();
+        for path in &scene.paths {
+            let noise = path.class == Some("biosvg-noise");
+            assert_eq!(noise, path.color == "#999999");
+        }
+        assert!(scene.dots.iter().all(|dot| dot.color == "#999999"));
+
+        let scene = builder
+            .char_colors(vec!["#33aa00"])
+            .scene(&mut StdRng::seed_from_u64(7))
+            .unwrap();
+        for path in &scene.paths {
+            let expected = if path.class == Some("biosvg-noise") {
+                "#999999"
+            } else {
+                "#33aa00"
+            };
+            assert_eq!(path.color, expected);
+        }
+    }
+
+    #[test]
+    fn distinct_adjacent_colors() {
+        let builder = BiosvgBuilder::new()
+            .length(8)
+            .split(false)
+            .colors(vec!["#0078D6".to_string()])
+            .char_colors(vec!["#aa3333", "#33aa00"])
+            .distinct_adjacent_colors(true);
+        for seed in 0..20 {
+            let scene = builder.scene(&mut StdRng::seed_from_u64(seed)).unwrap();
+            // the paths are shuffled, order the glyphs left to right
+            let mut glyphs = scene
+                .paths
+                .iter()
+                .filter(|path| path.color != "#0078D6")
+                .collect::<Vec<&model::Path>>();
+            glyphs.sort_by(|a, b| a.bounds().center().0.total_cmp(&b.bounds().center().0));
+            let colors = glyphs
+                .iter()
+                .map(|path| path.color.as_str())
+                .collect::<Vec<&str>>();
+            assert_eq!(colors.len(), 8);
+            assert!(colors.windows(2).all(|pair| pair[0] != pair[1]));
+        }
+    }
+
+    #[test]
+    fn generator() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CaptchaGenerator>();
+
+        assert!(matches!(
+            BiosvgBuilder::new().length(4).compile(),
+            Err(PathError::Build(BuildError::NoColors))
+        ));
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let generator = builder.clone().compile().unwrap();
+        let captchas = std::thread::scope(|scope| {
+            let handles = (0..4)
+                .map(|_| scope.spawn(|| generator.generate().unwrap()))
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<Captcha>>()
+        });
+        assert!(captchas.iter().all(|captcha| captcha.answer.len() == 4));
+
+        let captcha = generator
+            .generate_with_rng(&mut StdRng::seed_from_u64(5))
+            .unwrap();
+        let expected = builder
+            .build_captcha_with_rng(&mut StdRng::seed_from_u64(5))
+            .unwrap();
+        assert_eq!(
+            (captcha.answer, captcha.svg),
+            (expected.answer, expected.svg)
+        );
+    }
+
+    #[test]
+    fn global_generator() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        let builder = generator::from_env(env(&[
+            ("BIOSVG_LENGTH", "6"),
+            ("BIOSVG_COLORS", "#0078D6, red"),
+        ]))
+        .unwrap();
+        assert_eq!(builder.length, 6);
+        assert_eq!(builder.difficulty, 6);
+        assert_eq!(builder.colors, vec!["#0078D6", "red"]);
+        assert!(matches!(
+            generator::from_env(env(&[("BIOSVG_DIFFICULTY", "many")])),
+            Err(PathError::Build(BuildError::InvalidEnv(
+                "BIOSVG_DIFFICULTY"
+            )))
+        ));
+
+        let builder = BiosvgBuilder::new()
+            .length(5)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        assert!(set_global(builder.clone()).is_ok());
+        assert_eq!(generate().unwrap().answer.len(), 5);
+        assert!(std::ptr::eq(global().unwrap(), global().unwrap()));
+        assert!(matches!(
+            set_global(builder),
+            Err(PathError::Build(BuildError::GlobalInitialized))
+        ));
+    }
+
+    #[test]
+    fn optimize() {
+        let format = Format {
+            precision: Some(2),
+            compact: true,
+        };
+        assert_eq!(format.number(-0.001), "0");
+        assert_eq!(format.number(0.456), ".46");
+        assert_eq!(format.number(-0.25), "-.25");
+        assert_eq!(format.number(12.5), "12.5");
+        let command = |x, y, command_type| model::Command { x, y, command_type };
+        let commands = [
+            command(10.0, 10.0, model::CommandType::Move),
+            command(20.0, 10.0, model::CommandType::LineTo),
+            command(30.0, 10.0, model::CommandType::LineTo),
+            command(30.004, 25.5, model::CommandType::LineTo),
+            command(50.0, 20.25, model::CommandType::QuadTo(40.0, 30.0)),
+            command(10.0, 10.0, model::CommandType::Close),
+            command(10.5, 10.0, model::CommandType::LineTo),
+        ];
+        let path_data = |commands: &[model::Command]| {
+            let mut data = String::new();
+            format.write_path_data(&mut data, commands).unwrap();
+            data
+        };
+        assert_eq!(path_data(&commands), "m10 10h20v15.5q10 4.5 20-5.25zh.5");
+
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(6)
+            .dots(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let (_, full) = builder.clone().seed(1).build().unwrap();
+        let (_, optimized) = builder.clone().optimize(2).seed(1).build().unwrap();
+        assert!(optimized.len() * 3 < full.len() * 2);
+        // at most 2 decimal places after every decimal point
+        assert!(optimized.split('.').skip(1).all(|fraction| fraction
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .count()
+            <= 2));
+
+        // the optimized paths have the same shape
+        let scene = builder
+            .optimize(2)
+            .scene(&mut StdRng::seed_from_u64(1))
+            .unwrap();
+        for path in &scene.paths {
+            let data = path_data(&path.commands);
+            let parsed = model::Path::parse(&data).unwrap().bounds();
+            let bounds = path.bounds();
+            assert!((parsed.width() - bounds.width()).abs() < 0.05);
+            assert!((parsed.height() - bounds.height()).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn precision() {
+        let (_, svg) = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(6)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .precision(2)
+            .build()
+            .unwrap();
+        let width = svg.split('"').nth(1).unwrap();
+        assert!(width.parse::<f64>().is_ok());
+        assert!(width.find('.').is_none_or(|dot| width.len() - dot <= 3));
+        assert!(svg.contains("d=\"M "));
+        for number in svg.split(|ch: char| !(ch.is_ascii_digit() || ch == '.')) {
+            assert!(number.find('.').is_none_or(|dot| number.len() - dot <= 3));
+        }
+    }
+
+    #[test]
+    fn write_svg() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(6)
+            .dots(3)
+            .background("#ffffff")
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .seed(9);
+        let mut streamed = Vec::new();
+        let answer = builder.write_svg(&mut streamed).unwrap();
+        let (expected_answer, expected) = builder.clone().build().unwrap();
+        assert_eq!(answer, expected_answer);
+        assert_eq!(String::from_utf8(streamed).unwrap(), expected);
+
+        let mut streamed = Vec::new();
+        let generator = builder.compile().unwrap();
+        generator.write_svg(&mut streamed).unwrap();
+        assert_eq!(String::from_utf8(streamed).unwrap(), expected);
+
+        let full = &mut [0u8; 64][..];
+        assert!(matches!(
+            generator.write_svg(full),
+            Err(PathError::Io(err)) if err.kind() == io::ErrorKind::WriteZero
+        ));
+    }
+
+    #[test]
+    fn caller_rng() {
+        // every random choice comes from the generator of the caller
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(6)
+            .dots(3)
+            .decoys(1)
+            .opacity_range(0.6..1.0)
+            .dash(0.3)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let rng = || StdRng::seed_from_u64(11);
+        let svg = |builder: BiosvgBuilder| builder.build_with_rng(&mut rng()).unwrap();
+        assert_eq!(svg(builder.clone()), svg(builder.clone()));
+        let click = |builder: BiosvgBuilder| builder.build_click_with_rng(&mut rng()).unwrap();
+        let (a, b) = (click(builder.clone()), click(builder.clone()));
+        assert_eq!((a.svg, a.target), (b.svg, b.target));
+        let slider = |builder: BiosvgBuilder| {
+            let slider = builder.build_slider_with_rng(&mut rng()).unwrap();
+            (slider.background, slider.piece, slider.x)
+        };
+        assert_eq!(slider(builder.clone()), slider(builder.clone()));
+        let batch = |builder: BiosvgBuilder| {
+            builder
+                .build_batch_with_rng(&mut rng(), 3)
+                .unwrap()
+                .into_iter()
+                .map(|captcha| captcha.svg)
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(batch(builder.clone()), batch(builder));
+    }
+
+    #[test]
+    fn high_accessibility() {
+        assert_eq!(color::contrast_ratio("#000", "#ffffff"), Some(21.0));
+        assert_eq!(color::contrast_ratio("nope", "#ffffff"), None);
+
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(6)
+            .colors(vec!["#0078D6".to_string(), "#eeeeee".to_string()])
+            .char_colors(vec!["#0078D6", "#eeeeee"])
+            .rotation_range(-1.0, 1.0)
+            .accessibility(AccessibilityLevel::High);
+        assert_eq!(
+            builder.contrasting_colors(builder.char_colors.clone()),
+            vec![ColorSpec::from("#0078D6")]
+        );
+        let builder = builder.background("#333333");
+        assert_eq!(
+            builder.contrasting_colors(builder.char_colors.clone()),
+            vec![ColorSpec::from("#eeeeee")]
+        );
+        let (_, svg) = builder.build().unwrap();
+        // 4 whole glyphs and 3 noise lines
+        assert_eq!(svg.matches("<path").count(), 7);
+    }
+
+    #[cfg(feature = "debug-geometry")]
+    #[test]
+    fn glyph_geometry() {
+        let captcha = BiosvgBuilder::new()
+            .length(4)
+            .seed(3)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .build_captcha()
+            .unwrap();
+        let chars = captcha
+            .glyphs
+            .iter()
+            .map(|glyph| glyph.ch)
+            .collect::<String>();
+        assert_eq!(chars, captcha.answer);
+        for glyph in &captcha.glyphs {
+            assert!(glyph.x >= 0.0 && glyph.x + glyph.w <= captcha.width);
+            assert!(glyph.y >= 0.0 && glyph.y + glyph.h <= captcha.height);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn builder_config() {
+        let builder: BiosvgBuilder = serde_json::from_str(
+            r##"{
+                "length": 5,
+                "difficulty": 3,
+                "colors": ["#0078D6", "rgb(170, 51, 51)"],
+                "char_colors": ["#33aa00", ["#aa33aa", "#f08012"]],
+                "noise_style": "lines",
+                "stroke_width": {"relative": 0.1},
+                "seed": 7
+            }"##,
+        )
+        .unwrap();
+        assert_eq!(builder.length, 5);
+        assert_eq!(builder.noise_style, NoiseStyle::Lines);
+        assert_eq!(builder.stroke_width, StrokeWidth::Relative(0.1));
+        assert_eq!(
+            builder.char_colors[1],
+            ColorSpec::Gradient(vec!["#aa33aa".to_string(), "#f08012".to_string()])
+        );
+        // unset settings keep their defaults
+        assert_eq!(builder.scale_range, BiosvgBuilder::new().scale_range);
+
+        let json = serde_json::to_string(&builder).unwrap();
+        let reloaded: BiosvgBuilder = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&reloaded).unwrap(), json);
+        assert_eq!(reloaded.build().unwrap(), builder.build().unwrap());
+    }
+
+    #[test]
+    fn build_batch() {
+        let builder = BiosvgBuilder::new()
+            .length(5)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .seed(11);
+        let batch = builder.clone().build_batch(20).unwrap();
+        assert_eq!(batch.len(), 20);
+        assert!(batch.iter().all(|captcha| captcha.answer.len() == 5));
+        assert_ne!(batch[0].answer, batch[1].answer);
+        let again = builder.clone().build_batch(20).unwrap();
+        assert!(batch.iter().zip(&again).all(|(a, b)| a.svg == b.svg));
+        assert!(builder.build_batch(0).unwrap().is_empty());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn build_async() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let captcha = builder.clone().seed(3).build_async().await.unwrap();
+        assert_eq!(captcha.svg, builder.clone().seed(3).build().unwrap().1);
+
+        let pool = CaptchaPool::new(builder, 2).unwrap();
+        assert_eq!(pool.pop_async().await.unwrap().answer.len(), 4);
+    }
+
+    #[cfg(feature = "ttf")]
+    #[test]
+    fn invalid_ttf() {
+        assert!(matches!(
+            Font::from_ttf_bytes(&[0, 1, 2, 3]),
+            Err(model::PathError::FontError(_))
+        ));
     }
 }
@@ -1,10 +1,38 @@
+use std::f64::consts::PI;
+use std::fmt;
+use std::ops::RangeInclusive;
+
 use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use super::format::{escape, Format};
+
+/// upper bound of the line segments a curve is flattened to
+const MAX_FLATTEN_STEPS: usize = 1024;
+
+/// curves are flattened within this fraction of the size of their path
+const FLATTEN_PRECISION: f64 = 500.0;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CommandType {
     Move,
     LineTo,
+    /// quadratic bézier curve with its control point
+    QuadTo(f64, f64),
+    /// cubic bézier curve with its two control points
+    CubicTo(f64, f64, f64, f64),
+    /// close the sub-path, the point is the start of the sub-path
+    Close,
+    /// elliptical arc, `rotation` is the x-axis rotation in degrees
+    Arc {
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -19,21 +47,308 @@ pub struct Path {
     pub commands: Vec<Command>,
     pub width: f64,
     pub height: f64,
+    /// y of the baseline the glyph sits on, below the bottom of the glyph
+    /// body for characters with descenders such as `g` or `p`
+    pub baseline: f64,
+    pub color: String,
+    pub stroke_width: f64,
+    pub render_mode: RenderMode,
+    /// opacity of the stroke and fill in `0.0..=1.0`
+    pub opacity: f64,
+    /// alternating dash and gap lengths of the stroke, solid if empty
+    pub dash: Vec<f64>,
+    pub animation: Option<Animation>,
+    /// css class of the element
+    pub class: Option<&'static str>,
+}
+
+/// StrokeWidth is the stroke width of the captcha text
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum StrokeWidth {
+    /// 1/12 of the character height
+    #[default]
+    Auto,
+    /// fixed width in units of the output svg
+    Fixed(f64),
+    /// ratio of the character height
+    Relative(f64),
+}
+
+/// AnimationStyle is how the strokes of the captcha are animated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AnimationStyle {
+    /// strokes draw themselves once, each after a random delay
+    DrawOn,
+    /// strokes jitter around their place continuously
+    Wiggle,
+}
+
+/// Animation is the SMIL animation of a path
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Animation {
+    /// draw the stroke within `duration` seconds after `delay` seconds
+    DrawOn { delay: f64, duration: f64 },
+    /// move the path by (`dx`, `dy`) and back every `duration` seconds
+    Wiggle { dx: f64, dy: f64, duration: f64 },
+}
+
+impl Animation {
+    /// Scale the distances of the animation.
+    pub fn scale(self, scale: f64) -> Animation {
+        match self {
+            Animation::Wiggle { dx, dy, duration } => Animation::Wiggle {
+                dx: dx * scale,
+                dy: dy * scale,
+                duration,
+            },
+            animation => animation,
+        }
+    }
+}
+
+/// RenderMode is how the captcha text is painted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RenderMode {
+    /// stroked outlines
+    #[default]
+    Stroke,
+    /// closed and filled shapes
+    Fill,
+    /// closed shapes, filled and stroked
+    Both,
+}
+
+/// Dot is a small filled circle
+#[derive(Debug, Clone)]
+pub struct Dot {
+    pub x: f64,
+    pub y: f64,
+    pub r: f64,
     pub color: String,
+    /// css class of the element
+    pub class: Option<&'static str>,
+}
+
+/// Background fills the whole canvas with a color, or a linear gradient when
+/// there are several colors
+#[derive(Debug, Clone)]
+pub struct Background {
+    /// id of the gradient, referenced by the rect
+    pub id: String,
+    pub colors: Vec<String>,
+    /// direction of the gradient in radians
+    pub angle: f64,
+}
+
+/// Bounds is the axis aligned bounding box of a path
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Bounds {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl Bounds {
+    pub fn width(&self) -> f64 {
+        self.max_x - self.min_x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max_y - self.min_y
+    }
+
+    /// the box grown to contain the point
+    const fn include(mut self, x: f64, y: f64) -> Bounds {
+        if x < self.min_x {
+            self.min_x = x;
+        }
+        if y < self.min_y {
+            self.min_y = y;
+        }
+        if x > self.max_x {
+            self.max_x = x;
+        }
+        if y > self.max_y {
+            self.max_y = y;
+        }
+        self
+    }
+
+    /// center of the box
+    pub fn center(&self) -> (f64, f64) {
+        (
+            (self.min_x + self.max_x) / 2.0,
+            (self.min_y + self.max_y) / 2.0,
+        )
+    }
+}
+
+/// Transform is a similarity transform, a uniform scale, then a rotation
+/// around the origin, then a move by (`x`, `y`). the svg `transform` of a
+/// group.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    pub scale: f64,
+    /// rotation in radians
+    pub angle: f64,
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Transform {
+    pub const IDENTITY: Transform = Transform {
+        scale: 1.0,
+        angle: 0.0,
+        x: 0.0,
+        y: 0.0,
+    };
+
+    /// Map a point.
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        let (sin, cos) = self.angle.sin_cos();
+        (
+            self.x + self.scale * (x * cos - y * sin),
+            self.y + self.scale * (x * sin + y * cos),
+        )
+    }
+
+    /// Transform that applies `inner` first and then `self`, like an element
+    /// with the `inner` transform inside a group with this one.
+    pub fn then(&self, inner: &Transform) -> Transform {
+        let (x, y) = self.apply(inner.x, inner.y);
+        Transform {
+            scale: self.scale * inner.scale,
+            angle: self.angle + inner.angle,
+            x,
+            y,
+        }
+    }
+
+    /// Transform that undoes this one.
+    pub fn inverse(&self) -> Transform {
+        let (sin, cos) = (-self.angle).sin_cos();
+        let scale = 1.0 / self.scale;
+        Transform {
+            scale,
+            angle: -self.angle,
+            x: -scale * (self.x * cos - self.y * sin),
+            y: -scale * (self.x * sin + self.y * cos),
+        }
+    }
+
+    /// Write the `transform` attribute value with the numbers in `format`.
+    pub fn write_svg<W: fmt::Write + ?Sized>(&self, out: &mut W, format: &Format) -> fmt::Result {
+        write!(
+            out,
+            "translate({} {}) rotate({}) scale({})",
+            format.number(self.x),
+            format.number(self.y),
+            format.number(self.angle.to_degrees()),
+            format.number(self.scale)
+        )
+    }
+}
+
+/// Gradient is a linear gradient def in user space coordinates, paths use it
+/// with `url(#id)` as their color
+#[derive(Debug, Clone)]
+pub struct Gradient {
+    pub id: String,
+    pub colors: Vec<String>,
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
 }
 
 #[derive(Error, Debug)]
 pub enum PathError {
     #[error("invalid path or unsupported command")]
     ParseError,
-    #[error("regex error")]
-    RegexError(#[from] regex::Error),
+    #[error(transparent)]
+    Build(#[from] BuildError),
+    #[error("no characters available for the answer")]
+    EmptyCharset,
+    #[error("invalid {0} range")]
+    InvalidRange(&'static str),
+    // the variants of optional features are always declared, cargo unifies
+    // features across the dependency graph and matches must not break when
+    // another crate turns one on
+    /// the ttf font is invalid, with the `ttf` feature
+    #[error("invalid font: {0}")]
+    FontError(String),
+    /// the png can't be rendered, with the `raster` feature
+    #[error("failed to rasterize captcha")]
+    RasterError,
+    /// the voice or its samples are invalid, with the `audio` feature
+    #[error("invalid audio: {0}")]
+    AudioError(String),
+    #[error("failed to write svg: {0}")]
+    Io(#[from] std::io::Error),
+    /// the runtime shut down before generating, with the `tokio` feature
+    #[error("captcha generation was cancelled")]
+    Cancelled,
     #[error("unknown path error")]
     Unknown,
 }
 
+/// BuildError is an invalid setting of the builder, found before anything is
+/// generated
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    #[error("no colors given")]
+    NoColors,
+    #[error("captcha length is zero")]
+    LengthZero,
+    #[error("character {0:?} has no glyph in the font")]
+    UnsupportedCharacter(char),
+    #[error("invalid color {0:?}")]
+    InvalidColor(String),
+    #[error("invalid value of environment variable {0}")]
+    InvalidEnv(&'static str),
+    #[error("the global generator is already initialized")]
+    GlobalInitialized,
+    #[error("color {0:?} does not contrast enough with the background")]
+    LowContrast(String),
+    #[error("answer entropy of {0} whole bits is below the minimum")]
+    LowEntropy(u32),
+    #[error("invalid element id prefix {0:?}")]
+    InvalidIdPrefix(String),
+    #[error("no built-in icon named {0:?}")]
+    UnknownIcon(String),
+    #[error("occlusion needs a single background color")]
+    OcclusionWithoutBackground,
+    #[error("arithmetic captchas have at most {max} terms, got {0}", max = crate::arithmetic::MAX_TERMS)]
+    TooManyTerms(usize),
+}
+
+impl CommandType {
+    /// Apply a point transform to the control points of curve commands.
+    fn map_points(self, f: impl Fn(f64, f64) -> (f64, f64)) -> CommandType {
+        match self {
+            CommandType::QuadTo(cx, cy) => {
+                let (cx, cy) = f(cx, cy);
+                CommandType::QuadTo(cx, cy)
+            }
+            CommandType::CubicTo(c1x, c1y, c2x, c2y) => {
+                let (c1x, c1y) = f(c1x, c1y);
+                let (c2x, c2y) = f(c2x, c2y);
+                CommandType::CubicTo(c1x, c1y, c2x, c2y)
+            }
+            command_type => command_type,
+        }
+    }
+}
+
 impl Command {
-    pub fn new(x: f64, y: f64, command_type: CommandType) -> Command {
+    pub const fn new(x: f64, y: f64, command_type: CommandType) -> Command {
         Command { x, y, command_type }
     }
 
@@ -41,159 +356,967 @@ impl Command {
         Command {
             x: self.x + x,
             y: self.y + y,
-            command_type: self.command_type,
+            command_type: self.command_type.map_points(|cx, cy| (cx + x, cy + y)),
         }
     }
 
+    /// Scale the command, arcs are only scaled exactly when they are not
+    /// rotated or the scale is uniform.
     pub fn scale(&self, x: f64, y: f64) -> Command {
+        let command_type = match self.command_type {
+            CommandType::Arc {
+                rx,
+                ry,
+                rotation,
+                large_arc,
+                sweep,
+            } => CommandType::Arc {
+                rx: rx * x.abs(),
+                ry: ry * y.abs(),
+                rotation,
+                large_arc,
+                // mirroring flips the arc direction
+                sweep: if x * y < 0.0 { !sweep } else { sweep },
+            },
+            command_type => command_type.map_points(|cx, cy| (cx * x, cy * y)),
+        };
         Command {
             x: self.x * x,
             y: self.y * y,
-            command_type: self.command_type,
+            command_type,
         }
     }
 
     /// Rotate the command aim point around the origin (0, 0).
     pub fn rotate(&self, angle: f64) -> Command {
-        let x = self.x * angle.cos() - self.y * angle.sin();
-        let y = self.x * angle.sin() + self.y * angle.cos();
+        let rotate = |x: f64, y: f64| {
+            (
+                x * angle.cos() - y * angle.sin(),
+                x * angle.sin() + y * angle.cos(),
+            )
+        };
+        let (x, y) = rotate(self.x, self.y);
+        let command_type = match self.command_type {
+            CommandType::Arc {
+                rx,
+                ry,
+                rotation,
+                large_arc,
+                sweep,
+            } => CommandType::Arc {
+                rx,
+                ry,
+                rotation: rotation + angle.to_degrees(),
+                large_arc,
+                sweep,
+            },
+            command_type => command_type.map_points(rotate),
+        };
+        Command { x, y, command_type }
+    }
+
+    /// Shear the command, x moves by `x_factor` times y and y by `y_factor`
+    /// times x. only the end point of arcs is sheared, `Path::skew` converts
+    /// arcs to cubics first.
+    pub fn skew(self, x_factor: f64, y_factor: f64) -> Command {
+        let skew = |x: f64, y: f64| (x + x_factor * y, y + y_factor * x);
+        let (x, y) = skew(self.x, self.y);
         Command {
             x,
             y,
-            command_type: self.command_type,
+            command_type: self.command_type.map_points(skew),
         }
     }
 
-    pub fn to_string(&self) -> String {
-        match self.command_type {
-            CommandType::Move => format!("M {} {} ", self.x, self.y),
-            CommandType::LineTo => format!("L {} {} ", self.x, self.y),
+    /// Convert an arc command starting at (`from_x`, `from_y`) to cubic bézier
+    /// commands, following the endpoint to center conversion of the svg spec.
+    /// other commands are returned as they are.
+    pub fn arc_to_cubics(&self, from_x: f64, from_y: f64) -> Vec<Command> {
+        let CommandType::Arc {
+            rx,
+            ry,
+            rotation,
+            large_arc,
+            sweep,
+        } = self.command_type
+        else {
+            return vec![self.clone()];
+        };
+        if from_x == self.x && from_y == self.y {
+            return Vec::new();
+        }
+        let (mut rx, mut ry) = (rx.abs(), ry.abs());
+        if rx == 0.0 || ry == 0.0 {
+            return vec![Command::new(self.x, self.y, CommandType::LineTo)];
         }
-    }
-}
 
-impl Path {
-    pub fn parse(path: &str) -> Result<Path, PathError> {
+        let (sin_phi, cos_phi) = rotation.to_radians().sin_cos();
+        let dx = (from_x - self.x) / 2.0;
+        let dy = (from_y - self.y) / 2.0;
+        let x1 = cos_phi * dx + sin_phi * dy;
+        let y1 = -sin_phi * dx + cos_phi * dy;
+        // scale up radii that are too small to reach the end point
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+
+        let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+        let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+        let sign = if large_arc != sweep { 1.0 } else { -1.0 };
+        let coef = sign * (numerator / denominator).max(0.0).sqrt();
+        let cx1 = coef * rx * y1 / ry;
+        let cy1 = -coef * ry * x1 / rx;
+        let cx = cos_phi * cx1 - sin_phi * cy1 + (from_x + self.x) / 2.0;
+        let cy = sin_phi * cx1 + cos_phi * cy1 + (from_y + self.y) / 2.0;
+
+        let start = ((y1 - cy1) / ry).atan2((x1 - cx1) / rx);
+        let end = ((-y1 - cy1) / ry).atan2((-x1 - cx1) / rx);
+        let mut delta = end - start;
+        if sweep && delta < 0.0 {
+            delta += 2.0 * PI;
+        } else if !sweep && delta > 0.0 {
+            delta -= 2.0 * PI;
+        }
+
+        let point = |t: f64| {
+            (
+                cx + rx * cos_phi * t.cos() - ry * sin_phi * t.sin(),
+                cy + rx * sin_phi * t.cos() + ry * cos_phi * t.sin(),
+            )
+        };
+        let derivative = |t: f64| {
+            (
+                -rx * cos_phi * t.sin() - ry * sin_phi * t.cos(),
+                -rx * sin_phi * t.sin() + ry * cos_phi * t.cos(),
+            )
+        };
+        // each cubic approximates at most a quarter of the ellipse
+        let segments = (delta.abs() / (PI / 2.0)).ceil().max(1.0) as usize;
+        let step = delta / segments as f64;
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
         let mut commands = Vec::new();
-        let rx = regex::Regex::new(r"([ML])\s?(-?\d{1,}\.?\d{1,}?)\s(-?\d{1,}\.?\d{1,}?)")?;
-        let mut max_x = 0.0;
-        let mut min_x = 0.0;
-        let mut max_y = 0.0;
-        let mut min_y = 0.0;
-        for cap in rx.captures_iter(path) {
-            let command_type = match &cap[1] {
-                "M" => CommandType::Move,
-                "L" => CommandType::LineTo,
-                _ => return Err(PathError::ParseError),
+        for i in 0..segments {
+            let t1 = start + step * i as f64;
+            let t2 = t1 + step;
+            let (x1, y1) = point(t1);
+            let (dx1, dy1) = derivative(t1);
+            let (x2, y2) = if i == segments - 1 {
+                (self.x, self.y)
+            } else {
+                point(t2)
             };
-            let x = cap[2].parse::<f64>().map_err(|_| PathError::ParseError)?;
-            let y = cap[3].parse::<f64>().map_err(|_| PathError::ParseError)?;
-            if x > max_x {
-                max_x = x;
-            } else if x < min_x {
-                min_x = x;
+            let (dx2, dy2) = derivative(t2);
+            commands.push(Command::new(
+                x2,
+                y2,
+                CommandType::CubicTo(x1 + k * dx1, y1 + k * dy1, x2 - k * dx2, y2 - k * dy2),
+            ));
+        }
+        commands
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Format::default().write_absolute(f, self)?;
+        f.write_str(" ")
+    }
+}
+
+/// Glyph is a built-in glyph, parsed and measured at compile time so the
+/// tables are plain statics. `path` builds the centered path of a lookup.
+#[derive(Debug)]
+pub struct Glyph {
+    commands: &'static [Command],
+    bounds: Bounds,
+    baseline: Option<f64>,
+}
+
+impl Glyph {
+    /// a glyph of parsed commands, `baseline` is in their coordinates and
+    /// defaults to the bottom of the bounding box
+    pub const fn new(commands: &'static [Command], baseline: Option<f64>) -> Glyph {
+        Glyph {
+            commands,
+            bounds: bounds(commands),
+            baseline,
+        }
+    }
+
+    /// height of the centered path
+    pub fn height(&self) -> f64 {
+        self.bounds.height()
+    }
+
+    /// the path centered on the bounding box of the end and control points
+    pub fn path(&self) -> Path {
+        Path::centered(self.commands, self.bounds, self.baseline)
+    }
+}
+
+/// Number of commands in path data, the length of the array of
+/// `parse_commands`. invalid data counts as no commands.
+pub const fn command_count(path: &str) -> usize {
+    let mut count = 0;
+    let mut cursor = Cursor::START;
+    while let Ok(Some((_, next))) = next_command(path.as_bytes(), cursor) {
+        count += 1;
+        cursor = next;
+    }
+    count
+}
+
+/// Parse path data into its commands in const context, so built-in glyphs
+/// are parsed at compile time. panics on invalid data, which fails the build.
+pub const fn parse_commands<const N: usize>(path: &str) -> [Command; N] {
+    const EMPTY: Command = Command::new(0.0, 0.0, CommandType::Move);
+    let mut commands = [EMPTY; N];
+    let mut count = 0;
+    let mut cursor = Cursor::START;
+    loop {
+        match next_command(path.as_bytes(), cursor) {
+            Ok(Some((command, next))) => {
+                if count == N {
+                    panic!("path has more commands than its array");
+                }
+                commands[count] = command;
+                count += 1;
+                cursor = next;
             }
-            if y > max_y {
-                max_y = y;
-            } else if y < min_y {
-                min_y = y;
+            Ok(None) => break,
+            Err(()) => panic!("invalid path"),
+        }
+    }
+    if count != N {
+        panic!("path has fewer commands than its array");
+    }
+    commands
+}
+
+/// Cursor is the parser state between two commands of path data
+#[derive(Debug, Clone, Copy)]
+struct Cursor {
+    /// position in the data
+    pos: usize,
+    /// current point
+    x: f64,
+    y: f64,
+    /// start of the current sub-path
+    start_x: f64,
+    start_y: f64,
+    /// last control point, reflected by the smooth curve commands
+    control_x: f64,
+    control_y: f64,
+    /// letter of the last command, repeated when its letter is left out
+    command: u8,
+}
+
+impl Cursor {
+    const START: Cursor = Cursor {
+        pos: 0,
+        x: 0.0,
+        y: 0.0,
+        start_x: 0.0,
+        start_y: 0.0,
+        control_x: 0.0,
+        control_y: 0.0,
+        command: 0,
+    };
+}
+
+/// Parse the command at the cursor, returns the command and the cursor after
+/// it, or `None` at the end of the data. supports all commands of the svg
+/// path syntax, absolute and relative. a command letter may be left out to
+/// repeat the last command, with `L` following `M`. `H` and `V` become lines,
+/// `S` and `T` curves with their reflected control point.
+const fn next_command(path: &[u8], cursor: Cursor) -> Result<Option<(Command, Cursor)>, ()> {
+    let pos = skip_separators(path, cursor.pos);
+    if pos == path.len() {
+        return Ok(None);
+    }
+    let (letter, pos) = if path[pos].is_ascii_alphabetic() {
+        (path[pos], pos + 1)
+    } else {
+        match cursor.command {
+            0 | b'Z' | b'z' => return Err(()),
+            b'M' => (b'L', pos),
+            b'm' => (b'l', pos),
+            letter => (letter, pos),
+        }
+    };
+    let relative = letter.is_ascii_lowercase();
+    let upper = letter.to_ascii_uppercase();
+    let arguments = match upper {
+        b'M' | b'L' | b'T' => 2,
+        b'H' | b'V' => 1,
+        b'Q' | b'S' => 4,
+        b'C' => 6,
+        b'A' => 7,
+        b'Z' => 0,
+        _ => return Err(()),
+    };
+    let mut n = [0.0; 7];
+    let mut pos = pos;
+    let mut i = 0;
+    while i < arguments {
+        // the arc flags are single digits that may be written without
+        // separators
+        let number = if upper == b'A' && (i == 3 || i == 4) {
+            next_flag(path, pos)
+        } else {
+            next_number(path, pos)
+        };
+        match number {
+            Ok((number, next)) => {
+                n[i] = number;
+                pos = next;
             }
-            commands.push(Command::new(x, y, command_type));
+            Err(()) => return Err(()),
         }
-        // offset the original point to the center of the path
-        let offset_x = (max_x + min_x) / 2.0;
-        let offset_y = (max_y + min_y) / 2.0;
+        i += 1;
+    }
+    if relative {
+        match upper {
+            b'H' => n[0] += cursor.x,
+            b'V' => n[0] += cursor.y,
+            b'A' => {
+                n[5] += cursor.x;
+                n[6] += cursor.y;
+            }
+            _ => {
+                let mut i = 0;
+                while i < arguments {
+                    n[i] += cursor.x;
+                    n[i + 1] += cursor.y;
+                    i += 2;
+                }
+            }
+        }
+    }
+    // the first control point of smooth curves mirrors the last one of the
+    // previous curve of the same kind
+    let previous = cursor.command.to_ascii_uppercase();
+    let (reflected_x, reflected_y) = match (upper, previous) {
+        (b'S', b'C' | b'S') | (b'T', b'Q' | b'T') => (
+            2.0 * cursor.x - cursor.control_x,
+            2.0 * cursor.y - cursor.control_y,
+        ),
+        _ => (cursor.x, cursor.y),
+    };
+    let (command, control_x, control_y) = match upper {
+        b'M' => (Command::new(n[0], n[1], CommandType::Move), n[0], n[1]),
+        b'L' => (Command::new(n[0], n[1], CommandType::LineTo), n[0], n[1]),
+        b'H' => (
+            Command::new(n[0], cursor.y, CommandType::LineTo),
+            n[0],
+            cursor.y,
+        ),
+        b'V' => (
+            Command::new(cursor.x, n[0], CommandType::LineTo),
+            cursor.x,
+            n[0],
+        ),
+        b'Q' => (
+            Command::new(n[2], n[3], CommandType::QuadTo(n[0], n[1])),
+            n[0],
+            n[1],
+        ),
+        b'T' => (
+            Command::new(n[0], n[1], CommandType::QuadTo(reflected_x, reflected_y)),
+            reflected_x,
+            reflected_y,
+        ),
+        b'C' => (
+            Command::new(n[4], n[5], CommandType::CubicTo(n[0], n[1], n[2], n[3])),
+            n[2],
+            n[3],
+        ),
+        b'S' => (
+            Command::new(
+                n[2],
+                n[3],
+                CommandType::CubicTo(reflected_x, reflected_y, n[0], n[1]),
+            ),
+            n[0],
+            n[1],
+        ),
+        b'A' => (
+            Command::new(
+                n[5],
+                n[6],
+                CommandType::Arc {
+                    rx: n[0],
+                    ry: n[1],
+                    rotation: n[2],
+                    large_arc: n[3] != 0.0,
+                    sweep: n[4] != 0.0,
+                },
+            ),
+            n[5],
+            n[6],
+        ),
+        _ => (
+            Command::new(cursor.start_x, cursor.start_y, CommandType::Close),
+            cursor.start_x,
+            cursor.start_y,
+        ),
+    };
+    let (start_x, start_y) = match upper {
+        b'M' => (command.x, command.y),
+        _ => (cursor.start_x, cursor.start_y),
+    };
+    let cursor = Cursor {
+        pos,
+        x: command.x,
+        y: command.y,
+        start_x,
+        start_y,
+        control_x,
+        control_y,
+        command: letter,
+    };
+    Ok(Some((command, cursor)))
+}
 
-        for command in &mut commands {
-            command.x -= offset_x;
-            command.y -= offset_y;
+/// Parse an arc flag, `0` or `1`.
+const fn next_flag(path: &[u8], pos: usize) -> Result<(f64, usize), ()> {
+    let pos = skip_separators(path, pos);
+    if pos == path.len() {
+        return Err(());
+    }
+    match path[pos] {
+        b'0' => Ok((0.0, pos + 1)),
+        b'1' => Ok((1.0, pos + 1)),
+        _ => Err(()),
+    }
+}
+
+/// Parse a number like `-12.5`, `.5` or `1e-3`, returns the number and the
+/// position after it. the significant digits are scaled by a power of ten
+/// once, which rounds the same as `str::parse` for the precision of glyph
+/// data.
+const fn next_number(path: &[u8], pos: usize) -> Result<(f64, usize), ()> {
+    let mut pos = skip_separators(path, pos);
+    let negative = pos < path.len() && path[pos] == b'-';
+    if pos < path.len() && matches!(path[pos], b'-' | b'+') {
+        pos += 1;
+    }
+    let mut mantissa: u64 = 0;
+    let mut digits = 0;
+    let mut exponent: i32 = 0;
+    let mut fraction = false;
+    while pos < path.len() {
+        match path[pos] {
+            b'0'..=b'9' => {
+                // digits past the precision of a u64 only scale the number
+                if mantissa < u64::MAX / 10 - 9 {
+                    mantissa = mantissa * 10 + (path[pos] - b'0') as u64;
+                    if fraction {
+                        exponent -= 1;
+                    }
+                } else if !fraction {
+                    exponent += 1;
+                }
+                digits += 1;
+            }
+            b'.' if !fraction => fraction = true,
+            _ => break,
+        }
+        pos += 1;
+    }
+    if digits == 0 {
+        return Err(());
+    }
+    if pos < path.len() && matches!(path[pos], b'e' | b'E') {
+        pos += 1;
+        let negative = pos < path.len() && path[pos] == b'-';
+        if pos < path.len() && matches!(path[pos], b'-' | b'+') {
+            pos += 1;
         }
+        let mut value: i32 = 0;
+        let mut digits = 0;
+        while pos < path.len() && path[pos].is_ascii_digit() {
+            if value < 10_000 {
+                value = value * 10 + (path[pos] - b'0') as i32;
+            }
+            digits += 1;
+            pos += 1;
+        }
+        if digits == 0 {
+            return Err(());
+        }
+        exponent += if negative { -value } else { value };
+    }
+    // out of range exponents end up as infinity or zero anyway
+    let mut scale = 1.0;
+    let mut power = exponent.unsigned_abs();
+    while power > 0 && scale < f64::MAX {
+        scale *= 10.0;
+        power -= 1;
+    }
+    let number = if exponent < 0 {
+        mantissa as f64 / scale
+    } else {
+        mantissa as f64 * scale
+    };
+    Ok((if negative { -number } else { number }, pos))
+}
 
-        let path = Path {
-            commands,
-            width: max_x - min_x,
-            height: max_y - min_y,
-            color: String::from("black"),
+/// bounding box of the end and control points, empty at (0, 0) without
+/// commands
+const fn bounds(commands: &[Command]) -> Bounds {
+    if commands.is_empty() {
+        return Bounds {
+            min_x: 0.0,
+            min_y: 0.0,
+            max_x: 0.0,
+            max_y: 0.0,
         };
+    }
+    let mut bounds = Bounds {
+        min_x: f64::INFINITY,
+        min_y: f64::INFINITY,
+        max_x: f64::NEG_INFINITY,
+        max_y: f64::NEG_INFINITY,
+    };
+    // a while loop, so the built-in glyphs are measured at compile time
+    let mut i = 0;
+    while i < commands.len() {
+        let command = &commands[i];
+        bounds = bounds.include(command.x, command.y);
+        match command.command_type {
+            CommandType::QuadTo(cx, cy) => bounds = bounds.include(cx, cy),
+            CommandType::CubicTo(c1x, c1y, c2x, c2y) => {
+                bounds = bounds.include(c1x, c1y).include(c2x, c2y);
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    bounds
+}
 
-        Ok(path)
+const fn skip_separators(path: &[u8], mut pos: usize) -> usize {
+    while pos < path.len() && matches!(path[pos], b' ' | b'\t' | b'\n' | b'\r' | b',') {
+        pos += 1;
     }
+    pos
+}
 
-    pub fn scale(&self, x: f64, y: f64) -> Path {
+impl Path {
+    /// Parse path data at runtime, the built-in glyphs are parsed at compile
+    /// time with `parse_commands` instead.
+    pub fn parse(path: &str) -> Result<Path, PathError> {
         let mut commands = Vec::new();
-        for command in &self.commands {
-            commands.push(command.scale(x, y));
+        let mut cursor = Cursor::START;
+        while let Some((command, next)) =
+            next_command(path.as_bytes(), cursor).map_err(|_| PathError::ParseError)?
+        {
+            commands.push(command);
+            cursor = next;
         }
+        Ok(Path::centered(&commands, bounds(&commands), None))
+    }
+
+    /// Build a path from parsed commands, the original point is moved to the
+    /// center of their `bounds`. the baseline is at `baseline` in the
+    /// coordinates of the commands, or the bottom of the box.
+    fn centered(commands: &[Command], bounds: Bounds, baseline: Option<f64>) -> Path {
+        // offset the original point to the center of the path
+        let (offset_x, offset_y) = bounds.center();
+
+        let commands = commands
+            .iter()
+            .map(|command| command.offset(-offset_x, -offset_y))
+            .collect();
         Path {
             commands,
-            width: self.width * x,
-            height: self.height * y,
-            color: self.color.clone(),
+            width: bounds.width(),
+            height: bounds.height(),
+            baseline: baseline.unwrap_or(bounds.max_y) - offset_y,
+            ..Path::stroke(Vec::new(), "black", bounds.height() / 12.0)
+        }
+    }
+
+    /// Build a path from commands, the original point is moved to the center
+    /// of the bounding box of the end and control points. the baseline is at
+    /// y = 0 like in fonts.
+    #[cfg(feature = "ttf")]
+    pub fn from_commands(commands: Vec<Command>) -> Path {
+        Path::centered(&commands, bounds(&commands), Some(0.0))
+    }
+
+    /// Build a stroked path of commands in `color`, the size is the bounding
+    /// box of the commands and the baseline is at y = 0.
+    pub fn stroke(commands: Vec<Command>, color: &str, stroke_width: f64) -> Path {
+        let bounds = bounds(&commands);
+        Path {
+            commands,
+            width: bounds.width(),
+            height: bounds.height(),
+            baseline: 0.0,
+            color: color.to_string(),
+            stroke_width,
+            render_mode: RenderMode::Stroke,
+            opacity: 1.0,
+            dash: Vec::new(),
+            animation: None,
+            class: None,
+        }
+    }
+
+    /// Bounding box of the end and control points of the commands, `width`
+    /// and `height` are kept from before transforms like `rotate`.
+    pub fn bounds(&self) -> Bounds {
+        bounds(&self.commands)
+    }
+
+    /// Scale the path around the origin (0, 0), the stroke width is kept so
+    /// line weights stay consistent.
+    pub fn scale(mut self, x: f64, y: f64) -> Path {
+        for command in &mut self.commands {
+            *command = command.scale(x, y);
         }
+        self.width *= x;
+        self.height *= y;
+        self.baseline *= y;
+        self
     }
 
     /// Rotate the path around the origin (0, 0).
-    pub fn rotate(&self, angle: f64) -> Path {
+    pub fn rotate(mut self, angle: f64) -> Path {
+        for command in &mut self.commands {
+            *command = command.rotate(angle);
+        }
+        self
+    }
+
+    /// Shear the path around the origin (0, 0), arcs are converted to cubic
+    /// béziers as a sheared ellipse is no longer axis aligned to its radii.
+    pub fn skew(self, x_factor: f64, y_factor: f64) -> Path {
         let mut commands = Vec::new();
+        let (mut x, mut y) = (0.0, 0.0);
         for command in &self.commands {
-            commands.push(command.rotate(angle));
+            for command in command.arc_to_cubics(x, y) {
+                commands.push(command.skew(x_factor, y_factor));
+            }
+            (x, y) = (command.x, command.y);
         }
-        Path {
-            commands,
-            width: self.width,
-            height: self.height,
-            color: self.color.clone(),
+        Path { commands, ..self }
+    }
+
+    pub fn offset(mut self, x: f64, y: f64) -> Path {
+        for command in &mut self.commands {
+            *command = command.offset(x, y);
         }
+        self
     }
 
-    pub fn offset(&self, x: f64, y: f64) -> Path {
+    /// Convert curves and arcs to line segments that stay within `tolerance`
+    /// of the curve, for the transforms that assume straight segments.
+    pub fn flatten(&self, tolerance: f64) -> Path {
+        let tolerance = if tolerance > 0.0 {
+            tolerance
+        } else {
+            f64::EPSILON
+        };
+        // the chord of a parameter step h deviates at most h² / 8 times the
+        // largest second derivative from the curve
+        let steps = |second_derivative: f64| {
+            ((second_derivative / (8.0 * tolerance)).sqrt().ceil() as usize)
+                .clamp(1, MAX_FLATTEN_STEPS)
+        };
         let mut commands = Vec::new();
+        let (mut x, mut y) = (0.0, 0.0);
         for command in &self.commands {
-            commands.push(command.offset(x, y));
+            for command in command.arc_to_cubics(x, y) {
+                let (x0, y0) = (x, y);
+                match command.command_type {
+                    CommandType::QuadTo(cx, cy) => {
+                        let n = steps(
+                            2.0 * (x0 - 2.0 * cx + command.x).hypot(y0 - 2.0 * cy + command.y),
+                        );
+                        for i in 1..=n {
+                            let t = i as f64 / n as f64;
+                            let u = 1.0 - t;
+                            commands.push(Command::new(
+                                u * u * x0 + 2.0 * u * t * cx + t * t * command.x,
+                                u * u * y0 + 2.0 * u * t * cy + t * t * command.y,
+                                CommandType::LineTo,
+                            ));
+                        }
+                    }
+                    CommandType::CubicTo(c1x, c1y, c2x, c2y) => {
+                        let d1 = (x0 - 2.0 * c1x + c2x).hypot(y0 - 2.0 * c1y + c2y);
+                        let d2 = (c1x - 2.0 * c2x + command.x).hypot(c1y - 2.0 * c2y + command.y);
+                        let n = steps(6.0 * d1.max(d2));
+                        for i in 1..=n {
+                            let t = i as f64 / n as f64;
+                            let u = 1.0 - t;
+                            commands.push(Command::new(
+                                u * u * u * x0
+                                    + 3.0 * u * u * t * c1x
+                                    + 3.0 * u * t * t * c2x
+                                    + t * t * t * command.x,
+                                u * u * u * y0
+                                    + 3.0 * u * u * t * c1y
+                                    + 3.0 * u * t * t * c2y
+                                    + t * t * t * command.y,
+                                CommandType::LineTo,
+                            ));
+                        }
+                    }
+                    _ => commands.push(command.clone()),
+                }
+                (x, y) = (command.x, command.y);
+            }
+        }
+        self.with_commands(commands)
+    }
+
+    /// Bend the path along a sine wave, each point is moved vertically by
+    /// `amplitude * sin(frequency * x + phase)`. curves are flattened and line
+    /// segments are subdivided so that straight strokes follow the wave.
+    pub fn wave(&self, amplitude: f64, frequency: f64, phase: f64) -> Path {
+        let displace = |x: f64, y: f64| (x, y + amplitude * (frequency * x + phase).sin());
+        // subdivide segments to at most 1/8 of the wave length
+        let max_step = if frequency > 0.0 {
+            std::f64::consts::TAU / frequency / 8.0
+        } else {
+            f64::INFINITY
+        };
+        let flat = self.flatten(self.height.max(self.width) / FLATTEN_PRECISION);
+        let mut commands = Vec::new();
+        let mut last: Option<&Command> = None;
+        for command in &flat.commands {
+            if let (Some(from), CommandType::LineTo) = (last, command.command_type) {
+                let length = (command.x - from.x).hypot(command.y - from.y);
+                let steps = (length / max_step).ceil().max(1.0) as usize;
+                for i in 1..steps {
+                    let t = i as f64 / steps as f64;
+                    let (x, y) = displace(
+                        from.x + (command.x - from.x) * t,
+                        from.y + (command.y - from.y) * t,
+                    );
+                    commands.push(Command::new(x, y, CommandType::LineTo));
+                }
+            }
+            let (x, y) = displace(command.x, command.y);
+            commands.push(Command::new(
+                x,
+                y,
+                command.command_type.map_points(displace),
+            ));
+            last = Some(command);
+        }
+        self.with_commands(commands)
+    }
+
+    /// Close every sub-path so the path can be filled.
+    pub fn close(&self) -> Path {
+        let mut commands: Vec<Command> = Vec::new();
+        let mut start: Option<Command> = None;
+        for command in &self.commands {
+            if command.command_type == CommandType::Move {
+                if let Some(start) = start.take() {
+                    commands.push(Command::new(start.x, start.y, CommandType::Close));
+                }
+            }
+            if command.command_type == CommandType::Close {
+                start = None;
+            } else if start.is_none() {
+                start = Some(command.clone());
+            }
+            commands.push(command.clone());
+        }
+        if let Some(start) = start {
+            commands.push(Command::new(start.x, start.y, CommandType::Close));
+        }
+        self.with_commands(commands)
+    }
+
+    /// Split the path at its move commands into one path per sub-path.
+    pub fn sub_paths(&self) -> Vec<Path> {
+        let mut paths = Vec::new();
+        let mut commands: Vec<Command> = Vec::new();
+        for command in &self.commands {
+            if command.command_type == CommandType::Move && commands.len() > 1 {
+                paths.push(self.with_commands(std::mem::take(&mut commands)));
+            }
+            if command.command_type == CommandType::Move {
+                commands.clear();
+            }
+            commands.push(command.clone());
+        }
+        if commands.len() > 1 {
+            paths.push(self.with_commands(commands));
+        }
+        paths
+    }
+
+    /// Reverse the direction of every sub-path, the path looks the same but
+    /// is drawn from the other end.
+    pub fn reverse(&self) -> Path {
+        let mut commands = Vec::new();
+        for sub_path in self.sub_paths() {
+            let closed = sub_path
+                .commands
+                .last()
+                .is_some_and(|command| command.command_type == CommandType::Close);
+            // each segment is drawn from the end point of the previous one
+            let ends = sub_path.commands.iter().skip(1).rev();
+            let starts = sub_path.commands.iter().rev().skip(1);
+            let last = sub_path.commands.last().unwrap();
+            commands.push(Command::new(last.x, last.y, CommandType::Move));
+            for (end, start) in ends.zip(starts) {
+                let command_type = match end.command_type {
+                    CommandType::CubicTo(c1x, c1y, c2x, c2y) => {
+                        CommandType::CubicTo(c2x, c2y, c1x, c1y)
+                    }
+                    CommandType::Arc {
+                        rx,
+                        ry,
+                        rotation,
+                        large_arc,
+                        sweep,
+                    } => CommandType::Arc {
+                        rx,
+                        ry,
+                        rotation,
+                        large_arc,
+                        sweep: !sweep,
+                    },
+                    CommandType::Move | CommandType::Close => CommandType::LineTo,
+                    command_type => command_type,
+                };
+                commands.push(Command::new(start.x, start.y, command_type));
+            }
+            if closed {
+                commands.push(Command::new(last.x, last.y, CommandType::Close));
+            }
         }
+        self.with_commands(commands)
+    }
+
+    /// Join the sub-paths of `paths` into one path with the attributes of the
+    /// first one.
+    pub fn merge(paths: &[Path]) -> Option<Path> {
+        let first = paths.first()?;
+        let commands = paths
+            .iter()
+            .flat_map(|path| path.commands.iter().cloned())
+            .collect::<Vec<Command>>();
+        let bounds = bounds(&commands);
+        let mut path = first.with_commands(commands);
+        path.width = bounds.width();
+        path.height = bounds.height();
+        Some(path)
+    }
+
+    /// Transform the path, the stroke width, dashes and animation are scaled
+    /// with it.
+    pub fn transform(mut self, transform: &Transform) -> Path {
+        let scale = transform.scale;
+        self.stroke_width *= scale;
+        self.dash.iter_mut().for_each(|len| *len *= scale);
+        self.animation = self.animation.map(|animation| animation.scale(scale));
+        self.scale(scale, scale)
+            .rotate(transform.angle)
+            .offset(transform.x, transform.y)
+    }
+
+    /// copy of the path with other commands
+    fn with_commands(&self, commands: Vec<Command>) -> Path {
         Path {
             commands,
             width: self.width,
             height: self.height,
+            baseline: self.baseline,
             color: self.color.clone(),
+            stroke_width: self.stroke_width,
+            render_mode: self.render_mode,
+            opacity: self.opacity,
+            dash: self.dash.clone(),
+            animation: self.animation,
+            class: self.class,
         }
     }
 
-    pub fn with_color(&self, color: &str) -> Path {
-        Path {
-            commands: self.commands.clone(),
-            width: self.width,
-            height: self.height,
-            color: String::from(color),
+    pub fn with_render_mode(mut self, render_mode: RenderMode) -> Path {
+        self.render_mode = render_mode;
+        self
+    }
+
+    pub fn with_stroke_width(mut self, stroke_width: f64) -> Path {
+        self.stroke_width = stroke_width;
+        self
+    }
+
+    pub fn with_opacity(mut self, opacity: f64) -> Path {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Length of the path with curves flattened.
+    pub fn length(&self) -> f64 {
+        let flat = self.flatten(self.height.max(self.width) / FLATTEN_PRECISION);
+        let mut length = 0.0;
+        let (mut x, mut y) = (0.0, 0.0);
+        for command in &flat.commands {
+            if command.command_type != CommandType::Move {
+                length += (command.x - x).hypot(command.y - y);
+            }
+            (x, y) = (command.x, command.y);
         }
+        length
+    }
+
+    pub fn with_class(mut self, class: Option<&'static str>) -> Path {
+        self.class = class;
+        self
     }
 
-    pub fn random_split(&self) -> Vec<Path> {
-        let mut rng = rand::thread_rng();
+    pub fn with_color(mut self, color: &str) -> Path {
+        self.color = String::from(color);
+        self
+    }
+
+    /// Split the path into sub-paths of a random number of segments within
+    /// `segments`.
+    pub fn random_split<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        segments: RangeInclusive<usize>,
+    ) -> Vec<Path> {
+        let Some(first) = self.commands.first() else {
+            return vec![self.clone()];
+        };
         let mut paths = Vec::new();
         let mut commands = Vec::new();
-        let mut break_limit = rng.gen_range(2..=4);
-        let mut start_cmd = self.commands[0].clone();
+        let mut break_limit = rng.gen_range(segments.clone());
+        let mut start_cmd = first.clone();
         for command in &self.commands {
+            // a piece can't close the sub-path it was split from
+            let command = &match command.command_type {
+                CommandType::Close => Command::new(command.x, command.y, CommandType::LineTo),
+                _ => command.clone(),
+            };
             if commands.len() >= break_limit || command.command_type == CommandType::Move {
-                if command.command_type == CommandType::LineTo {
+                if command.command_type != CommandType::Move {
                     commands.push(command.clone());
                 }
 
                 if commands.len() > 1 {
-                    paths.push(Path {
-                        commands: commands.clone(),
-                        width: self.width,
-                        height: self.height,
-                        color: self.color.clone(),
-                    });
+                    paths.push(self.with_commands(std::mem::take(&mut commands)));
                 }
-                commands = Vec::new();
+                commands.clear();
                 start_cmd = command.clone();
                 start_cmd.command_type = CommandType::Move;
-                break_limit = rng.gen_range(2..=4);
+                break_limit = rng.gen_range(segments.clone());
             } else {
-                if commands.len() == 0 {
+                if commands.is_empty() {
                     commands.push(start_cmd.clone());
                 }
                 commands.push(command.clone());
@@ -201,27 +1324,253 @@ impl Path {
         }
 
         if commands.len() > 1 {
-            paths.push(Path {
-                commands: commands.clone(),
-                width: self.width,
-                height: self.height,
-                color: self.color.clone(),
-            });
+            paths.push(self.with_commands(commands));
         }
         paths
     }
+}
 
-    pub fn to_string(&self) -> String {
-        let mut commands = String::new();
-        for command in &self.commands {
-            commands.push_str(&command.to_string());
-        }
-        // the stroke-width should be calculated by the path size
-        format!(
-            "<path d=\"{}\" stroke=\"{}\" stroke-width=\"{}\" fill=\"none\" />",
-            commands.trim(),
-            self.color,
-            self.height / 12.0
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_svg(f, &Format::default())
+    }
+}
+
+impl Path {
+    /// Write the path element with the numbers in `format`, optional
+    /// attributes are only written when they are set.
+    pub fn write_svg<W: fmt::Write + ?Sized>(&self, out: &mut W, format: &Format) -> fmt::Result {
+        let n = |value: f64| format.number(value);
+        write!(out, "<path{} d=\"", class_attribute(self.class))?;
+        format.write_path_data(out, &self.commands)?;
+        out.write_char('"')?;
+        let length = match self.animation {
+            Some(Animation::DrawOn { .. }) => self.length(),
+            _ => 0.0,
+        };
+        if self.render_mode == RenderMode::Fill {
+            out.write_str(" stroke=\"none\"")?;
+        } else {
+            write!(
+                out,
+                " stroke=\"{}\" stroke-width=\"{}\"",
+                escape(&self.color),
+                n(self.stroke_width)
+            )?;
+            if self.opacity < 1.0 {
+                write!(out, " stroke-opacity=\"{}\"", n(self.opacity))?;
+            }
+            if let Some(Animation::DrawOn { .. }) = self.animation {
+                // the stroke is one dash, hidden by its offset until drawn
+                write!(
+                    out,
+                    " stroke-dasharray=\"{}\" stroke-dashoffset=\"{}\"",
+                    n(length),
+                    n(length)
+                )?;
+            } else if !self.dash.is_empty() {
+                out.write_str(" stroke-dasharray=\"")?;
+                for (i, len) in self.dash.iter().enumerate() {
+                    if i > 0 {
+                        out.write_char(' ')?;
+                    }
+                    format.write_number(out, *len)?;
+                }
+                out.write_char('"')?;
+            }
+        }
+        if self.render_mode == RenderMode::Stroke {
+            out.write_str(" fill=\"none\"")?;
+        } else {
+            write!(out, " fill=\"{}\"", escape(&self.color))?;
+            if self.opacity < 1.0 {
+                write!(out, " fill-opacity=\"{}\"", n(self.opacity))?;
+            }
+        }
+        match self.animation {
+            None => out.write_str(if format.compact { "/>" } else { " />" }),
+            Some(Animation::DrawOn { delay, duration }) => write!(
+                out,
+                "><animate attributeName=\"stroke-dashoffset\" from=\"{}\" to=\"0\" begin=\"{}s\" dur=\"{}s\" fill=\"freeze\" /></path>",
+                n(length),
+                n(delay),
+                n(duration)
+            ),
+            Some(Animation::Wiggle { dx, dy, duration }) => write!(
+                out,
+                "><animateTransform attributeName=\"transform\" type=\"translate\" values=\"0 0;{} {};0 0\" dur=\"{}s\" repeatCount=\"indefinite\" /></path>",
+                n(dx),
+                n(dy),
+                n(duration)
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Dot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_svg(f, &Format::default())
+    }
+}
+
+impl Dot {
+    /// Write the circle element with the numbers in `format`.
+    pub fn write_svg<W: fmt::Write + ?Sized>(&self, out: &mut W, format: &Format) -> fmt::Result {
+        write!(
+            out,
+            "<circle{} cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"{}",
+            class_attribute(self.class),
+            format.number(self.x),
+            format.number(self.y),
+            format.number(self.r),
+            escape(&self.color),
+            if format.compact { "/>" } else { " />" }
+        )
+    }
+}
+
+/// ` class="..."` if there's a class.
+fn class_attribute(class: Option<&str>) -> String {
+    class.map_or_else(String::new, |class| format!(" class=\"{}\"", escape(class)))
+}
+
+impl Dot {
+    /// Scale the dot position and radius around the origin (0, 0), then move it.
+    pub fn scale_offset(&self, scale: f64, x: f64, y: f64) -> Dot {
+        Dot {
+            x: self.x * scale + x,
+            y: self.y * scale + y,
+            r: self.r * scale,
+            color: self.color.clone(),
+            class: self.class,
+        }
+    }
+}
+
+/// Offsets of gradient stops, evenly distributed.
+fn gradient_stops(colors: &[String]) -> impl Iterator<Item = (f64, &str)> {
+    let last = (colors.len().max(2) - 1) as f64;
+    colors
+        .iter()
+        .enumerate()
+        .map(move |(i, color)| (i as f64 / last, color.as_str()))
+}
+
+impl Gradient {
+    /// Scale the gradient vector around the origin (0, 0), then move it.
+    pub fn scale_offset(&self, scale: f64, x: f64, y: f64) -> Gradient {
+        Gradient {
+            id: self.id.clone(),
+            colors: self.colors.clone(),
+            x1: self.x1 * scale + x,
+            y1: self.y1 * scale + y,
+            x2: self.x2 * scale + x,
+            y2: self.y2 * scale + y,
+        }
+    }
+
+    pub fn stops(&self) -> impl Iterator<Item = (f64, &str)> {
+        gradient_stops(&self.colors)
+    }
+}
+
+impl fmt::Display for Gradient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_svg(f, &Format::default())
+    }
+}
+
+impl Gradient {
+    /// Write the gradient definition with the numbers in `format`.
+    pub fn write_svg<W: fmt::Write + ?Sized>(&self, out: &mut W, format: &Format) -> fmt::Result {
+        let n = |value: f64| format.number(value);
+        let close = if format.compact { "/>" } else { " />" };
+        let stops = self
+            .stops()
+            .map(|(offset, color)| {
+                format!(
+                    r#"<stop offset="{}" stop-color="{}"{}"#,
+                    n(offset),
+                    escape(color),
+                    close
+                )
+            })
+            .collect::<String>();
+        write!(
+            out,
+            r#"<defs><linearGradient id="{}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}">{}</linearGradient></defs>"#,
+            escape(&self.id),
+            n(self.x1),
+            n(self.y1),
+            n(self.x2),
+            n(self.y2),
+            stops
+        )
+    }
+}
+
+impl Background {
+    /// Start and end points of the gradient, relative to the canvas size.
+    pub fn gradient_vector(&self) -> (f64, f64, f64, f64) {
+        let (sin, cos) = self.angle.sin_cos();
+        (
+            0.5 - cos / 2.0,
+            0.5 - sin / 2.0,
+            0.5 + cos / 2.0,
+            0.5 + sin / 2.0,
+        )
+    }
+
+    pub fn stops(&self) -> impl Iterator<Item = (f64, &str)> {
+        gradient_stops(&self.colors)
+    }
+
+    /// Write the background as a rect covering a `width` x `height` canvas.
+    pub fn write_svg<W: fmt::Write + ?Sized>(
+        &self,
+        out: &mut W,
+        width: f64,
+        height: f64,
+        format: &Format,
+    ) -> fmt::Result {
+        let n = |value: f64| format.number(value);
+        let close = if format.compact { "/>" } else { " />" };
+        if self.colors.len() < 2 {
+            let color = self.colors.first().map_or("none", |color| color.as_str());
+            return write!(
+                out,
+                r#"<rect width="{}" height="{}" fill="{}"{}"#,
+                n(width),
+                n(height),
+                escape(color),
+                close
+            );
+        }
+        let (x1, y1, x2, y2) = self.gradient_vector();
+        let stops = self
+            .stops()
+            .map(|(offset, color)| {
+                format!(
+                    r#"<stop offset="{}" stop-color="{}"{}"#,
+                    n(offset),
+                    escape(color),
+                    close
+                )
+            })
+            .collect::<String>();
+        write!(
+            out,
+            r#"<defs><linearGradient id="{}" x1="{}" y1="{}" x2="{}" y2="{}">{}</linearGradient></defs><rect width="{}" height="{}" fill="url(#{})"{}"#,
+            escape(&self.id),
+            n(x1),
+            n(y1),
+            n(x2),
+            n(y2),
+            stops,
+            n(width),
+            n(height),
+            escape(&self.id),
+            close
         )
     }
 }
@@ -0,0 +1,357 @@
+use std::f64::consts::FRAC_PI_4;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::model::{Command, CommandType, Dot, Path, RenderMode};
+
+/// NoiseStyle is the shape of the noise strokes added by `difficulty`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum NoiseStyle {
+    /// straight line segments
+    Lines,
+    /// quadratic and cubic bézier curves crossing the glyphs
+    #[default]
+    Curves,
+    /// elliptical arcs and small circles scattered around the glyphs
+    Arcs,
+    /// strokes cut out of characters that aren't part of the answer, with
+    /// the same curvature and stroke width as the real glyphs
+    Mimic,
+}
+
+impl NoiseStyle {
+    /// range of the stroke lengths relative to the glyph height if none is
+    /// configured, the diameter for arcs
+    pub(crate) fn length_range(self) -> (f64, f64) {
+        match self {
+            NoiseStyle::Lines => (0.5, 1.5),
+            NoiseStyle::Curves => (1.0, 2.5),
+            NoiseStyle::Arcs => (0.6, 2.4),
+            NoiseStyle::Mimic => (0.5, 1.5),
+        }
+    }
+
+    /// range of the stroke widths relative to the glyph stroke width if none
+    /// is configured
+    pub(crate) fn stroke_width_range(self) -> (f64, f64) {
+        match self {
+            NoiseStyle::Mimic => (1.0, 1.0),
+            _ => (2.0 / 3.0, 2.0 / 3.0),
+        }
+    }
+}
+
+/// Generate one noise stroke of the given style, `height` is the glyph height
+/// and `length` the range of the stroke length relative to it. `glyphs` are
+/// the glyphs mimic strokes are cut out of, curves are drawn if there's none.
+pub fn generate<R: Rng + ?Sized>(
+    rng: &mut R,
+    style: NoiseStyle,
+    width: f64,
+    height: f64,
+    length: (f64, f64),
+    glyphs: &[Path],
+    color: &str,
+) -> Path {
+    let length = rng.gen_range(length.0..=length.1) * height;
+    match style {
+        NoiseStyle::Lines => line(rng, width, height, length, color),
+        NoiseStyle::Curves => curve(rng, width, height, length, color),
+        NoiseStyle::Arcs => {
+            if rng.gen_bool(0.5) {
+                arc(rng, width, height, length, color)
+            } else {
+                circle(rng, width, height, color)
+            }
+        }
+        NoiseStyle::Mimic => match glyphs.choose(rng) {
+            Some(glyph) => mimic(rng, glyph, width, height, length, color),
+            None => curve(rng, width, height, length, color),
+        },
+    }
+}
+
+/// Random start of a stroke spanning `length` along a side of `size`, short
+/// strokes stay on the canvas and long strokes cross all of it.
+fn start<R: Rng + ?Sized>(rng: &mut R, length: f64, size: f64) -> f64 {
+    let slack = size - length;
+    if slack > 0.0 {
+        rng.gen_range(0.0..=slack)
+    } else {
+        rng.gen_range(slack..=0.0)
+    }
+}
+
+/// Generate a straight noise line of `length` at most 45 degrees off the
+/// horizontal somewhere on the canvas.
+pub fn line<R: Rng + ?Sized>(
+    rng: &mut R,
+    width: f64,
+    height: f64,
+    length: f64,
+    color: &str,
+) -> Path {
+    let angle = rng.gen_range(-FRAC_PI_4..=FRAC_PI_4);
+    let (dx, dy) = (length * angle.cos(), length * angle.sin());
+    let start_x = start(rng, dx, width);
+    let end_x = start_x + dx;
+    // the line passes a random height in the middle of its part on the canvas
+    let middle_x = (start_x.max(0.0) + end_x.min(width)) / 2.0;
+    let start_y = rng.gen_range(0.0..height * 1.5) - (middle_x - start_x) * angle.tan();
+    let end_y = start_y + dy;
+    Path::stroke(
+        vec![
+            Command::new(start_x, start_y, CommandType::Move),
+            Command::new(end_x, end_y, CommandType::LineTo),
+        ],
+        color,
+        height / 18.0,
+    )
+}
+
+/// Generate a quadratic or cubic bézier noise stroke with a chord of
+/// `length` that wanders through the glyph band. curved strokes look like
+/// glyph strokes and can't be filtered out as easily as straight lines.
+pub fn curve<R: Rng + ?Sized>(
+    rng: &mut R,
+    width: f64,
+    height: f64,
+    length: f64,
+    color: &str,
+) -> Path {
+    let start_x = start(rng, length, width);
+    let end_x = start_x + length;
+    let start_y = rng.gen_range(0.0..height * 1.5);
+    let end_y = rng.gen_range(0.0..height * 1.5);
+    let command_type = if rng.gen_bool(0.5) {
+        let cx = (start_x + end_x) / 2.0;
+        let cy = rng.gen_range(-0.5 * height..2.0 * height);
+        CommandType::QuadTo(cx, cy)
+    } else {
+        // control points are placed around the thirds of the chord
+        let c1x = start_x + (end_x - start_x) / 3.0;
+        let c2x = start_x + (end_x - start_x) * 2.0 / 3.0;
+        let c1y = rng.gen_range(-0.5 * height..2.0 * height);
+        let c2y = rng.gen_range(-0.5 * height..2.0 * height);
+        CommandType::CubicTo(c1x, c1y, c2x, c2y)
+    };
+    Path::stroke(
+        vec![
+            Command::new(start_x, start_y, CommandType::Move),
+            Command::new(end_x, end_y, command_type),
+        ],
+        color,
+        height / 18.0,
+    )
+}
+
+/// Generate a filled occluder of about `size` around (`x`, `y`), a thin
+/// bar at a random angle or a round blob, to be drawn over the glyphs in
+/// the background color so it hides bits of their strokes.
+pub fn occluder<R: Rng + ?Sized>(rng: &mut R, x: f64, y: f64, size: f64, color: &str) -> Path {
+    let mut commands = Vec::new();
+    if rng.gen_bool(0.5) {
+        let (sin, cos) = rng.gen_range(0.0..std::f64::consts::PI).sin_cos();
+        let (half_w, half_h) = (size / 2.0, size * rng.gen_range(0.1..0.2));
+        let corners = [
+            (-half_w, -half_h),
+            (half_w, -half_h),
+            (half_w, half_h),
+            (-half_w, half_h),
+        ];
+        for (i, (cx, cy)) in corners.into_iter().enumerate() {
+            let command_type = match i {
+                0 => CommandType::Move,
+                _ => CommandType::LineTo,
+            };
+            commands.push(Command::new(
+                x + cx * cos - cy * sin,
+                y + cx * sin + cy * cos,
+                command_type,
+            ));
+        }
+    } else {
+        // cubic lobes through points at random radii around the center
+        let lobes = rng.gen_range(4..=6);
+        let points = (0..lobes)
+            .map(|i| {
+                let angle = std::f64::consts::TAU * i as f64 / lobes as f64;
+                let radius = size / 2.0 * rng.gen_range(0.5..1.0);
+                (angle, radius)
+            })
+            .collect::<Vec<(f64, f64)>>();
+        let point =
+            |(angle, radius): (f64, f64)| (x + radius * angle.cos(), y + radius * angle.sin());
+        let (start_x, start_y) = point(points[0]);
+        commands.push(Command::new(start_x, start_y, CommandType::Move));
+        let step = std::f64::consts::TAU / lobes as f64 / 3.0;
+        for i in 0..lobes {
+            let (from_angle, from_radius) = points[i];
+            let (to_angle, to_radius) = points[(i + 1) % lobes];
+            let to_angle = if i + 1 == lobes {
+                to_angle + std::f64::consts::TAU
+            } else {
+                to_angle
+            };
+            let (c1x, c1y) = point((from_angle + step, from_radius));
+            let (c2x, c2y) = point((to_angle - step, to_radius));
+            let (end_x, end_y) = point((to_angle, to_radius));
+            commands.push(Command::new(
+                end_x,
+                end_y,
+                CommandType::CubicTo(c1x, c1y, c2x, c2y),
+            ));
+        }
+    }
+    commands.push(Command::new(
+        commands[0].x,
+        commands[0].y,
+        CommandType::Close,
+    ));
+    Path::stroke(commands, color, 0.0).with_render_mode(RenderMode::Fill)
+}
+
+/// Generate a stroke cut out of `glyph` scaled to `height`, consecutive
+/// commands of a random sub-path from a random point until they are about
+/// `length` long, rotated and moved to a random place on the canvas.
+pub fn mimic<R: Rng + ?Sized>(
+    rng: &mut R,
+    glyph: &Path,
+    width: f64,
+    height: f64,
+    length: f64,
+    color: &str,
+) -> Path {
+    let scale = height / glyph.height.max(f64::EPSILON);
+    let glyph = glyph.clone().scale(scale, scale);
+    // the drawing commands of every sub-path with the point they start from
+    let mut sub_paths: Vec<Vec<((f64, f64), Command)>> = Vec::new();
+    let mut from = (0.0, 0.0);
+    for command in &glyph.commands {
+        match command.command_type {
+            CommandType::Move => sub_paths.push(Vec::new()),
+            command_type => {
+                // a cut stroke doesn't start where the sub-path does
+                let command_type = match command_type {
+                    CommandType::Close => CommandType::LineTo,
+                    command_type => command_type,
+                };
+                if sub_paths.is_empty() {
+                    sub_paths.push(Vec::new());
+                }
+                if let Some(sub_path) = sub_paths.last_mut() {
+                    sub_path.push((from, Command::new(command.x, command.y, command_type)));
+                }
+            }
+        }
+        from = (command.x, command.y);
+    }
+    sub_paths.retain(|sub_path| !sub_path.is_empty());
+    let Some(sub_path) = sub_paths.choose(rng) else {
+        return curve(rng, width, height, length, color);
+    };
+
+    let first = rng.gen_range(0..sub_path.len());
+    let (start_x, start_y) = sub_path[first].0;
+    let mut commands = vec![Command::new(start_x, start_y, CommandType::Move)];
+    let mut drawn = 0.0;
+    for ((from_x, from_y), command) in &sub_path[first..] {
+        commands.push(command.clone());
+        drawn += (command.x - from_x).hypot(command.y - from_y);
+        if drawn >= length {
+            break;
+        }
+    }
+    let stroke = Path::stroke(commands, color, height / 12.0);
+    let (center_x, center_y) = stroke.bounds().center();
+    stroke
+        .offset(-center_x, -center_y)
+        .rotate(rng.gen_range(0.0..std::f64::consts::TAU))
+        .offset(rng.gen_range(0.0..width), rng.gen_range(0.0..height * 1.5))
+}
+
+/// Generate a part of a randomly rotated ellipse around the glyph band, the
+/// larger diameter is `length`.
+pub fn arc<R: Rng + ?Sized>(
+    rng: &mut R,
+    width: f64,
+    height: f64,
+    length: f64,
+    color: &str,
+) -> Path {
+    let rx = length / 2.0;
+    let ry = rng.gen_range(0.25..=1.0) * rx;
+    let cx = rng.gen_range(0.0..width);
+    let cy = rng.gen_range(0.25 * height..1.25 * height);
+    let rotation: f64 = rng.gen_range(0.0..180.0);
+    let start = rng.gen_range(0.0..std::f64::consts::TAU);
+    let end = start + rng.gen_range(0.25..0.75) * std::f64::consts::TAU;
+    // point on the rotated ellipse at the parametric angle t
+    let point = |t: f64| {
+        let (sin_phi, cos_phi) = rotation.to_radians().sin_cos();
+        (
+            cx + rx * cos_phi * t.cos() - ry * sin_phi * t.sin(),
+            cy + rx * sin_phi * t.cos() + ry * cos_phi * t.sin(),
+        )
+    };
+    let (start_x, start_y) = point(start);
+    let (end_x, end_y) = point(end);
+    Path::stroke(
+        vec![
+            Command::new(start_x, start_y, CommandType::Move),
+            Command::new(
+                end_x,
+                end_y,
+                CommandType::Arc {
+                    rx,
+                    ry,
+                    rotation,
+                    large_arc: end - start > std::f64::consts::PI,
+                    sweep: true,
+                },
+            ),
+        ],
+        color,
+        height / 18.0,
+    )
+}
+
+/// Generate a small closed circle somewhere on the canvas.
+pub fn circle<R: Rng + ?Sized>(rng: &mut R, width: f64, height: f64, color: &str) -> Path {
+    let r = rng.gen_range(0.05 * height..0.2 * height);
+    let cx = rng.gen_range(0.0..width);
+    let cy = rng.gen_range(0.0..height * 1.5);
+    let half = CommandType::Arc {
+        rx: r,
+        ry: r,
+        rotation: 0.0,
+        large_arc: true,
+        sweep: false,
+    };
+    Path::stroke(
+        vec![
+            Command::new(cx - r, cy, CommandType::Move),
+            Command::new(cx + r, cy, half),
+            Command::new(cx - r, cy, half),
+        ],
+        color,
+        height / 18.0,
+    )
+}
+
+/// Generate a tiny filled dot anywhere on the canvas.
+pub fn dot<R: Rng + ?Sized>(rng: &mut R, width: f64, height: f64, color: &str) -> Dot {
+    Dot {
+        x: rng.gen_range(0.0..width),
+        y: rng.gen_range(0.0..height * 1.5),
+        r: rng.gen_range(0.01 * height..0.04 * height),
+        color: color.to_string(),
+        class: None,
+    }
+}
@@ -0,0 +1,440 @@
+//! Obfuscation pass of the svg output.
+//!
+//! The elements of `BiosvgBuilder::obfuscate` captchas get their attributes
+//! in random order, random junk attributes and ids, and every element writes
+//! its numbers in a style of its own, so the markup can't be fingerprinted
+//! or diffed to tell the glyph paths apart. the pass only changes how the
+//! markup is written, every number keeps its exact value.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// attributes with lists of numbers, rewritten number by number
+const NUMBER_LISTS: &[&str] = &["transform", "stroke-dasharray", "points", "viewBox"];
+
+/// geometry attributes with a single number, numbers in other attributes
+/// such as `aria-label` or `version` are text and kept as they are
+const NUMBERS: &[&str] = &[
+    "x",
+    "y",
+    "width",
+    "height",
+    "cx",
+    "cy",
+    "r",
+    "stroke-width",
+    "stroke-dashoffset",
+];
+
+/// NumberStyle is how an element writes its numbers, the value stays the same
+struct NumberStyle {
+    /// `+` before positive numbers
+    plus: bool,
+    /// trailing zeros added to the fraction
+    zeros: usize,
+    /// fractions as integers with a negative exponent, `1.25` as `125e-2`
+    exponent: bool,
+    /// write or drop the zero before the point of fractions below 1, as
+    /// written if `None`
+    leading_zero: Option<bool>,
+    /// between two numbers of a list
+    separator: &'static str,
+}
+
+impl NumberStyle {
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> NumberStyle {
+        NumberStyle {
+            plus: rng.gen_bool(0.3),
+            zeros: rng.gen_range(0..=2),
+            exponent: rng.gen_bool(0.2),
+            leading_zero: *[None, Some(true), Some(false)].choose(rng).unwrap(),
+            separator: [" ", ",", ", ", "  "].choose(rng).unwrap(),
+        }
+    }
+
+    /// Rewrite a number, numbers with an exponent only get the sign.
+    fn number(&self, number: &str) -> String {
+        let (sign, digits) = match number.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => (
+                if self.plus { "+" } else { "" },
+                number.strip_prefix('+').unwrap_or(number),
+            ),
+        };
+        if digits.contains(['e', 'E']) {
+            return format!("{}{}", sign, digits);
+        }
+        let (int, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let fraction = format!("{}{}", fraction, "0".repeat(self.zeros));
+        if self.exponent && !fraction.is_empty() {
+            return format!("{}{}{}e-{}", sign, int, fraction, fraction.len());
+        }
+        let int = match self.leading_zero {
+            Some(false) if int == "0" && !fraction.is_empty() => "",
+            Some(true) if int.is_empty() => "0",
+            _ => int,
+        };
+        match fraction.is_empty() {
+            true => format!("{}{}", sign, int),
+            false => format!("{}{}.{}", sign, int, fraction),
+        }
+    }
+
+    /// Rewrite every number of a list such as a transform, other text is
+    /// kept with single spaces for the separators.
+    fn numbers(&self, value: &str) -> String {
+        self.rewrite(value, false)
+    }
+
+    /// Rewrite the numbers of path data like `numbers`, the large arc and
+    /// sweep flags of arcs can only be a bare `0` or `1` and are kept.
+    fn path_data(&self, value: &str) -> String {
+        self.rewrite(value, true)
+    }
+
+    fn rewrite(&self, value: &str, path_data: bool) -> String {
+        let bytes = value.as_bytes();
+        let mut out = String::with_capacity(value.len() * 2);
+        let (mut after_number, mut separated) = (false, false);
+        // the path command and the index of its next argument
+        let (mut command, mut argument) = (' ', 0);
+        let mut i = 0;
+        while let Some(ch) = value[i..].chars().next() {
+            if ch == ',' || ch.is_whitespace() {
+                separated = true;
+                i += ch.len_utf8();
+                continue;
+            }
+            let flag = path_data && command == 'a' && matches!(argument % 7, 3 | 4);
+            let end = match flag {
+                true if matches!(ch, '0' | '1') => i + 1,
+                _ => number_end(bytes, i),
+            };
+            if end > i {
+                // a comma may only separate two numbers
+                match after_number {
+                    true => out.push_str(self.separator),
+                    false if separated => out.push(' '),
+                    false => (),
+                }
+                match flag {
+                    true => out.push(ch),
+                    false => out.push_str(&self.number(&value[i..end])),
+                }
+                (i, after_number) = (end, true);
+                argument += 1;
+            } else {
+                if separated {
+                    out.push(' ');
+                }
+                out.push(ch);
+                (i, after_number) = (i + ch.len_utf8(), false);
+                (command, argument) = (ch.to_ascii_lowercase(), 0);
+            }
+            separated = false;
+        }
+        out
+    }
+}
+
+/// End of the number starting at `start`, `start` if none starts there. a
+/// second point or a sign starts the next number, like in compact path data.
+fn number_end(bytes: &[u8], start: usize) -> usize {
+    let digits = |mut i: usize| {
+        while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+            i += 1;
+        }
+        i
+    };
+    let mut i = start;
+    if matches!(bytes.get(i), Some(b'+' | b'-')) {
+        i += 1;
+    }
+    let int_end = digits(i);
+    let mut end = int_end;
+    let mut fraction_digits = 0;
+    if bytes.get(end) == Some(&b'.') {
+        end = digits(end + 1);
+        fraction_digits = end - int_end - 1;
+    }
+    // at least one digit before or after the point
+    if int_end == i && fraction_digits == 0 {
+        return start;
+    }
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let exponent = match bytes.get(end + 1) {
+            Some(b'+' | b'-') => end + 2,
+            _ => end + 1,
+        };
+        if digits(exponent) > exponent {
+            end = digits(exponent);
+        }
+    }
+    end
+}
+
+/// Obfuscate the elements of svg markup written by the scene, cdata,
+/// comments, end tags and text are kept as they are.
+pub(crate) fn obfuscate<R: Rng + ?Sized>(rng: &mut R, svg: &str) -> String {
+    let mut out = String::with_capacity(svg.len() * 3 / 2);
+    let mut ids = HashSet::new();
+    let mut rest = svg;
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = match () {
+            _ if rest.starts_with("<![CDATA[") => "]]>",
+            _ if rest.starts_with("<!--") => "-->",
+            _ => ">",
+        };
+        let split = rest.find(end).map_or(rest.len(), |i| i + end.len());
+        let (tag, tail) = rest.split_at(split);
+        match tag.starts_with("<!") || tag.starts_with("</") {
+            true => out.push_str(tag),
+            false => write_element(rng, &mut out, tag, svg, &mut ids),
+        }
+        rest = tail;
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Write a start tag with shuffled attributes, junk attributes and the
+/// numbers in a random style. tags that don't parse are written as they are.
+fn write_element<R: Rng + ?Sized>(
+    rng: &mut R,
+    out: &mut String,
+    tag: &str,
+    svg: &str,
+    ids: &mut HashSet<String>,
+) {
+    let Some((name, mut attributes, empty)) = parse_tag(tag) else {
+        out.push_str(tag);
+        return;
+    };
+    let style = NumberStyle::random(rng);
+    // css lengths of older parsers take neither signs nor exponents
+    let attribute_style = NumberStyle {
+        plus: false,
+        exponent: false,
+        ..style
+    };
+    for (name, value) in &mut attributes {
+        let numeric = NUMBERS.contains(name)
+            && !value.is_empty()
+            && value.bytes().all(|b| b"0123456789.+-eE".contains(&b))
+            && value.parse::<f64>().is_ok();
+        if *name == "d" {
+            *value = Cow::Owned(style.path_data(value));
+        } else if NUMBER_LISTS.contains(name) {
+            *value = Cow::Owned(style.numbers(value));
+        } else if numeric {
+            *value = Cow::Owned(attribute_style.number(value));
+        }
+    }
+    let mut junk = Vec::new();
+    for _ in 0..rng.gen_range(0..=2) {
+        let key = (0..rng.gen_range(3..=8))
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect::<String>();
+        let key = format!("data-{}", key);
+        if junk.iter().all(|(name, _)| *name != key) {
+            junk.push((key, format!("{:x}", rng.gen::<u32>())));
+        }
+    }
+    if !attributes.iter().any(|(name, _)| *name == "id") && rng.gen_bool(0.3) {
+        // unique in the document, the ids of the scene are left alone
+        let id = loop {
+            let id = format!(
+                "{}{:x}",
+                rng.gen_range(b'a'..=b'z') as char,
+                rng.gen::<u32>()
+            );
+            if !ids.contains(&id) && !svg.contains(&format!("id=\"{}\"", id)) {
+                break id;
+            }
+        };
+        ids.insert(id.clone());
+        junk.push(("id".to_string(), id));
+    }
+    let mut attributes = attributes
+        .iter()
+        .map(|(name, value)| (*name, value.as_ref()))
+        .chain(
+            junk.iter()
+                .map(|(name, value)| (name.as_str(), value.as_str())),
+        )
+        .collect::<Vec<(&str, &str)>>();
+    attributes.shuffle(rng);
+    out.push('<');
+    out.push_str(name);
+    for (name, value) in attributes {
+        out.push_str(&format!(" {}=\"{}\"", name, value));
+    }
+    out.push_str(match (empty, rng.gen_bool(0.5)) {
+        (true, true) => " />",
+        (true, false) => "/>",
+        (false, _) => ">",
+    });
+}
+
+/// name, attributes and whether it's an empty element tag
+type Tag<'a> = (&'a str, Vec<(&'a str, Cow<'a, str>)>, bool);
+
+/// Split a start tag of the scene markup, attribute values are quoted with
+/// `"` and escaped.
+fn parse_tag(tag: &str) -> Option<Tag<'_>> {
+    let inner = tag.strip_prefix('<')?.strip_suffix('>')?;
+    let (inner, empty) = match inner.strip_suffix('/') {
+        Some(inner) => (inner, true),
+        None => (inner, false),
+    };
+    let name_end = inner.find(char::is_whitespace).unwrap_or(inner.len());
+    let (name, mut rest) = inner.split_at(name_end);
+    let mut attributes = Vec::new();
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        let (key, value) = rest.split_once("=\"")?;
+        let (value, tail) = value.split_once('"')?;
+        attributes.push((key.trim(), Cow::Borrowed(value)));
+        rest = tail;
+    }
+    Some((name, attributes, empty))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::tests::assert_well_formed;
+    use crate::{model, AriaOptions, BiosvgBuilder, NoiseStyle};
+
+    #[test]
+    fn obfuscate() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(3)
+            .dots(3)
+            .css_classes(true)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let plain = builder
+            .clone()
+            .scene(&mut StdRng::seed_from_u64(6))
+            .unwrap();
+        let scene = builder
+            .clone()
+            .obfuscate(true)
+            .scene(&mut StdRng::seed_from_u64(6))
+            .unwrap();
+        let (plain, svg) = (plain.to_svg(), scene.to_svg());
+        assert_well_formed(&svg);
+        assert_ne!(svg, plain);
+        assert!(svg.contains(" data-"));
+        assert_eq!(svg.matches("<path").count(), plain.matches("<path").count());
+        // every number of the path data keeps its value
+        let path_data = |svg: &str| {
+            svg.split(" d=\"")
+                .skip(1)
+                .map(|data| {
+                    data[..data.find('"').unwrap()]
+                        .split(|c: char| !(c.is_ascii_digit() || "+-.e".contains(c)))
+                        .filter(|number| !number.is_empty())
+                        .map(|number| number.parse::<f64>().unwrap())
+                        .collect::<Vec<f64>>()
+                })
+                .collect::<Vec<Vec<f64>>>()
+        };
+        assert_eq!(path_data(&svg), path_data(&plain));
+        assert_eq!(scene.to_svg(), svg);
+        // numbers outside of the geometry are text and kept
+        for seed in 0..20 {
+            let (_, svg) = builder
+                .clone()
+                .aria(AriaOptions {
+                    label: Some("1234".to_string()),
+                    ..AriaOptions::default()
+                })
+                .obfuscate(true)
+                .build_with_rng(&mut StdRng::seed_from_u64(seed))
+                .unwrap();
+            assert!(svg.contains(" aria-label=\"1234\""));
+            let svg = super::obfuscate(
+                &mut StdRng::seed_from_u64(seed),
+                "<svg version=\"1.1\" width=\"10\"></svg>",
+            );
+            assert!(svg.contains(" version=\"1.1\""));
+            let width = svg.split(" width=\"").nth(1).unwrap();
+            assert_eq!(width[..width.find('"').unwrap()].parse::<f64>(), Ok(10.0));
+        }
+        let (_, svg) = builder
+            .obfuscate(true)
+            .optimize(2)
+            .nested_groups(2)
+            .build_with_rng(&mut StdRng::seed_from_u64(6))
+            .unwrap();
+        assert_well_formed(&svg);
+
+        // arc flags stay bare, the obfuscated path data parses to the same
+        // commands
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(6)
+            .noise_style(NoiseStyle::Arcs)
+            .colors(vec!["#0078D6".to_string()]);
+        let path_data = |svg: &str| {
+            svg.split(" d=\"")
+                .skip(1)
+                .map(|data| model::Path::parse(&data[..data.find('"').unwrap()]).unwrap())
+                .collect::<Vec<model::Path>>()
+        };
+        let mut arcs = 0;
+        for seed in 0..20 {
+            let plain = builder
+                .clone()
+                .scene(&mut StdRng::seed_from_u64(seed))
+                .unwrap();
+            let scene = builder
+                .clone()
+                .obfuscate(true)
+                .scene(&mut StdRng::seed_from_u64(seed))
+                .unwrap();
+            let (plain, obfuscated) = (path_data(&plain.to_svg()), path_data(&scene.to_svg()));
+            assert_eq!(plain.len(), obfuscated.len());
+            for (plain, obfuscated) in plain.iter().zip(&obfuscated) {
+                assert_eq!(plain.commands.len(), obfuscated.commands.len());
+                for (a, b) in plain.commands.iter().zip(&obfuscated.commands) {
+                    assert!((a.x - b.x).abs() < 1e-6 && (a.y - b.y).abs() < 1e-6);
+                    match (a.command_type, b.command_type) {
+                        (
+                            model::CommandType::Arc {
+                                large_arc, sweep, ..
+                            },
+                            model::CommandType::Arc {
+                                large_arc: obfuscated_large_arc,
+                                sweep: obfuscated_sweep,
+                                ..
+                            },
+                        ) => {
+                            assert_eq!(
+                                (large_arc, sweep),
+                                (obfuscated_large_arc, obfuscated_sweep)
+                            );
+                            arcs += 1;
+                        }
+                        (a, b) => {
+                            assert_eq!(std::mem::discriminant(&a), std::mem::discriminant(&b))
+                        }
+                    }
+                }
+            }
+        }
+        assert!(arcs > 0);
+    }
+}
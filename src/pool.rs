@@ -0,0 +1,204 @@
+//! Captchas generated ahead of time on a background thread.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+
+use super::captcha::entropy_rng;
+use super::model::PathError;
+use super::{BiosvgBuilder, Captcha, CaptchaGenerator};
+
+/// CaptchaPool keeps up to `capacity` captchas generated ahead of time on a
+/// background thread, so handing one out doesn't wait for generation. the
+/// builder is compiled once and every captcha is generated with a fresh
+/// random generator, the `seed` of the builder is ignored.
+#[derive(Debug)]
+pub struct CaptchaPool {
+    shared: Arc<Shared>,
+    worker: Option<JoinHandle<()>>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    generator: CaptchaGenerator,
+    capacity: usize,
+    state: Mutex<State>,
+    refill: Condvar,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    captchas: VecDeque<Captcha>,
+    closed: bool,
+}
+
+impl CaptchaPool {
+    /// start filling a pool of `capacity` captchas of the builder, fails
+    /// like `BiosvgBuilder::compile` if the settings are invalid
+    pub fn new(builder: BiosvgBuilder, capacity: usize) -> Result<CaptchaPool, PathError> {
+        Ok(CaptchaPool::from_generator(builder.compile()?, capacity))
+    }
+
+    /// start filling a pool of `capacity` captchas of a compiled generator
+    pub fn from_generator(generator: CaptchaGenerator, capacity: usize) -> CaptchaPool {
+        let shared = Arc::new(Shared {
+            generator,
+            capacity,
+            state: Mutex::new(State::default()),
+            refill: Condvar::new(),
+        });
+        let worker = {
+            let shared = shared.clone();
+            thread::spawn(move || shared.fill())
+        };
+        CaptchaPool {
+            shared,
+            worker: Some(worker),
+        }
+    }
+
+    /// Take a captcha out of the pool and have it refilled, expired captchas
+    /// are dropped. when the pool is empty the captcha is generated right
+    /// away.
+    pub fn pop(&self) -> Result<Captcha, PathError> {
+        match self.take() {
+            Some(captcha) => Ok(captcha),
+            None => self.shared.generate(),
+        }
+    }
+
+    /// Take a captcha out of the pool like `pop`, when the pool is empty the
+    /// captcha is generated on the blocking thread pool of tokio like
+    /// `build_async`, and fails with `Cancelled` if the runtime shuts down
+    /// before generating.
+    #[cfg(feature = "tokio")]
+    pub async fn pop_async(&self) -> Result<Captcha, PathError> {
+        if let Some(captcha) = self.take() {
+            return Ok(captcha);
+        }
+        let shared = self.shared.clone();
+        match tokio::task::spawn_blocking(move || shared.generate()).await {
+            Ok(captcha) => captcha,
+            Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+            Err(_) => Err(PathError::Cancelled),
+        }
+    }
+
+    /// the settings the pool generates captchas with
+    pub fn builder(&self) -> &BiosvgBuilder {
+        self.shared.generator.builder()
+    }
+
+    /// number of captchas ready in the pool
+    pub fn len(&self) -> usize {
+        self.shared.lock().captchas.len()
+    }
+
+    /// whether no captcha is ready in the pool
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// the number of captchas the pool is kept filled with
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity
+    }
+
+    /// the first captcha that hasn't expired, if any
+    fn take(&self) -> Option<Captcha> {
+        let captcha = {
+            let mut state = self.shared.lock();
+            let mut captcha = state.captchas.pop_front();
+            while captcha.as_ref().is_some_and(Captcha::is_expired) {
+                captcha = state.captchas.pop_front();
+            }
+            captcha
+        };
+        self.shared.refill.notify_one();
+        captcha
+    }
+}
+
+impl Drop for CaptchaPool {
+    fn drop(&mut self) {
+        self.shared.lock().closed = true;
+        self.shared.refill.notify_one();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // the state stays consistent even if a holder panicked
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// a captcha of its own, a seeded generator would repeat the same one
+    fn generate(&self) -> Result<Captcha, PathError> {
+        self.generator.generate_with_rng(&mut entropy_rng())
+    }
+
+    /// generate captchas whenever the pool isn't full, until it's closed.
+    /// a generator that fails is left to `pop` to report.
+    fn fill(&self) {
+        loop {
+            {
+                let mut state = self.lock();
+                while state.captchas.len() >= self.capacity && !state.closed {
+                    state = self
+                        .refill
+                        .wait(state)
+                        .unwrap_or_else(|err| err.into_inner());
+                }
+                if state.closed {
+                    return;
+                }
+            }
+            let Ok(captcha) = self.generate() else {
+                return;
+            };
+            self.lock().captchas.push_back(captcha);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captcha_pool() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()]);
+        let pool = CaptchaPool::new(builder.clone(), 3).unwrap();
+        assert_eq!(pool.capacity(), 3);
+        for _ in 0..500 {
+            if pool.len() == 3 {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert_eq!(pool.len(), 3);
+        for _ in 0..5 {
+            assert_eq!(pool.pop().unwrap().answer.len(), 4);
+        }
+
+        // an invalid builder fails to compile
+        assert!(CaptchaPool::new(BiosvgBuilder::new().charset(""), 3).is_err());
+
+        // a seeded builder still fills the pool with different captchas
+        let pool = CaptchaPool::from_generator(builder.seed(7).compile().unwrap(), 4);
+        assert_eq!(pool.builder().seed, Some(7));
+        let captchas = (0..8)
+            .map(|_| pool.pop().unwrap())
+            .collect::<Vec<Captcha>>();
+        for (i, captcha) in captchas.iter().enumerate() {
+            assert!(captchas[i + 1..]
+                .iter()
+                .all(|other| other.svg != captcha.svg));
+        }
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::resource::FONT_TABLE;
+
+/// Difficulty presets for `BiosvgBuilder::preset`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Difficulty {
+    /// little noise, nearly upright glyphs
+    Easy,
+    /// close to the default look of the captcha
+    Medium,
+    /// more noise, heavier rotation and a light wave warp
+    Hard,
+    /// heavy noise, rotation, scaling and warping
+    Extreme,
+}
+
+/// Accessibility levels for `BiosvgBuilder::accessibility`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AccessibilityLevel {
+    /// the captcha as configured
+    #[default]
+    Standard,
+    /// unsplit glyphs, half the noise, rotation of at most 0.1 radians and
+    /// text colors with a contrast ratio of at least 4.5:1 to the background
+    High,
+}
+
+/// Character sets for `BiosvgBuilder::charset_kind`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Charset {
+    /// lowercase letters of the built-in font table
+    Lower,
+    /// uppercase letters of the built-in font table
+    Upper,
+    /// the digits `2` to `9` of the built-in font table
+    Digits,
+    /// the whole built-in font table
+    #[default]
+    Alphanumeric,
+    /// exactly these characters, every one must have a glyph in the font
+    Custom(String),
+}
+
+impl Charset {
+    /// characters of the set, before the font and exclusions are applied
+    pub fn chars(&self) -> Vec<char> {
+        let filter = |keep: fn(&char) -> bool| FONT_TABLE.iter().copied().filter(keep).collect();
+        match self {
+            Charset::Lower => filter(char::is_ascii_lowercase),
+            Charset::Upper => filter(char::is_ascii_uppercase),
+            Charset::Digits => filter(char::is_ascii_digit),
+            Charset::Alphanumeric => FONT_TABLE.to_vec(),
+            Charset::Custom(charset) => charset.chars().collect(),
+        }
+    }
+
+    /// whether answers of the set should be verified ignoring case, true for
+    /// the single case sets where the user can't tell which case to type
+    pub fn case_insensitive(&self) -> bool {
+        matches!(self, Charset::Lower | Charset::Upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BiosvgBuilder;
+
+    #[test]
+    fn presets() {
+        for preset in [
+            Difficulty::Easy,
+            Difficulty::Medium,
+            Difficulty::Hard,
+            Difficulty::Extreme,
+        ] {
+            let (answer, svg) = BiosvgBuilder::new()
+                .length(4)
+                .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+                .preset(preset)
+                .build()
+                .unwrap();
+            assert_eq!(answer.len(), 4);
+            assert!(svg.starts_with("<svg"));
+        }
+        let builder = BiosvgBuilder::new().preset(Difficulty::Hard).difficulty(3);
+        assert_eq!(builder.difficulty, 3);
+        assert_eq!(builder.split_range, (2, 3));
+    }
+}
@@ -0,0 +1,170 @@
+use tiny_skia::{
+    Color, FillRule, GradientStop, LineCap, LineJoin, LinearGradient, Paint, PathBuilder, Pixmap,
+    Point, Rect, Shader, SpreadMode, Stroke, StrokeDash, Transform,
+};
+
+use super::model::{Background, CommandType, PathError, RenderMode};
+use super::Scene;
+
+/// height of the rendered png image in pixels when no output size is set,
+/// the width keeps the aspect ratio
+pub const PNG_HEIGHT: f64 = 96.0;
+
+/// Render the scene scaled by `scale` and encode the result as png, the
+/// canvas is transparent unless the scene has a background.
+pub(crate) fn render_png(scene: &Scene, scale: f64) -> Result<Vec<u8>, PathError> {
+    let mut pixmap = Pixmap::new(
+        (scene.width * scale).ceil().max(1.0) as u32,
+        (scene.height * scale).ceil().max(1.0) as u32,
+    )
+    .ok_or(PathError::RasterError)?;
+    let transform = Transform::from_scale(scale as f32, scale as f32);
+
+    if let Some(background) = &scene.background {
+        if let Some(rect) = Rect::from_xywh(0.0, 0.0, scene.width as f32, scene.height as f32) {
+            let paint = Paint {
+                shader: background_shader(background, scene.width, scene.height),
+                ..Paint::default()
+            };
+            pixmap.fill_rect(rect, &paint, transform, None);
+        }
+    }
+
+    for dot in &scene.dots {
+        let Some(circle) = PathBuilder::from_circle(dot.x as f32, dot.y as f32, dot.r as f32)
+        else {
+            continue;
+        };
+        let mut paint = Paint::default();
+        paint.set_color(parse_color(&dot.color));
+        paint.anti_alias = true;
+        pixmap.fill_path(&circle, &paint, FillRule::Winding, transform, None);
+    }
+
+    for path in &scene.paths {
+        let mut builder = PathBuilder::new();
+        let mut current = (0.0, 0.0);
+        let commands = path.commands.iter().flat_map(|command| {
+            let (from_x, from_y) = current;
+            current = (command.x, command.y);
+            command.arc_to_cubics(from_x, from_y)
+        });
+        for command in commands {
+            match command.command_type {
+                CommandType::Move => builder.move_to(command.x as f32, command.y as f32),
+                CommandType::LineTo => builder.line_to(command.x as f32, command.y as f32),
+                CommandType::QuadTo(cx, cy) => {
+                    builder.quad_to(cx as f32, cy as f32, command.x as f32, command.y as f32)
+                }
+                CommandType::CubicTo(c1x, c1y, c2x, c2y) => builder.cubic_to(
+                    c1x as f32,
+                    c1y as f32,
+                    c2x as f32,
+                    c2y as f32,
+                    command.x as f32,
+                    command.y as f32,
+                ),
+                CommandType::Close => builder.close(),
+                // arcs are converted to cubic curves above
+                CommandType::Arc { .. } => {}
+            }
+        }
+        // paths with a single point can't be drawn
+        let Some(skia_path) = builder.finish() else {
+            continue;
+        };
+
+        let mut shader = stroke_shader(&path.color, scene);
+        shader.apply_opacity(path.opacity as f32);
+        let paint = Paint {
+            shader,
+            anti_alias: true,
+            ..Paint::default()
+        };
+        if path.render_mode != RenderMode::Stroke {
+            pixmap.fill_path(&skia_path, &paint, FillRule::Winding, transform, None);
+        }
+        if path.render_mode != RenderMode::Fill {
+            // same defaults as svg strokes
+            let stroke = Stroke {
+                width: path.stroke_width as f32,
+                line_cap: LineCap::Butt,
+                line_join: LineJoin::Miter,
+                dash: StrokeDash::new(path.dash.iter().map(|&len| len as f32).collect(), 0.0),
+                ..Stroke::default()
+            };
+            pixmap.stroke_path(&skia_path, &paint, &stroke, transform, None);
+        }
+    }
+
+    pixmap.encode_png().map_err(|_| PathError::RasterError)
+}
+
+/// Solid color or `url(#id)` gradient shader of a path stroke.
+fn stroke_shader(color: &str, scene: &Scene) -> Shader<'static> {
+    let gradient = color
+        .strip_prefix("url(#")
+        .and_then(|id| id.strip_suffix(')'))
+        .and_then(|id| scene.gradients.iter().find(|gradient| gradient.id == id));
+    let Some(gradient) = gradient else {
+        return Shader::SolidColor(parse_color(color));
+    };
+    let stops = gradient
+        .stops()
+        .map(|(offset, color)| GradientStop::new(offset as f32, parse_color(color)))
+        .collect::<Vec<GradientStop>>();
+    LinearGradient::new(
+        Point::from_xy(gradient.x1 as f32, gradient.y1 as f32),
+        Point::from_xy(gradient.x2 as f32, gradient.y2 as f32),
+        stops,
+        SpreadMode::Pad,
+        Transform::identity(),
+    )
+    .unwrap_or(Shader::SolidColor(Color::BLACK))
+}
+
+/// Solid color or linear gradient shader of the background.
+fn background_shader(background: &Background, width: f64, height: f64) -> Shader<'static> {
+    if background.colors.len() < 2 {
+        return Shader::SolidColor(
+            background
+                .colors
+                .first()
+                .map_or(Color::TRANSPARENT, |color| parse_color(color)),
+        );
+    }
+    let stops = background
+        .stops()
+        .map(|(offset, color)| GradientStop::new(offset as f32, parse_color(color)))
+        .collect::<Vec<GradientStop>>();
+    let (x1, y1, x2, y2) = background.gradient_vector();
+    let start = Point::from_xy((x1 * width) as f32, (y1 * height) as f32);
+    let end = Point::from_xy((x2 * width) as f32, (y2 * height) as f32);
+    LinearGradient::new(start, end, stops, SpreadMode::Pad, Transform::identity())
+        .unwrap_or(Shader::SolidColor(Color::TRANSPARENT))
+}
+
+/// Parse a css color, `currentColor` and anything else is drawn in black.
+fn parse_color(color: &str) -> Color {
+    match super::color::parse(color) {
+        Some((rgb, alpha)) => Color::from_rgba8(rgb.r, rgb.g, rgb.b, (alpha * 255.0).round() as u8),
+        None => Color::BLACK,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BiosvgBuilder;
+
+    #[test]
+    fn build_png() {
+        let (answer, png) = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(6)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .build_png()
+            .unwrap();
+        assert_eq!(answer.len(), 4);
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+}
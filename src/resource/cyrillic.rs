@@ -0,0 +1,76 @@
+use crate::model::Glyph;
+
+/// Cyrillic letters of the built-in font sorted by character, converted from
+/// the outlines of DejaVu Sans.
+///
+/// Copyright (c) 2003 by Bitstream, Inc. All Rights Reserved. Bitstream Vera
+/// is a trademark of Bitstream, Inc. DejaVu changes are in public domain.
+/// See https://dejavu-fonts.github.io/License.html for the license terms.
+pub static CYRILLIC_GLYPHS: &[(char, Glyph)] = &[
+    ('Ё', glyph!("M 98.1 -729.0 L 559.1 -729.0 L 559.1 -646.0 L 196.8 -646.0 L 196.8 -430.2 L 543.9 -430.2 L 543.9 -347.2 L 196.8 -347.2 L 196.8 -83.0 L 567.9 -83.0 L 567.9 0.0 L 98.1 0.0 L 98.1 -729.0 M 372.6 -913.1 L 471.7 -913.1 L 471.7 -814.0 L 372.6 -814.0 L 372.6 -913.1 M 181.6 -913.1 L 280.8 -913.1 L 280.8 -814.0 L 181.6 -814.0 L 181.6 -913.1 ", 0.0)),
+    ('А', glyph!("M 341.8 -631.8 L 208.0 -269.0 L 476.1 -269.0 L 341.8 -631.8 M 286.1 -729.0 L 397.9 -729.0 L 675.8 0.0 L 573.2 0.0 L 506.8 -187.0 L 178.2 -187.0 L 111.8 0.0 L 7.8 0.0 L 286.1 -729.0 ", 0.0)),
+    ('Б', glyph!("M 511.2 -214.4 L 507.0 -255.7 L 494.2 -289.3 L 472.9 -315.2 L 442.8 -333.5 L 403.5 -344.5 L 355.0 -348.1 L 196.8 -348.1 L 196.8 -81.1 L 355.0 -81.1 L 403.5 -84.7 L 442.8 -95.7 L 472.9 -114.0 L 494.2 -139.8 L 507.0 -173.3 L 511.2 -214.4 M 564.0 -729.0 L 564.0 -647.9 L 196.8 -647.9 L 196.8 -429.2 L 359.9 -429.2 L 435.3 -423.3 L 498.3 -405.5 L 548.8 -376.0 L 585.7 -334.4 L 607.9 -280.5 L 615.2 -214.4 L 608.1 -148.5 L 586.7 -94.8 L 551.0 -53.2 L 501.2 -23.7 L 437.5 -5.9 L 359.9 0.0 L 98.1 0.0 L 98.1 -729.0 L 564.0 -729.0 ", 0.0)),
+    ('В', glyph!("M 196.8 -348.1 L 196.8 -81.1 L 355.0 -81.1 L 403.5 -84.7 L 442.8 -95.7 L 472.9 -114.0 L 494.2 -139.9 L 507.0 -173.5 L 511.2 -214.8 L 507.0 -256.4 L 494.2 -290.0 L 472.9 -315.7 L 442.8 -333.7 L 403.5 -344.5 L 355.0 -348.1 L 196.8 -348.1 M 196.8 -647.9 L 196.8 -428.2 L 342.8 -428.2 L 386.9 -431.2 L 422.7 -440.3 L 450.4 -455.3 L 470.1 -476.6 L 481.9 -504.2 L 485.8 -538.1 L 481.9 -571.8 L 470.1 -599.3 L 450.4 -620.6 L 422.7 -635.8 L 386.9 -644.9 L 342.8 -647.9 L 196.8 -647.9 M 98.1 -729.0 L 350.1 -729.0 L 419.5 -723.8 L 477.5 -708.2 L 523.9 -682.1 L 557.8 -646.5 L 578.2 -602.1 L 585.0 -548.8 L 581.5 -507.3 L 571.1 -471.8 L 553.7 -442.4 L 529.6 -419.3 L 499.0 -402.9 L 461.9 -393.1 L 506.8 -378.9 L 544.5 -357.2 L 575.0 -327.9 L 597.3 -292.1 L 610.8 -250.8 L 615.2 -204.1 L 607.9 -143.9 L 585.7 -93.6 L 548.8 -53.2 L 498.3 -23.7 L 435.3 -5.9 L 359.9 0.0 L 98.1 0.0 L 98.1 -729.0 ", 0.0)),
+    ('Г', glyph!("M 98.1 0.0 L 98.1 -729.0 L 551.8 -729.0 L 551.8 -646.0 L 196.8 -646.0 L 196.8 0.0 L 98.1 0.0 ", 0.0)),
+    ('Д', glyph!("M 228.0 -83.0 L 550.3 -83.0 L 550.3 -646.0 L 313.5 -646.0 L 313.5 -542.5 L 307.4 -389.2 L 289.2 -254.1 L 258.8 -137.2 L 250.4 -116.4 L 240.1 -98.4 L 228.0 -83.0 M 84.0 -83.0 L 122.4 -106.6 L 150.4 -136.4 L 168.0 -172.4 L 194.3 -285.8 L 210.1 -425.4 L 215.3 -591.3 L 215.3 -729.0 L 648.9 -729.0 L 648.9 -83.0 L 731.9 -83.0 L 731.9 156.7 L 648.9 156.7 L 648.9 0.0 L 132.3 0.0 L 132.3 156.7 L 49.3 156.7 L 49.3 -83.0 L 84.0 -83.0 ", 0.0)),
+    ('Е', glyph!("M 98.1 -729.0 L 559.1 -729.0 L 559.1 -646.0 L 196.8 -646.0 L 196.8 -430.2 L 543.9 -430.2 L 543.9 -347.2 L 196.8 -347.2 L 196.8 -83.0 L 567.9 -83.0 L 567.9 0.0 L 98.1 0.0 L 98.1 -729.0 ", 0.0)),
+    ('Ж', glyph!("M 489.3 -729.0 L 587.9 -729.0 L 587.9 -368.7 L 920.9 -729.0 L 1040.5 -729.0 L 774.4 -441.4 L 1057.6 0.0 L 954.6 0.0 L 713.9 -375.5 L 587.9 -239.3 L 587.9 0.0 L 489.3 0.0 L 489.3 -239.3 L 363.3 -375.5 L 122.6 0.0 L 19.5 0.0 L 302.7 -441.4 L 36.6 -729.0 L 156.3 -729.0 L 489.3 -368.7 L 489.3 -729.0 ", 0.0)),
+    ('З', glyph!("M 285.6 -742.2 L 360.8 -736.5 L 426.3 -719.5 L 481.9 -691.2 L 524.3 -653.2 L 549.6 -607.2 L 558.1 -553.2 L 554.3 -514.9 L 542.7 -480.8 L 523.4 -450.9 L 497.1 -425.9 L 464.2 -406.7 L 424.8 -393.1 L 468.6 -379.3 L 505.4 -358.3 L 535.4 -330.1 L 557.5 -295.7 L 570.8 -256.3 L 575.2 -211.9 L 565.6 -143.2 L 536.7 -86.8 L 488.5 -42.7 L 427.5 -11.1 L 359.8 7.8 L 285.6 14.2 L 244.8 12.4 L 201.9 7.0 L 156.7 -2.0 L 115.8 -12.4 L 85.6 -22.4 L 65.9 -31.7 L 65.9 -133.3 L 112.0 -108.0 L 150.7 -90.0 L 181.9 -79.3 L 211.5 -73.5 L 245.5 -70.0 L 283.7 -68.8 L 340.3 -72.8 L 387.9 -84.5 L 426.3 -104.0 L 454.5 -131.6 L 471.4 -167.6 L 477.1 -211.9 L 472.0 -251.8 L 457.0 -285.4 L 431.9 -312.7 L 396.2 -333.0 L 349.4 -345.1 L 291.5 -349.1 L 191.9 -349.1 L 191.9 -430.2 L 295.4 -430.2 L 348.3 -433.4 L 390.2 -443.1 L 421.4 -459.2 L 442.8 -481.4 L 455.7 -509.4 L 460.0 -543.0 L 455.5 -577.5 L 442.3 -606.2 L 420.2 -629.2 L 387.3 -645.8 L 341.8 -655.8 L 283.7 -659.2 L 244.8 -658.5 L 215.5 -656.6 L 195.8 -653.3 L 172.2 -645.9 L 131.3 -631.4 L 73.2 -609.9 L 73.2 -706.1 L 110.3 -717.8 L 147.9 -727.1 L 186.0 -733.9 L 222.7 -738.5 L 255.9 -741.3 L 285.6 -742.2 ", 0.0)),
+    ('И', glyph!("M 649.9 -729.0 L 649.9 0.0 L 554.2 0.0 L 554.2 -609.9 L 231.0 0.0 L 98.1 0.0 L 98.1 -729.0 L 193.8 -729.0 L 193.8 -119.1 L 517.1 -729.0 L 649.9 -729.0 ", 0.0)),
+    ('Й', glyph!("M 649.9 -729.0 L 649.9 0.0 L 554.2 0.0 L 554.2 -609.9 L 231.0 0.0 L 98.1 0.0 L 98.1 -729.0 L 193.8 -729.0 L 193.8 -119.1 L 517.1 -729.0 L 649.9 -729.0 M 216.8 -928.2 L 274.4 -928.2 L 280.6 -912.0 L 290.8 -898.6 L 304.9 -887.9 L 322.9 -880.2 L 344.4 -875.6 L 369.6 -874.0 L 394.5 -875.5 L 415.7 -880.0 L 433.3 -887.5 L 447.4 -897.9 L 457.9 -911.5 L 464.8 -928.2 L 522.5 -928.2 L 515.4 -892.6 L 500.8 -863.2 L 478.5 -840.1 L 449.1 -823.4 L 412.8 -813.4 L 369.6 -810.1 L 326.3 -813.4 L 289.9 -823.3 L 260.5 -839.8 L 238.4 -862.9 L 223.8 -892.4 L 216.8 -928.2 ", 0.0)),
+    ('К', glyph!("M 98.1 -729.0 L 196.8 -729.0 L 196.8 -368.7 L 549.3 -729.0 L 675.8 -729.0 L 394.0 -440.9 L 690.4 0.0 L 583.0 0.0 L 330.1 -375.5 L 196.8 -239.3 L 196.8 0.0 L 98.1 0.0 L 98.1 -729.0 ", 0.0)),
+    ('Л', glyph!("M 41.0 0.0 L 41.0 -83.0 L 103.2 -104.7 L 148.7 -139.0 L 177.2 -186.0 L 200.8 -288.6 L 215.0 -428.2 L 219.7 -605.0 L 219.7 -729.0 L 653.3 -729.0 L 653.3 0.0 L 554.7 0.0 L 554.7 -646.0 L 317.9 -646.0 L 317.9 -556.2 L 312.3 -388.3 L 295.7 -253.2 L 268.1 -150.9 L 221.6 -79.2 L 145.9 -28.9 L 41.0 0.0 ", 0.0)),
+    ('М', glyph!("M 98.1 -729.0 L 245.1 -729.0 L 431.2 -232.9 L 618.2 -729.0 L 765.1 -729.0 L 765.1 0.0 L 668.9 0.0 L 668.9 -640.1 L 481.0 -140.1 L 381.8 -140.1 L 193.8 -640.1 L 193.8 0.0 L 98.1 0.0 L 98.1 -729.0 ", 0.0)),
+    ('Н', glyph!("M 98.1 -729.0 L 196.8 -729.0 L 196.8 -430.2 L 555.2 -430.2 L 555.2 -729.0 L 653.8 -729.0 L 653.8 0.0 L 555.2 0.0 L 555.2 -347.2 L 196.8 -347.2 L 196.8 0.0 L 98.1 0.0 L 98.1 -729.0 ", 0.0)),
+    ('О', glyph!("M 394.0 -662.1 L 327.3 -653.2 L 270.5 -626.5 L 223.4 -582.0 L 188.3 -522.2 L 167.2 -449.4 L 160.2 -363.8 L 167.2 -278.4 L 188.3 -205.8 L 223.4 -146.0 L 270.5 -101.5 L 327.3 -74.8 L 394.0 -65.9 L 460.7 -74.8 L 517.4 -101.5 L 564.2 -146.0 L 599.1 -205.8 L 620.0 -278.4 L 627.0 -363.8 L 620.0 -449.4 L 599.1 -522.2 L 564.2 -582.0 L 517.4 -626.5 L 460.7 -653.2 L 394.0 -662.1 M 394.0 -742.2 L 489.4 -730.8 L 571.1 -696.5 L 639.2 -639.4 L 690.2 -563.1 L 720.8 -471.2 L 731.0 -363.8 L 720.8 -256.6 L 690.2 -164.9 L 639.2 -88.6 L 571.1 -31.5 L 489.4 2.7 L 394.0 14.2 L 298.4 2.8 L 216.4 -31.4 L 148.2 -88.4 L 97.1 -164.6 L 66.4 -256.3 L 56.2 -363.8 L 66.4 -471.2 L 97.1 -563.1 L 148.2 -639.4 L 216.4 -696.5 L 298.4 -730.8 L 394.0 -742.2 ", 0.0)),
+    ('П', glyph!("M 653.8 -729.0 L 653.8 0.0 L 555.2 0.0 L 555.2 -646.0 L 196.8 -646.0 L 196.8 0.0 L 98.1 0.0 L 98.1 -729.0 L 653.8 -729.0 ", 0.0)),
+    ('Р', glyph!("M 196.8 -647.9 L 196.8 -374.0 L 320.8 -374.0 L 363.2 -378.0 L 398.7 -389.9 L 427.2 -409.7 L 448.1 -436.8 L 460.7 -470.6 L 464.8 -511.2 L 460.7 -551.5 L 448.1 -585.2 L 427.2 -612.3 L 398.7 -632.1 L 363.2 -644.0 L 320.8 -647.9 L 196.8 -647.9 M 98.1 -729.0 L 320.8 -729.0 L 395.9 -722.8 L 457.6 -704.4 L 506.1 -673.6 L 541.0 -630.9 L 561.9 -576.8 L 568.8 -511.2 L 561.9 -445.1 L 541.0 -390.8 L 506.1 -348.1 L 457.6 -317.5 L 395.9 -299.1 L 320.8 -293.0 L 196.8 -293.0 L 196.8 0.0 L 98.1 0.0 L 98.1 -729.0 ", 0.0)),
+    ('С', glyph!("M 644.0 -672.9 L 644.0 -568.8 L 610.1 -597.2 L 574.7 -620.3 L 537.8 -638.2 L 499.5 -650.9 L 459.5 -658.6 L 418.0 -661.1 L 341.1 -652.6 L 277.3 -627.2 L 226.6 -584.7 L 189.7 -526.2 L 167.5 -452.6 L 160.2 -363.8 L 167.5 -275.3 L 189.7 -201.8 L 226.6 -143.3 L 277.3 -100.9 L 341.1 -75.4 L 418.0 -66.9 L 459.5 -69.4 L 499.5 -77.1 L 537.8 -89.8 L 574.7 -107.7 L 610.1 -130.9 L 644.0 -159.2 L 644.0 -56.2 L 608.9 -34.7 L 572.3 -17.1 L 534.4 -3.4 L 495.1 6.3 L 454.3 12.2 L 412.1 14.2 L 309.6 2.9 L 222.7 -30.9 L 151.4 -87.2 L 98.5 -162.9 L 66.7 -255.1 L 56.2 -363.8 L 66.7 -472.7 L 98.5 -565.0 L 151.4 -640.9 L 222.7 -697.2 L 309.6 -730.9 L 412.1 -742.2 L 454.9 -740.3 L 496.0 -734.5 L 535.4 -724.9 L 573.2 -711.4 L 609.4 -694.0 L 644.0 -672.9 ", 0.0)),
+    ('Т', glyph!("M -2.9 -729.0 L 613.8 -729.0 L 613.8 -646.0 L 355.0 -646.0 L 355.0 0.0 L 255.9 0.0 L 255.9 -646.0 L -2.9 -646.0 L -2.9 -729.0 ", 0.0)),
+    ('У', glyph!("M 319.8 -88.4 L 312.4 -72.8 L 303.8 -58.4 L 293.9 -45.4 L 258.9 -20.2 L 205.2 -5.0 L 132.8 0.0 L 95.2 0.0 L 95.2 -83.0 L 126.0 -83.0 L 160.8 -84.5 L 187.6 -88.9 L 206.5 -96.2 L 215.1 -103.1 L 222.6 -112.7 L 229.0 -125.0 L 251.0 -176.8 L 17.1 -729.0 L 123.0 -729.0 L 304.2 -301.3 L 486.3 -729.0 L 592.3 -729.0 L 319.8 -88.4 ", 0.0)),
+    ('Ф', glyph!("M 381.3 -579.1 L 316.2 -568.1 L 262.2 -548.3 L 219.2 -519.5 L 188.0 -481.2 L 169.3 -432.5 L 163.1 -373.5 L 169.3 -314.6 L 188.0 -266.0 L 219.2 -227.8 L 262.2 -199.2 L 316.2 -179.4 L 381.3 -168.5 L 381.3 -579.1 M 480.5 -168.5 L 545.5 -179.4 L 599.4 -199.2 L 642.1 -227.8 L 673.0 -266.0 L 691.6 -314.6 L 697.8 -373.5 L 691.6 -432.5 L 673.0 -481.2 L 642.1 -519.5 L 599.4 -548.3 L 545.5 -568.1 L 480.5 -579.1 L 480.5 -168.5 M 381.3 -86.9 L 286.1 -101.3 L 206.5 -129.3 L 142.8 -171.1 L 96.3 -226.1 L 68.4 -293.5 L 59.1 -373.5 L 68.4 -453.6 L 96.3 -521.2 L 142.8 -576.4 L 206.5 -618.5 L 286.1 -646.7 L 381.3 -661.1 L 381.3 -729.0 L 480.5 -729.0 L 480.5 -661.1 L 575.7 -646.8 L 655.1 -618.7 L 718.5 -576.9 L 764.8 -521.9 L 792.5 -454.1 L 801.8 -373.5 L 792.5 -293.5 L 764.8 -226.1 L 718.5 -171.1 L 655.1 -129.3 L 575.7 -101.3 L 480.5 -86.9 L 480.5 0.0 L 381.3 0.0 L 381.3 -86.9 ", 0.0)),
+    ('Х', glyph!("M 63.0 -729.0 L 168.9 -729.0 L 350.1 -458.0 L 532.2 -729.0 L 638.2 -729.0 L 403.8 -378.9 L 653.8 0.0 L 547.9 0.0 L 342.8 -310.1 L 136.2 0.0 L 29.8 0.0 L 290.0 -389.2 L 63.0 -729.0 ", 0.0)),
+    ('Ц', glyph!("M 653.8 0.0 L 98.1 0.0 L 98.1 -729.0 L 196.8 -729.0 L 196.8 -83.0 L 555.2 -83.0 L 555.2 -729.0 L 653.8 -729.0 L 653.8 -83.0 L 736.8 -83.0 L 736.8 156.7 L 653.8 156.7 L 653.8 0.0 ", 0.0)),
+    ('Ч', glyph!("M 488.3 0.0 L 488.3 -298.8 L 284.7 -298.8 L 228.2 -305.1 L 179.9 -324.1 L 139.6 -355.7 L 109.5 -400.2 L 91.5 -457.9 L 85.4 -528.8 L 85.4 -729.0 L 183.6 -729.0 L 183.6 -536.6 L 187.0 -489.2 L 197.0 -450.5 L 213.9 -420.4 L 237.4 -399.0 L 267.7 -386.1 L 304.7 -381.8 L 488.3 -381.8 L 488.3 -729.0 L 587.4 -729.0 L 587.4 0.0 L 488.3 0.0 ", 0.0)),
+    ('Ш', glyph!("M 584.0 -83.0 L 872.6 -83.0 L 872.6 -729.0 L 971.2 -729.0 L 971.2 0.0 L 98.1 0.0 L 98.1 -729.0 L 196.8 -729.0 L 196.8 -83.0 L 485.4 -83.0 L 485.4 -729.0 L 584.0 -729.0 L 584.0 -83.0 ", 0.0)),
+    ('Щ', glyph!("M 971.2 0.0 L 98.1 0.0 L 98.1 -729.0 L 196.8 -729.0 L 196.8 -83.0 L 485.4 -83.0 L 485.4 -729.0 L 584.0 -729.0 L 584.0 -83.0 L 872.6 -83.0 L 872.6 -729.0 L 971.2 -729.0 L 971.2 -83.0 L 1054.2 -83.0 L 1054.2 156.7 L 971.2 156.7 L 971.2 0.0 ", 0.0)),
+    ('Ъ', glyph!("M 244.6 0.0 L 244.6 -646.0 L 29.3 -646.0 L 29.3 -729.0 L 343.3 -729.0 L 343.3 -429.2 L 506.3 -429.2 L 581.8 -423.3 L 644.8 -405.5 L 695.3 -376.0 L 732.2 -334.4 L 754.3 -280.5 L 761.7 -214.4 L 754.3 -148.5 L 732.2 -94.8 L 695.3 -53.2 L 644.8 -23.7 L 581.8 -5.9 L 506.3 0.0 L 244.6 0.0 M 657.7 -214.4 L 653.4 -255.8 L 640.6 -289.4 L 619.1 -315.4 L 589.1 -333.6 L 549.9 -344.5 L 501.5 -348.1 L 343.3 -348.1 L 343.3 -81.1 L 501.5 -81.1 L 549.5 -84.7 L 588.7 -95.8 L 619.1 -114.3 L 640.6 -139.9 L 653.4 -173.3 L 657.7 -214.4 ", 0.0)),
+    ('Ы', glyph!("M 511.2 -214.4 L 506.9 -255.8 L 494.1 -289.4 L 472.7 -315.4 L 442.7 -333.6 L 403.4 -344.5 L 355.0 -348.1 L 196.8 -348.1 L 196.8 -81.1 L 355.0 -81.1 L 403.4 -84.7 L 442.7 -95.8 L 472.7 -114.3 L 494.1 -139.9 L 506.9 -173.3 L 511.2 -214.4 M 98.1 -729.0 L 196.8 -729.0 L 196.8 -429.2 L 359.9 -429.2 L 435.3 -423.3 L 498.3 -405.5 L 548.8 -376.0 L 585.7 -334.4 L 607.9 -280.5 L 615.2 -214.4 L 607.9 -148.5 L 585.7 -94.8 L 548.8 -53.2 L 498.3 -23.7 L 435.3 -5.9 L 359.9 0.0 L 98.1 0.0 L 98.1 -729.0 M 685.5 -729.0 L 784.2 -729.0 L 784.2 0.0 L 685.5 0.0 L 685.5 -729.0 ", 0.0)),
+    ('Ь', glyph!("M 511.2 -214.4 L 506.9 -255.8 L 494.1 -289.4 L 472.7 -315.4 L 442.7 -333.6 L 403.4 -344.5 L 355.0 -348.1 L 196.8 -348.1 L 196.8 -81.1 L 355.0 -81.1 L 403.4 -84.7 L 442.7 -95.8 L 472.7 -114.3 L 494.1 -139.9 L 506.9 -173.3 L 511.2 -214.4 M 98.1 -729.0 L 196.8 -729.0 L 196.8 -429.2 L 359.9 -429.2 L 435.3 -423.3 L 498.3 -405.5 L 548.8 -376.0 L 585.7 -334.4 L 607.9 -280.5 L 615.2 -214.4 L 607.9 -148.5 L 585.7 -94.8 L 548.8 -53.2 L 498.3 -23.7 L 435.3 -5.9 L 359.9 0.0 L 98.1 0.0 L 98.1 -729.0 ", 0.0)),
+    ('Э', glyph!("M 54.2 -159.2 L 125.3 -107.9 L 201.3 -77.1 L 282.2 -66.9 L 360.3 -74.6 L 422.6 -97.7 L 469.2 -136.2 L 502.7 -187.7 L 525.7 -249.7 L 538.1 -322.3 L 148.4 -322.3 L 148.4 -405.3 L 538.1 -405.3 L 525.7 -477.8 L 502.7 -539.9 L 469.2 -591.6 L 422.6 -630.2 L 360.3 -653.4 L 282.2 -661.1 L 201.3 -650.9 L 125.3 -620.1 L 54.2 -568.8 L 54.2 -672.9 L 125.3 -711.4 L 203.3 -734.5 L 288.1 -742.2 L 389.5 -730.9 L 475.8 -697.2 L 546.9 -640.9 L 599.8 -565.1 L 631.5 -472.9 L 642.1 -364.3 L 631.5 -255.3 L 599.8 -163.0 L 546.9 -87.2 L 475.8 -30.9 L 389.5 2.9 L 288.1 14.2 L 204.2 6.3 L 126.2 -17.1 L 54.2 -56.2 L 54.2 -159.2 ", 0.0)),
+    ('Ю', glyph!("M 686.5 -662.1 L 619.8 -653.2 L 562.8 -626.5 L 515.6 -582.0 L 480.6 -522.2 L 459.6 -449.4 L 452.6 -363.8 L 459.6 -278.4 L 480.6 -205.8 L 515.6 -146.0 L 562.8 -101.5 L 619.8 -74.8 L 686.5 -65.9 L 753.1 -74.8 L 809.8 -101.5 L 856.4 -146.0 L 891.4 -205.8 L 912.4 -278.4 L 919.4 -363.8 L 912.4 -449.4 L 891.4 -522.2 L 856.4 -582.0 L 809.8 -626.5 L 753.1 -653.2 L 686.5 -662.1 M 350.1 -405.8 L 363.7 -492.8 L 395.2 -571.8 L 444.8 -642.6 L 510.7 -697.9 L 591.3 -731.1 L 686.5 -742.2 L 781.9 -730.8 L 863.6 -696.6 L 931.6 -639.6 L 982.6 -563.2 L 1013.2 -471.2 L 1023.4 -363.8 L 1013.2 -256.6 L 982.6 -165.0 L 931.6 -88.9 L 863.6 -31.6 L 781.9 2.7 L 686.5 14.2 L 591.3 3.0 L 510.7 -30.3 L 444.8 -85.9 L 395.2 -156.7 L 363.7 -235.7 L 350.1 -322.8 L 201.7 -322.8 L 201.7 0.0 L 103.0 0.0 L 103.0 -729.0 L 201.7 -729.0 L 201.7 -405.8 L 350.1 -405.8 ", 0.0)),
+    ('Я', glyph!("M 200.7 -519.0 L 204.7 -479.8 L 216.9 -447.5 L 237.1 -422.1 L 265.2 -403.8 L 301.1 -392.8 L 344.7 -389.2 L 498.0 -389.2 L 498.0 -647.9 L 344.7 -647.9 L 301.1 -644.3 L 265.2 -633.5 L 237.1 -615.5 L 216.9 -590.3 L 204.7 -558.2 L 200.7 -519.0 M 66.4 0.0 L 265.6 -318.8 L 231.6 -330.5 L 194.4 -348.5 L 154.3 -372.8 L 119.6 -407.4 L 98.7 -456.1 L 91.8 -519.0 L 98.8 -581.0 L 120.0 -632.8 L 155.3 -674.3 L 204.5 -704.7 L 267.7 -722.9 L 344.7 -729.0 L 596.7 -729.0 L 596.7 0.0 L 498.0 0.0 L 498.0 -308.1 L 366.2 -308.1 L 173.8 0.0 L 66.4 0.0 ", 0.0)),
+    ('а', glyph!("M 342.8 -274.9 L 277.6 -272.1 L 227.3 -263.8 L 191.9 -250.0 L 168.6 -229.5 L 154.6 -201.2 L 149.9 -165.0 L 153.4 -135.3 L 163.9 -110.0 L 181.4 -89.1 L 204.9 -73.5 L 233.5 -64.2 L 267.1 -61.0 L 313.6 -66.9 L 353.6 -84.6 L 387.0 -114.0 L 412.1 -153.2 L 427.1 -200.2 L 432.1 -254.9 L 432.1 -274.9 L 342.8 -274.9 M 522.0 -312.0 L 522.0 0.0 L 432.1 0.0 L 432.1 -83.0 L 409.9 -52.7 L 384.4 -28.2 L 355.5 -9.5 L 322.6 3.6 L 285.2 11.5 L 243.2 14.2 L 191.0 8.9 L 146.5 -6.8 L 109.6 -33.0 L 82.1 -67.9 L 65.6 -110.0 L 60.1 -159.2 L 66.9 -215.7 L 87.5 -262.0 L 121.8 -298.3 L 169.8 -324.4 L 231.2 -340.0 L 306.2 -345.2 L 432.1 -345.2 L 432.1 -354.0 L 427.6 -392.2 L 414.0 -424.2 L 391.4 -450.0 L 360.5 -468.8 L 322.3 -480.1 L 276.9 -483.9 L 245.9 -482.6 L 215.4 -478.9 L 185.5 -472.7 L 156.3 -463.9 L 127.8 -452.7 L 100.1 -439.0 L 100.1 -522.0 L 132.6 -533.6 L 164.6 -543.1 L 195.8 -550.5 L 226.5 -555.8 L 256.6 -559.0 L 286.1 -560.1 L 358.6 -553.2 L 417.6 -532.7 L 463.4 -498.5 L 495.9 -450.5 L 515.5 -388.3 L 522.0 -312.0 ", 0.0)),
+    ('б', glyph!("M 311.0 -560.1 L 383.6 -551.6 L 444.9 -526.2 L 495.1 -483.9 L 532.3 -426.6 L 554.6 -356.3 L 562.0 -272.9 L 554.6 -189.9 L 532.3 -119.6 L 495.1 -62.3 L 444.9 -19.8 L 383.6 5.7 L 311.0 14.2 L 238.4 5.9 L 177.6 -18.8 L 128.4 -60.1 L 92.0 -116.7 L 69.2 -187.7 L 60.1 -272.9 L 57.1 -384.8 L 55.8 -419.3 L 55.0 -445.3 L 54.7 -462.9 L 57.8 -512.7 L 67.3 -557.9 L 83.0 -598.6 L 116.2 -653.2 L 156.7 -696.3 L 204.3 -728.0 L 261.4 -749.8 L 330.0 -763.2 L 410.2 -768.1 L 446.2 -769.3 L 474.8 -772.2 L 496.1 -776.9 L 521.0 -705.6 L 505.3 -700.1 L 490.0 -696.7 L 475.1 -695.3 L 353.0 -687.0 L 314.7 -682.2 L 281.8 -673.6 L 254.4 -661.1 L 199.4 -621.1 L 163.9 -577.1 L 147.9 -529.3 L 144.5 -502.0 L 191.2 -534.2 L 246.7 -553.6 L 311.0 -560.1 M 311.0 -483.9 L 266.2 -477.6 L 228.1 -458.8 L 196.8 -427.5 L 173.4 -385.3 L 159.5 -333.7 L 154.8 -272.9 L 159.4 -212.2 L 173.3 -160.6 L 196.5 -118.4 L 227.8 -87.1 L 266.0 -68.3 L 311.0 -62.0 L 355.6 -68.3 L 393.5 -87.2 L 424.8 -118.7 L 448.1 -161.0 L 462.1 -212.4 L 466.8 -272.9 L 462.1 -333.3 L 448.1 -384.6 L 424.8 -427.0 L 393.5 -458.6 L 355.6 -477.6 L 311.0 -483.9 ", 0.0)),
+    ('в', glyph!("M 180.7 -252.0 L 180.7 -71.8 L 308.6 -71.8 L 346.4 -74.4 L 377.6 -82.1 L 402.3 -95.0 L 420.2 -112.7 L 431.0 -135.1 L 434.6 -162.1 L 431.0 -189.1 L 420.2 -211.4 L 402.3 -229.0 L 377.6 -241.8 L 346.4 -249.4 L 308.6 -252.0 L 180.7 -252.0 M 180.7 -475.1 L 180.7 -323.7 L 298.8 -323.7 L 330.6 -326.0 L 358.3 -332.7 L 381.8 -344.0 L 399.7 -359.3 L 410.5 -378.1 L 414.1 -400.4 L 410.5 -422.6 L 399.7 -441.1 L 381.8 -455.8 L 358.3 -466.5 L 330.6 -473.0 L 298.8 -475.1 L 180.7 -475.1 M 90.8 -546.9 L 304.7 -546.9 L 363.9 -543.0 L 413.2 -531.3 L 452.6 -511.7 L 481.4 -485.0 L 498.6 -451.6 L 504.4 -411.6 L 501.5 -380.4 L 492.7 -353.8 L 478.0 -331.8 L 457.6 -314.6 L 431.6 -302.3 L 399.9 -294.9 L 438.2 -284.3 L 470.2 -268.0 L 496.1 -246.1 L 515.1 -219.3 L 526.5 -188.4 L 530.3 -153.3 L 524.0 -108.2 L 505.2 -70.4 L 473.9 -40.0 L 430.9 -17.8 L 377.3 -4.4 L 313.0 0.0 L 90.8 0.0 L 90.8 -546.9 ", 0.0)),
+    ('г', glyph!("M 90.8 0.0 L 90.8 -546.9 L 476.6 -546.9 L 476.6 -475.1 L 181.2 -475.1 L 181.2 0.0 L 90.8 0.0 ", 0.0)),
+    ('д', glyph!("M 216.3 -71.8 L 477.1 -71.8 L 477.1 -475.1 L 288.1 -475.1 L 288.1 -406.7 L 281.7 -283.0 L 262.5 -180.2 L 230.5 -98.1 L 216.3 -71.8 M 85.9 -71.8 L 112.8 -87.2 L 134.1 -107.6 L 149.9 -132.8 L 176.5 -213.1 L 192.4 -316.6 L 197.8 -443.4 L 197.8 -546.9 L 567.4 -546.9 L 567.4 -71.8 L 639.2 -71.8 L 639.2 138.2 L 567.4 138.2 L 567.4 0.0 L 124.0 0.0 L 124.0 138.2 L 52.2 138.2 L 52.2 -71.8 L 85.9 -71.8 ", 0.0)),
+    ('е', glyph!("M 562.0 -295.9 L 562.0 -252.0 L 148.9 -252.0 L 157.7 -195.0 L 176.4 -147.9 L 204.8 -110.6 L 242.6 -83.6 L 289.0 -67.4 L 344.2 -62.0 L 378.4 -63.4 L 411.8 -67.7 L 444.6 -74.7 L 476.9 -84.6 L 509.0 -97.3 L 541.0 -112.8 L 541.0 -27.8 L 508.5 -15.2 L 475.5 -4.8 L 441.9 3.4 L 407.9 9.4 L 373.5 13.0 L 338.9 14.2 L 257.7 5.7 L 188.6 -19.7 L 131.6 -62.0 L 89.1 -118.8 L 63.7 -187.4 L 55.2 -268.1 L 63.2 -351.4 L 87.4 -422.5 L 127.7 -481.2 L 181.6 -525.0 L 246.8 -551.3 L 323.2 -560.1 L 391.7 -552.2 L 449.9 -528.5 L 497.8 -489.0 L 533.5 -436.0 L 554.9 -371.6 L 562.0 -295.9 M 472.2 -322.3 L 467.1 -368.1 L 453.4 -407.3 L 430.9 -439.9 L 401.2 -464.4 L 365.6 -479.0 L 324.2 -483.9 L 277.4 -479.2 L 237.3 -465.0 L 203.9 -441.4 L 178.0 -409.2 L 160.7 -369.4 L 151.9 -321.8 L 472.2 -322.3 ", 0.0)),
+    ('ж', glyph!("M 405.8 -546.9 L 495.1 -546.9 L 495.1 -290.0 L 733.9 -546.9 L 838.4 -546.9 L 642.1 -336.4 L 866.7 0.0 L 770.5 0.0 L 586.4 -276.4 L 495.1 -178.2 L 495.1 0.0 L 405.8 0.0 L 405.8 -178.2 L 314.5 -276.4 L 130.4 0.0 L 34.2 0.0 L 258.8 -336.4 L 62.5 -546.9 L 167.0 -546.9 L 405.8 -290.0 L 405.8 -546.9 ", 0.0)),
+    ('з', glyph!("M 344.7 -294.9 L 382.1 -284.5 L 413.5 -268.7 L 439.0 -247.6 L 457.7 -221.9 L 468.9 -192.4 L 472.7 -159.2 L 465.7 -107.3 L 444.7 -64.3 L 409.7 -30.3 L 361.8 -5.6 L 302.0 9.2 L 230.5 14.2 L 204.2 13.4 L 177.4 11.1 L 150.1 7.3 L 122.3 2.0 L 93.9 -4.8 L 64.9 -13.2 L 64.9 -96.7 L 88.8 -85.6 L 114.2 -76.6 L 141.1 -69.6 L 169.2 -64.6 L 198.2 -61.6 L 228.0 -60.5 L 273.6 -63.6 L 311.9 -72.7 L 343.0 -87.9 L 365.9 -108.0 L 379.7 -131.7 L 384.3 -159.2 L 380.2 -186.0 L 368.0 -209.0 L 347.7 -228.3 L 319.9 -242.8 L 285.4 -251.5 L 244.1 -254.4 L 171.9 -254.4 L 171.9 -328.6 L 247.6 -328.6 L 282.7 -331.0 L 312.6 -338.3 L 337.2 -350.3 L 355.5 -366.2 L 366.5 -384.8 L 370.1 -406.3 L 366.3 -426.1 L 355.0 -444.0 L 336.2 -460.0 L 310.9 -472.4 L 280.2 -479.9 L 244.1 -482.4 L 220.5 -481.7 L 195.9 -479.6 L 170.2 -476.1 L 143.1 -471.0 L 114.2 -464.1 L 83.5 -455.6 L 83.5 -537.1 L 114.7 -544.3 L 144.5 -550.1 L 172.9 -554.7 L 200.0 -557.9 L 226.2 -559.9 L 251.5 -560.5 L 310.8 -556.3 L 361.2 -543.5 L 402.6 -522.2 L 433.4 -493.3 L 451.9 -457.5 L 458.0 -415.0 L 454.8 -386.3 L 445.0 -360.7 L 428.7 -338.1 L 406.4 -319.3 L 378.4 -304.9 L 344.7 -294.9 ", 0.0)),
+    ('и', glyph!("M 559.1 -546.9 L 559.1 0.0 L 469.7 0.0 L 469.7 -439.0 L 206.1 0.0 L 90.8 0.0 L 90.8 -546.9 L 180.2 -546.9 L 180.2 -108.9 L 443.4 -546.9 L 559.1 -546.9 ", 0.0)),
+    ('й', glyph!("M 559.1 -546.9 L 559.1 0.0 L 469.7 0.0 L 469.7 -439.0 L 206.1 0.0 L 90.8 0.0 L 90.8 -546.9 L 180.2 -546.9 L 180.2 -108.9 L 443.4 -546.9 L 559.1 -546.9 M 172.4 -759.8 L 230.0 -759.8 L 235.6 -737.4 L 245.3 -719.1 L 259.0 -704.8 L 276.9 -694.7 L 299.0 -688.6 L 325.2 -686.5 L 351.1 -688.5 L 372.9 -694.6 L 390.6 -704.6 L 404.4 -718.8 L 414.3 -737.1 L 420.4 -759.8 L 478.0 -759.8 L 471.0 -717.1 L 456.5 -682.1 L 434.6 -654.8 L 405.3 -635.3 L 368.8 -623.5 L 325.2 -619.6 L 281.6 -623.5 L 245.1 -635.3 L 215.8 -654.8 L 193.8 -682.1 L 179.4 -717.1 L 172.4 -759.8 ", 0.0)),
+    ('к', glyph!("M 90.8 -546.9 L 180.2 -546.9 L 180.2 -290.0 L 433.6 -546.9 L 543.9 -546.9 L 335.0 -335.4 L 570.8 0.0 L 470.2 0.0 L 276.4 -275.9 L 180.2 -178.2 L 180.2 0.0 L 90.8 0.0 L 90.8 -546.9 ", 0.0)),
+    ('л', glyph!("M 37.1 0.0 L 37.1 -74.7 L 89.5 -89.1 L 128.3 -114.2 L 153.3 -149.9 L 171.8 -222.5 L 182.8 -325.0 L 186.5 -457.5 L 186.5 -546.9 L 556.2 -546.9 L 556.2 0.0 L 466.3 0.0 L 466.3 -475.1 L 276.4 -475.1 L 276.4 -420.9 L 271.6 -293.2 L 257.3 -191.3 L 233.4 -115.2 L 191.2 -57.5 L 125.8 -19.1 L 37.1 0.0 ", 0.0)),
+    ('м', glyph!("M 90.8 -546.9 L 222.2 -546.9 L 377.4 -180.7 L 533.2 -546.9 L 663.6 -546.9 L 663.6 0.0 L 573.2 0.0 L 573.2 -460.9 L 422.4 -105.0 L 332.5 -105.0 L 181.2 -460.9 L 181.2 0.0 L 90.8 0.0 L 90.8 -546.9 ", 0.0)),
+    ('н', glyph!("M 90.8 -546.9 L 181.2 -546.9 L 181.2 -323.7 L 472.7 -323.7 L 472.7 -546.9 L 563.0 -546.9 L 563.0 0.0 L 472.7 0.0 L 472.7 -252.0 L 181.2 -252.0 L 181.2 0.0 L 90.8 0.0 L 90.8 -546.9 ", 0.0)),
+    ('о', glyph!("M 306.2 -483.9 L 261.3 -477.6 L 223.3 -458.8 L 191.9 -427.5 L 168.6 -385.3 L 154.6 -333.7 L 149.9 -272.9 L 154.5 -212.2 L 168.5 -160.6 L 191.7 -118.4 L 222.9 -87.1 L 261.1 -68.3 L 306.2 -62.0 L 350.7 -68.3 L 388.6 -87.2 L 419.9 -118.7 L 443.3 -161.0 L 457.2 -212.4 L 461.9 -272.9 L 457.2 -333.3 L 443.3 -384.6 L 419.9 -427.0 L 388.6 -458.6 L 350.7 -477.6 L 306.2 -483.9 M 306.2 -560.1 L 378.7 -551.6 L 440.0 -526.2 L 490.2 -483.9 L 527.4 -426.6 L 549.7 -356.3 L 557.1 -272.9 L 549.7 -189.9 L 527.4 -119.6 L 490.2 -62.3 L 440.0 -19.8 L 378.7 5.7 L 306.2 14.2 L 233.4 5.7 L 171.9 -19.8 L 121.8 -62.3 L 84.8 -119.6 L 62.6 -189.9 L 55.2 -272.9 L 62.6 -356.3 L 84.8 -426.6 L 121.8 -483.9 L 171.9 -526.2 L 233.4 -551.6 L 306.2 -560.1 ", 0.0)),
+    ('п', glyph!("M 563.0 -546.9 L 563.0 0.0 L 472.7 0.0 L 472.7 -475.1 L 181.2 -475.1 L 181.2 0.0 L 90.8 0.0 L 90.8 -546.9 L 563.0 -546.9 ", 0.0)),
+    ('р', glyph!("M 181.2 -82.0 L 181.2 208.0 L 90.8 208.0 L 90.8 -546.9 L 181.2 -546.9 L 181.2 -463.9 L 201.7 -493.6 L 225.5 -517.8 L 252.7 -536.4 L 283.4 -549.5 L 317.8 -557.4 L 356.0 -560.1 L 418.2 -551.3 L 472.2 -524.9 L 517.8 -481.0 L 552.4 -422.7 L 573.2 -353.4 L 580.1 -272.9 L 573.2 -192.5 L 552.4 -123.2 L 517.8 -64.9 L 472.2 -21.0 L 418.2 5.4 L 356.0 14.2 L 317.8 11.5 L 283.4 3.6 L 252.7 -9.5 L 225.5 -28.1 L 201.7 -52.3 L 181.2 -82.0 M 486.8 -272.9 L 482.3 -334.3 L 468.7 -386.1 L 446.0 -428.5 L 415.5 -459.8 L 378.1 -478.6 L 334.0 -484.9 L 289.8 -478.6 L 252.5 -459.8 L 221.9 -428.5 L 199.3 -386.1 L 185.7 -334.3 L 181.2 -272.9 L 185.7 -211.6 L 199.3 -159.8 L 221.9 -117.4 L 252.5 -86.1 L 289.8 -67.3 L 334.0 -61.0 L 378.1 -67.3 L 415.5 -86.1 L 446.0 -117.4 L 468.7 -159.8 L 482.3 -211.6 L 486.8 -272.9 ", 0.0)),
+    ('с', glyph!("M 487.8 -525.9 L 487.8 -441.9 L 462.4 -454.7 L 436.9 -465.2 L 411.4 -473.4 L 385.7 -479.2 L 359.9 -482.7 L 334.0 -483.9 L 280.1 -477.7 L 234.8 -459.3 L 198.2 -428.5 L 171.4 -386.6 L 155.3 -334.7 L 149.9 -272.9 L 155.3 -211.2 L 171.4 -159.3 L 198.2 -117.4 L 234.8 -86.6 L 280.1 -68.2 L 334.0 -62.0 L 359.9 -63.2 L 385.7 -66.7 L 411.4 -72.5 L 436.9 -80.7 L 462.4 -91.2 L 487.8 -104.0 L 487.8 -21.0 L 462.4 -10.3 L 436.5 -1.5 L 409.9 5.4 L 382.5 10.3 L 353.9 13.2 L 324.2 14.2 L 247.5 5.5 L 182.1 -20.3 L 127.9 -63.5 L 87.5 -121.3 L 63.3 -191.1 L 55.2 -272.9 L 63.3 -355.8 L 87.8 -425.9 L 128.7 -483.4 L 183.7 -526.0 L 250.8 -551.5 L 330.1 -560.1 L 357.5 -559.1 L 384.5 -556.3 L 411.1 -551.5 L 437.2 -544.9 L 462.8 -536.3 L 487.8 -525.9 ", 0.0)),
+    ('т', glyph!("M 29.3 -546.9 L 553.2 -546.9 L 553.2 -475.1 L 335.4 -475.1 L 335.4 0.0 L 247.1 0.0 L 247.1 -475.1 L 29.3 -475.1 L 29.3 -546.9 ", 0.0)),
+    ('у', glyph!("M 321.8 50.8 L 296.6 108.3 L 271.9 150.8 L 247.6 178.2 L 220.8 194.8 L 188.5 204.7 L 150.9 208.0 L 79.1 208.0 L 79.1 132.8 L 131.8 132.8 L 154.7 130.9 L 173.9 125.0 L 189.5 115.2 L 203.6 98.2 L 218.8 70.5 L 234.9 32.2 L 251.0 -8.8 L 29.8 -546.9 L 125.0 -546.9 L 295.9 -119.1 L 466.8 -546.9 L 562.0 -546.9 L 321.8 50.8 ", 0.0)),
+    ('ф', glyph!("M 147.9 -272.9 L 151.9 -203.8 L 163.7 -149.4 L 183.3 -109.6 L 209.7 -82.6 L 241.5 -66.4 L 278.8 -61.0 L 311.6 -70.1 L 346.1 -97.5 L 382.3 -143.1 L 382.3 -402.8 L 346.1 -448.4 L 311.6 -475.7 L 278.8 -484.9 L 241.5 -479.5 L 209.7 -463.3 L 183.3 -436.3 L 163.7 -396.5 L 151.9 -342.1 L 147.9 -272.9 M 382.3 208.0 L 382.3 -69.8 L 363.3 -42.2 L 343.4 -20.6 L 322.5 -4.9 L 300.2 5.7 L 276.1 12.0 L 250.0 14.2 L 198.4 5.8 L 152.2 -19.4 L 111.6 -61.3 L 80.0 -118.1 L 61.0 -188.0 L 54.7 -271.0 L 61.0 -354.2 L 80.0 -424.8 L 111.6 -482.7 L 152.2 -525.7 L 198.4 -551.5 L 250.0 -560.1 L 276.1 -558.0 L 300.2 -551.8 L 322.5 -541.5 L 343.4 -526.1 L 363.3 -504.6 L 382.3 -477.1 L 382.3 -729.0 L 472.7 -729.0 L 472.7 -477.1 L 491.7 -504.6 L 511.6 -526.1 L 532.5 -541.5 L 554.7 -551.8 L 578.9 -558.0 L 605.0 -560.1 L 656.6 -551.5 L 702.7 -525.7 L 743.4 -482.7 L 775.0 -424.8 L 794.0 -354.2 L 800.3 -271.0 L 794.0 -188.0 L 775.0 -118.1 L 743.4 -61.3 L 702.7 -19.4 L 656.6 5.8 L 605.0 14.2 L 578.9 12.0 L 554.7 5.7 L 532.5 -4.9 L 511.6 -20.6 L 491.7 -42.2 L 472.7 -69.8 L 472.7 208.0 L 382.3 208.0 M 707.0 -272.9 L 703.1 -342.1 L 691.3 -396.5 L 671.6 -436.3 L 645.3 -463.3 L 613.5 -479.5 L 576.2 -484.9 L 543.4 -475.7 L 508.9 -448.4 L 472.7 -402.8 L 472.7 -143.1 L 508.9 -97.5 L 543.4 -70.1 L 576.2 -61.0 L 613.5 -66.4 L 645.3 -82.6 L 671.6 -109.6 L 691.3 -149.4 L 703.1 -203.8 L 707.0 -272.9 ", 0.0)),
+    ('х', glyph!("M 548.8 -546.9 L 351.1 -280.8 L 559.1 0.0 L 453.1 0.0 L 293.9 -214.8 L 134.8 0.0 L 28.8 0.0 L 241.2 -286.1 L 46.9 -546.9 L 152.8 -546.9 L 297.9 -352.1 L 442.9 -546.9 L 548.8 -546.9 ", 0.0)),
+    ('ц', glyph!("M 563.0 0.0 L 90.8 0.0 L 90.8 -546.9 L 181.2 -546.9 L 181.2 -71.8 L 472.7 -71.8 L 472.7 -546.9 L 563.0 -546.9 L 563.0 -71.8 L 634.8 -71.8 L 634.8 138.2 L 563.0 138.2 L 563.0 0.0 ", 0.0)),
+    ('ч', glyph!("M 410.2 0.0 L 410.2 -230.0 L 242.7 -230.0 L 195.6 -235.1 L 154.1 -250.6 L 118.2 -276.4 L 93.2 -309.7 L 78.2 -353.6 L 73.2 -408.2 L 73.2 -546.9 L 163.1 -546.9 L 163.1 -416.5 L 165.9 -381.6 L 174.4 -352.9 L 188.5 -330.6 L 208.5 -314.6 L 234.0 -305.0 L 265.1 -301.8 L 410.2 -301.8 L 410.2 -546.9 L 500.0 -546.9 L 500.0 0.0 L 410.2 0.0 ", 0.0)),
+    ('ш', glyph!("M 502.4 -71.8 L 733.9 -71.8 L 733.9 -546.9 L 824.2 -546.9 L 824.2 0.0 L 90.8 0.0 L 90.8 -546.9 L 181.2 -546.9 L 181.2 -71.8 L 412.1 -71.8 L 412.1 -546.9 L 502.4 -546.9 L 502.4 -71.8 ", 0.0)),
+    ('щ', glyph!("M 824.2 0.0 L 90.8 0.0 L 90.8 -546.9 L 181.2 -546.9 L 181.2 -71.8 L 412.1 -71.8 L 412.1 -546.9 L 502.4 -546.9 L 502.4 -71.8 L 733.9 -71.8 L 733.9 -546.9 L 824.2 -546.9 L 824.2 -71.8 L 896.0 -71.8 L 896.0 138.2 L 824.2 138.2 L 824.2 0.0 ", 0.0)),
+    ('ъ', glyph!("M 430.2 -323.7 L 494.5 -319.3 L 548.1 -306.0 L 591.1 -283.9 L 622.4 -252.7 L 641.2 -212.1 L 647.5 -162.1 L 641.2 -112.1 L 622.4 -71.4 L 591.1 -40.0 L 548.1 -17.8 L 494.5 -4.4 L 430.2 0.0 L 207.5 0.0 L 207.5 -475.1 L 30.3 -475.1 L 30.3 -546.9 L 297.9 -546.9 L 297.9 -323.7 L 430.2 -323.7 M 426.3 -252.0 L 297.9 -252.0 L 297.9 -71.8 L 426.3 -71.8 L 464.0 -74.4 L 495.2 -82.1 L 519.8 -95.0 L 537.5 -112.7 L 548.2 -135.1 L 551.8 -162.1 L 548.2 -189.1 L 537.5 -211.4 L 519.8 -229.0 L 495.2 -241.8 L 464.0 -249.4 L 426.3 -252.0 ", 0.0)),
+    ('ы', glyph!("M 610.8 -546.9 L 700.7 -546.9 L 700.7 0.0 L 610.8 0.0 L 610.8 -546.9 M 655.8 -560.1 L 655.8 -560.1 M 434.6 -162.1 L 431.0 -189.1 L 420.4 -211.4 L 402.6 -229.0 L 378.0 -241.8 L 346.8 -249.4 L 309.1 -252.0 L 181.2 -252.0 L 181.2 -71.8 L 309.1 -71.8 L 346.8 -74.4 L 378.0 -82.1 L 402.6 -95.0 L 420.4 -112.7 L 431.0 -135.1 L 434.6 -162.1 M 90.8 -546.9 L 181.2 -546.9 L 181.2 -323.7 L 313.0 -323.7 L 377.3 -319.3 L 430.9 -306.0 L 473.9 -283.9 L 505.2 -252.7 L 524.0 -212.1 L 530.3 -162.1 L 524.0 -112.1 L 505.2 -71.4 L 473.9 -40.0 L 430.9 -17.8 L 377.3 -4.4 L 313.0 0.0 L 90.8 0.0 L 90.8 -546.9 ", 0.0)),
+    ('ь', glyph!("M 434.6 -162.1 L 431.0 -189.1 L 420.4 -211.4 L 402.6 -229.0 L 378.0 -241.8 L 346.8 -249.4 L 309.1 -252.0 L 181.2 -252.0 L 181.2 -71.8 L 309.1 -71.8 L 346.8 -74.4 L 378.0 -82.1 L 402.6 -95.0 L 420.4 -112.7 L 431.0 -135.1 L 434.6 -162.1 M 90.8 -546.9 L 181.2 -546.9 L 181.2 -323.7 L 313.0 -323.7 L 377.3 -319.3 L 430.9 -306.0 L 473.9 -283.9 L 505.2 -252.7 L 524.0 -212.1 L 530.3 -162.1 L 524.0 -112.1 L 505.2 -71.4 L 473.9 -40.0 L 430.9 -17.8 L 377.3 -4.4 L 313.0 0.0 L 90.8 0.0 L 90.8 -546.9 ", 0.0)),
+    ('э', glyph!("M 55.2 -104.0 L 106.6 -80.7 L 157.8 -66.7 L 209.0 -62.0 L 254.6 -66.7 L 295.6 -80.6 L 332.0 -103.8 L 361.5 -138.8 L 381.7 -188.2 L 392.6 -252.0 L 115.2 -252.0 L 115.2 -323.7 L 389.6 -323.7 L 382.1 -358.3 L 367.2 -393.5 L 345.0 -429.4 L 312.6 -459.7 L 267.3 -477.8 L 209.0 -483.9 L 158.5 -479.2 L 107.2 -465.2 L 55.2 -441.9 L 55.2 -525.9 L 106.8 -544.9 L 159.3 -556.3 L 212.9 -560.1 L 292.1 -551.5 L 359.3 -525.7 L 414.3 -482.7 L 455.1 -425.0 L 479.6 -355.1 L 487.8 -272.9 L 479.8 -191.1 L 456.0 -121.3 L 416.3 -63.5 L 362.6 -20.3 L 296.9 5.5 L 219.2 14.2 L 160.0 10.3 L 105.3 -1.5 L 55.2 -21.0 L 55.2 -104.0 ", 0.0)),
+    ('ю', glyph!("M 536.1 -483.9 L 491.3 -477.6 L 453.2 -458.8 L 421.9 -427.5 L 398.5 -385.3 L 384.5 -333.7 L 379.9 -272.9 L 384.5 -212.2 L 398.4 -160.6 L 421.6 -118.4 L 452.9 -87.1 L 491.1 -68.3 L 536.1 -62.0 L 580.7 -68.3 L 618.6 -87.2 L 649.9 -118.7 L 673.2 -161.0 L 687.2 -212.4 L 691.9 -272.9 L 687.2 -333.3 L 673.2 -384.6 L 649.9 -427.0 L 618.6 -458.6 L 580.7 -477.6 L 536.1 -483.9 M 288.1 -328.6 L 300.0 -387.9 L 323.4 -441.9 L 358.2 -490.7 L 404.9 -529.2 L 464.3 -552.4 L 536.1 -560.1 L 608.7 -551.6 L 670.0 -526.2 L 720.2 -483.9 L 757.4 -426.6 L 779.7 -356.3 L 787.1 -272.9 L 779.7 -189.9 L 757.4 -119.6 L 720.2 -62.3 L 670.0 -19.8 L 608.7 5.7 L 536.1 14.2 L 463.6 6.0 L 403.0 -18.6 L 354.2 -59.6 L 318.1 -114.0 L 295.2 -178.9 L 285.6 -254.4 L 184.1 -254.4 L 184.1 0.0 L 94.2 0.0 L 94.2 -546.9 L 184.1 -546.9 L 184.1 -328.6 L 288.1 -328.6 ", 0.0)),
+    ('я', glyph!("M 184.6 -389.2 L 188.0 -364.1 L 198.5 -342.9 L 215.8 -325.7 L 239.6 -312.9 L 269.4 -305.3 L 305.2 -302.7 L 426.3 -302.7 L 426.3 -475.1 L 305.2 -475.1 L 269.4 -472.6 L 239.6 -465.1 L 215.8 -452.6 L 198.5 -435.6 L 188.0 -414.4 L 184.6 -389.2 M 56.6 0.0 L 223.6 -239.7 L 188.0 -250.5 L 156.6 -265.9 L 129.4 -285.9 L 108.5 -312.2 L 96.0 -346.7 L 91.8 -389.2 L 97.6 -437.5 L 115.1 -476.9 L 144.3 -507.6 L 185.2 -529.4 L 238.0 -542.5 L 302.7 -546.9 L 516.6 -546.9 L 516.6 0.0 L 426.3 0.0 L 426.3 -231.0 L 314.5 -231.0 L 153.3 0.0 L 56.6 0.0 ", 0.0)),
+    ('ё', glyph!("M 562.0 -295.9 L 562.0 -252.0 L 148.9 -252.0 L 157.7 -195.0 L 176.4 -147.9 L 204.8 -110.6 L 242.6 -83.6 L 289.0 -67.4 L 344.2 -62.0 L 378.4 -63.4 L 411.8 -67.7 L 444.6 -74.7 L 476.9 -84.6 L 509.0 -97.3 L 541.0 -112.8 L 541.0 -27.8 L 508.5 -15.2 L 475.5 -4.8 L 441.9 3.4 L 407.9 9.4 L 373.5 13.0 L 338.9 14.2 L 257.7 5.7 L 188.6 -19.7 L 131.6 -62.0 L 89.1 -118.8 L 63.7 -187.4 L 55.2 -268.1 L 63.2 -351.4 L 87.4 -422.5 L 127.7 -481.2 L 181.6 -525.0 L 246.8 -551.3 L 323.2 -560.1 L 391.7 -552.2 L 449.9 -528.5 L 497.8 -489.0 L 533.5 -436.0 L 554.9 -371.6 L 562.0 -295.9 M 472.2 -322.3 L 467.1 -368.1 L 453.4 -407.3 L 430.9 -439.9 L 401.2 -464.4 L 365.6 -479.0 L 324.2 -483.9 L 277.4 -479.2 L 237.3 -465.0 L 203.9 -441.4 L 178.0 -409.2 L 160.7 -369.4 L 151.9 -321.8 L 472.2 -322.3 M 369.1 -757.8 L 468.3 -757.8 L 468.3 -659.2 L 369.1 -659.2 L 369.1 -757.8 M 178.2 -757.8 L 277.3 -757.8 L 277.3 -659.2 L 178.2 -659.2 L 178.2 -757.8 ", 0.0)),
+];
@@ -0,0 +1,105 @@
+use crate::model::Glyph;
+
+/// Icon tables sorted by name, simple line drawings of shapes, arrows, nature,
+/// objects and animals in a 100 x 100 box that are stroked like the glyphs.
+pub static ICON_GLYPHS: &[(&str, Glyph)] = &[
+    ("anchor", glyph!("M 50 6 C 54.4 6 58 9.6 58 14 C 58 18.4 54.4 22 50 22 C 45.6 22 42 18.4 42 14 C 42 9.6 45.6 6 50 6 M 50 22 L 50 92 M 30 36 L 70 36 M 8 58 C 12 80 30 92 50 92 C 70 92 88 80 92 58 M 8 58 L 18 66 M 92 58 L 82 66 ")),
+    ("arrow", glyph!("M 4 50 L 92 50 M 60 18 L 92 50 L 60 82 ")),
+    ("bell", glyph!("M 20 76 C 26 66 26 56 26 44 C 26 28 36 16 50 16 C 64 16 74 28 74 44 C 74 56 74 66 80 76 L 20 76 M 42 82 C 44 92 56 92 58 82 M 50 16 L 50 6 ")),
+    ("bird", glyph!("M 8 56 C 20 40 40 38 50 48 C 60 30 80 28 92 36 L 80 42 C 78 62 60 74 40 72 C 24 70 14 64 8 56 M 40 72 L 36 90 M 56 70 L 60 90 M 78 36 L 80 38 ")),
+    ("boat", glyph!("M 6 64 L 94 64 L 80 86 L 20 86 L 6 64 M 50 64 L 50 6 L 84 52 L 50 52 ")),
+    ("bolt", glyph!("M 58 4 L 18 56 L 46 56 L 38 96 L 82 40 L 54 40 L 58 4 ")),
+    ("car", glyph!("M 18 72 L 6 72 L 6 54 L 20 48 L 32 30 L 68 30 L 80 48 L 94 54 L 94 72 L 82 72 M 62 72 L 38 72 M 20 48 L 80 48 M 28 62 C 33.5 62 38 66.5 38 72 C 38 77.5 33.5 82 28 82 C 22.5 82 18 77.5 18 72 C 18 66.5 22.5 62 28 62 M 72 62 C 77.5 62 82 66.5 82 72 C 82 77.5 77.5 82 72 82 C 66.5 82 62 77.5 62 72 C 62 66.5 66.5 62 72 62 ")),
+    ("cat", glyph!("M 20 40 L 16 8 L 38 28 C 46 26 54 26 62 28 L 84 8 L 80 40 C 88 62 74 88 50 88 C 26 88 12 62 20 40 M 38 52 L 40 54 M 62 52 L 60 54 M 46 66 L 50 70 L 54 66 M 30 66 L 8 62 M 70 66 L 92 62 ")),
+    ("chevrons", glyph!("M 20 10 L 60 50 L 20 90 M 44 10 L 84 50 L 44 90 ")),
+    ("circle", glyph!("M 50 5 C 74.9 5 95 25.1 95 50 C 95 74.9 74.9 95 50 95 C 25.1 95 5 74.9 5 50 C 5 25.1 25.1 5 50 5 ")),
+    ("clock", glyph!("M 50 5 C 74.9 5 95 25.1 95 50 C 95 74.9 74.9 95 50 95 C 25.1 95 5 74.9 5 50 C 5 25.1 25.1 5 50 5 M 50 22 L 50 50 L 70 62 ")),
+    ("cloud", glyph!("M 24 78 C 10 78 4 66 8 56 C 12 46 22 44 28 46 C 30 30 44 20 58 24 C 70 26 76 36 76 44 C 88 42 96 52 94 62 C 92 72 84 78 76 78 L 24 78 ")),
+    ("cross", glyph!("M 36 6 L 64 6 L 64 36 L 94 36 L 94 64 L 64 64 L 64 94 L 36 94 L 36 64 L 6 64 L 6 36 L 36 36 L 36 6 ")),
+    ("cup", glyph!("M 14 20 L 70 20 L 66 80 C 66 86 62 90 56 90 L 28 90 C 22 90 18 86 18 80 L 14 20 M 70 32 C 88 32 92 40 92 48 C 92 58 84 64 68 64 ")),
+    ("curved_arrow", glyph!("M 10 88 C 10 44 40 22 80 24 M 62 6 L 80 24 L 62 42 ")),
+    ("diamond", glyph!("M 50 4 L 86 50 L 50 96 L 14 50 L 50 4 ")),
+    ("double_arrow", glyph!("M 4 50 L 96 50 M 26 26 L 4 50 L 26 74 M 74 26 L 96 50 L 74 74 ")),
+    ("drop", glyph!("M 50 4 C 40 24 18 44 18 64 C 18 82 32 96 50 96 C 68 96 82 82 82 64 C 82 44 60 24 50 4 ")),
+    ("envelope", glyph!("M 6 20 L 94 20 L 94 80 L 6 80 L 6 20 L 50 56 L 94 20 ")),
+    ("fish", glyph!("M 8 50 C 28 22 62 22 78 50 C 62 78 28 78 8 50 M 78 50 L 96 32 L 96 68 L 78 50 M 28 44 L 30 46 ")),
+    ("flower", glyph!("M 50 6 C 56.6 6 62 11.4 62 18 C 62 24.6 56.6 30 50 30 C 43.4 30 38 24.6 38 18 C 38 11.4 43.4 6 50 6 M 70.9 21.2 C 77.6 21.2 82.9 26.6 82.9 33.2 C 82.9 39.8 77.6 45.2 70.9 45.2 C 64.3 45.2 58.9 39.8 58.9 33.2 C 58.9 26.6 64.3 21.2 70.9 21.2 M 62.9 45.8 C 69.6 45.8 74.9 51.2 74.9 57.8 C 74.9 64.4 69.6 69.8 62.9 69.8 C 56.3 69.8 50.9 64.4 50.9 57.8 C 50.9 51.2 56.3 45.8 62.9 45.8 M 37.1 45.8 C 43.7 45.8 49.1 51.2 49.1 57.8 C 49.1 64.4 43.7 69.8 37.1 69.8 C 30.4 69.8 25.1 64.4 25.1 57.8 C 25.1 51.2 30.4 45.8 37.1 45.8 M 29.1 21.2 C 35.7 21.2 41.1 26.6 41.1 33.2 C 41.1 39.8 35.7 45.2 29.1 45.2 C 22.4 45.2 17.1 39.8 17.1 33.2 C 17.1 26.6 22.4 21.2 29.1 21.2 M 50 32 C 54.4 32 58 35.6 58 40 C 58 44.4 54.4 48 50 48 C 45.6 48 42 44.4 42 40 C 42 35.6 45.6 32 50 32 M 50 70 L 50 98 M 50 86 L 66 76 ")),
+    ("heart", glyph!("M 50 90 L 12 50 C 0 36 4 14 22 10 C 36 7 46 16 50 28 C 54 16 64 7 78 10 C 96 14 100 36 88 50 L 50 90 ")),
+    ("hexagon", glyph!("M 50 4 L 89.8 27 L 89.8 73 L 50 96 L 10.2 73 L 10.2 27 L 50 4 ")),
+    ("house", glyph!("M 8 46 L 50 10 L 92 46 M 20 36 L 20 90 L 80 90 L 80 36 M 40 90 L 40 62 L 60 62 L 60 90 ")),
+    ("key", glyph!("M 24 34 C 32.8 34 40 41.2 40 50 C 40 58.8 32.8 66 24 66 C 15.2 66 8 58.8 8 50 C 8 41.2 15.2 34 24 34 M 40 50 L 94 50 L 94 64 M 80 50 L 80 62 ")),
+    ("leaf", glyph!("M 10 90 C 10 40 40 10 90 10 C 90 60 60 90 10 90 M 10 90 L 60 40 ")),
+    ("lock", glyph!("M 16 44 L 84 44 L 84 94 L 16 94 L 16 44 M 28 44 L 28 30 C 28 16 38 6 50 6 C 62 6 72 16 72 30 L 72 44 M 50 62 L 50 76 ")),
+    ("moon", glyph!(
+            "M 64 6 C 18 10 6 72 50 92 C 64 98 80 94 90 86 C 52 88 36 56 46 30 C 50 20 56 12 64 6 "
+        )),
+    ("note", glyph!("M 40 80 L 40 12 L 84 4 L 84 70 M 40 24 L 84 16 M 28 68 C 34.6 68 40 73.4 40 80 C 40 86.6 34.6 92 28 92 C 21.4 92 16 86.6 16 80 C 16 73.4 21.4 68 28 68 M 72 58 C 78.6 58 84 63.4 84 70 C 84 76.6 78.6 82 72 82 C 65.4 82 60 76.6 60 70 C 60 63.4 65.4 58 72 58 ")),
+    ("snail", glyph!("M 58 28 C 73.5 28 86 40.5 86 56 C 86 71.5 73.5 84 58 84 C 42.5 84 30 71.5 30 56 C 30 40.5 42.5 28 58 28 M 58 44 C 64.6 44 70 49.4 70 56 C 70 62.6 64.6 68 58 68 C 51.4 68 46 62.6 46 56 C 46 49.4 51.4 44 58 44 M 4 88 L 92 88 C 98 88 98 82 90 80 M 6 88 C 4 74 10 66 22 64 L 16 40 M 22 64 L 28 42 ")),
+    ("square", glyph!("M 10 10 L 90 10 L 90 90 L 10 90 L 10 10 ")),
+    ("star", glyph!("M 50.0 4.0 L 61.2 36.6 L 95.7 37.2 L 68.1 57.9 L 78.2 90.8 L 50.0 71.0 L 21.8 90.8 L 31.9 57.9 L 4.3 37.2 L 38.8 36.6 L 50.0 4.0 ")),
+    ("sun", glyph!("M 50 30 C 61 30 70 39 70 50 C 70 61 61 70 50 70 C 39 70 30 61 30 50 C 30 39 39 30 50 30 M 80 50 L 96 50 M 71.2 71.2 L 82.5 82.5 M 50 80 L 50 96 M 28.8 71.2 L 17.5 82.5 M 20 50 L 4 50 M 28.8 28.8 L 17.5 17.5 M 50 20 L 50 4 M 71.2 28.8 L 82.5 17.5 ")),
+    ("tree", glyph!("M 50 6 L 20 48 L 36 48 L 14 78 L 86 78 L 64 48 L 80 48 L 50 6 M 50 78 L 50 96 ")),
+    ("triangle", glyph!("M 50 8 L 94 88 L 6 88 L 50 8 ")),
+    ("umbrella", glyph!("M 4 50 C 8 24 28 8 50 8 C 72 8 92 24 96 50 C 88 44 78 44 72 50 C 64 44 56 44 50 50 C 44 44 36 44 28 50 C 22 44 12 44 4 50 M 50 50 L 50 86 C 50 94 38 96 34 86 ")),
+];
+
+/// names of the icons in a fixed order, so seeded captchas are reproducible
+pub static ICON_TABLE: &[&str] = &[
+    "arrow",
+    "star",
+    "heart",
+    "house",
+    "tree",
+    "fish",
+    "cat",
+    "bird",
+    "circle",
+    "square",
+    "triangle",
+    "diamond",
+    "hexagon",
+    "cross",
+    "moon",
+    "double_arrow",
+    "curved_arrow",
+    "chevrons",
+    "sun",
+    "cloud",
+    "bolt",
+    "drop",
+    "leaf",
+    "flower",
+    "key",
+    "cup",
+    "umbrella",
+    "bell",
+    "clock",
+    "envelope",
+    "car",
+    "boat",
+    "lock",
+    "anchor",
+    "note",
+    "snail",
+];
+
+/// pairs of icons that look alike once rotated and skewed, a square turned
+/// by 45 degrees is a diamond and the arrows differ in details. a grid never
+/// fills its other cells with an icon that is confusable with the target.
+pub static CONFUSABLE_ICONS: &[(&str, &str)] = &[
+    ("square", "diamond"),
+    ("circle", "hexagon"),
+    ("arrow", "double_arrow"),
+    ("arrow", "curved_arrow"),
+    ("arrow", "chevrons"),
+    ("double_arrow", "curved_arrow"),
+    ("double_arrow", "chevrons"),
+    ("curved_arrow", "chevrons"),
+];
+
+/// whether two icons are a pair of `CONFUSABLE_ICONS`
+pub fn confusable_icons(a: &str, b: &str) -> bool {
+    CONFUSABLE_ICONS
+        .iter()
+        .any(|pair| *pair == (a, b) || *pair == (b, a))
+}
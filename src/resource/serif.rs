@@ -0,0 +1,71 @@
+use crate::model::Glyph;
+
+/// Serif font tables sorted by character, converted from the outlines of
+/// DejaVu Serif.
+///
+/// Copyright (c) 2003 by Bitstream, Inc. All Rights Reserved. Bitstream Vera
+/// is a trademark of Bitstream, Inc. DejaVu changes are in public domain.
+/// See https://dejavu-fonts.github.io/License.html for the license terms.
+pub static SERIF_GLYPHS: &[(char, Glyph)] = &[
+    ('+', glyph!("M 458.0 -627.0 L 458.0 -353.0 L 731.9 -353.0 L 731.9 -273.9 L 458.0 -273.9 L 458.0 0.0 L 379.9 0.0 L 379.9 -273.9 L 106.0 -273.9 L 106.0 -353.0 L 379.9 -353.0 L 379.9 -627.0 L 458.0 -627.0 ", 0.0)),
+    ('-', glyph!(
+            "M 106.0 -353.0 L 731.9 -353.0 L 731.9 -273.9 L 106.0 -273.9 L 106.0 -353.0 ",
+            0.0
+        )),
+    ('2', glyph!("M 127.9 -555.2 L 73.2 -555.2 L 73.2 -685.1 L 108.2 -702.1 L 143.2 -716.3 L 178.5 -727.5 L 213.5 -735.7 L 248.1 -740.6 L 282.2 -742.2 L 352.8 -736.1 L 412.7 -717.7 L 461.9 -687.0 L 498.5 -645.8 L 520.5 -595.9 L 527.8 -537.1 L 511.2 -461.2 L 461.4 -375.9 L 378.4 -281.3 L 371.3 -274.4 L 365.4 -268.7 L 360.8 -264.2 L 176.8 -80.1 L 481.0 -80.1 L 481.0 -169.9 L 538.1 -169.9 L 538.1 0.0 L 67.9 0.0 L 67.9 -53.2 L 289.1 -273.9 L 333.2 -321.4 L 368.1 -366.3 L 393.6 -408.4 L 410.9 -450.0 L 421.3 -492.9 L 424.8 -537.1 L 420.5 -582.7 L 407.8 -621.1 L 386.5 -652.3 L 357.7 -675.4 L 322.5 -689.2 L 280.8 -693.8 L 237.7 -690.0 L 201.8 -678.4 L 172.9 -659.2 L 150.9 -632.2 L 136.0 -597.5 L 127.9 -555.2 ", 0.0)),
+    ('3', glyph!("M 97.2 -698.2 L 134.7 -711.4 L 171.3 -722.3 L 206.8 -731.0 L 241.0 -737.2 L 273.8 -740.9 L 305.2 -742.2 L 371.3 -737.1 L 426.9 -721.7 L 472.2 -696.0 L 505.5 -661.3 L 525.6 -618.6 L 532.2 -567.9 L 527.6 -526.3 L 513.8 -489.6 L 490.7 -457.8 L 459.2 -431.5 L 420.0 -411.5 L 373.0 -397.9 L 428.5 -385.1 L 474.9 -363.3 L 512.5 -332.8 L 540.0 -294.3 L 556.5 -249.0 L 562.0 -196.8 L 554.4 -134.0 L 531.5 -81.9 L 493.4 -40.3 L 441.0 -10.0 L 375.1 8.1 L 295.9 14.2 L 258.1 12.8 L 220.9 8.7 L 184.3 2.0 L 148.1 -7.6 L 112.1 -20.0 L 76.2 -35.2 L 76.2 -176.8 L 130.9 -176.8 L 138.3 -133.7 L 154.1 -98.2 L 178.2 -70.3 L 210.4 -50.2 L 250.3 -38.2 L 297.9 -34.2 L 344.2 -39.0 L 383.5 -53.4 L 415.8 -77.4 L 439.8 -109.8 L 454.2 -149.2 L 459.0 -195.8 L 454.0 -248.5 L 439.2 -291.8 L 414.6 -325.9 L 380.3 -350.5 L 336.9 -365.2 L 284.2 -370.1 L 237.8 -370.1 L 237.8 -419.9 L 262.2 -419.9 L 314.4 -423.9 L 357.2 -435.7 L 390.4 -455.3 L 414.1 -482.9 L 428.4 -518.5 L 433.1 -562.0 L 429.2 -601.3 L 417.5 -634.0 L 397.9 -659.9 L 371.1 -678.8 L 337.4 -690.1 L 296.9 -693.8 L 256.2 -690.4 L 222.1 -680.0 L 194.6 -662.6 L 173.7 -638.4 L 159.4 -607.5 L 151.9 -569.8 L 97.2 -569.8 L 97.2 -698.2 ", 0.0)),
+    ('4', glyph!("M 349.1 -247.1 L 349.1 -634.8 L 100.1 -247.1 L 349.1 -247.1 M 564.0 0.0 L 231.9 0.0 L 231.9 -51.8 L 349.1 -51.8 L 349.1 -194.8 L 30.8 -194.8 L 30.8 -248.0 L 350.1 -742.2 L 446.8 -742.2 L 446.8 -247.1 L 585.9 -247.1 L 585.9 -194.8 L 446.8 -194.8 L 446.8 -51.8 L 564.0 -51.8 L 564.0 0.0 ", 0.0)),
+    ('5', glyph!("M 502.9 -729.0 L 502.9 -648.9 L 168.9 -648.9 L 168.9 -439.9 L 186.8 -450.7 L 206.6 -459.5 L 228.3 -466.3 L 251.8 -471.2 L 277.1 -474.1 L 304.2 -475.1 L 377.4 -467.8 L 439.6 -446.0 L 490.7 -409.7 L 528.7 -360.7 L 551.5 -301.2 L 559.1 -231.0 L 551.4 -159.5 L 528.4 -99.3 L 490.0 -50.5 L 437.7 -14.6 L 373.0 7.0 L 295.9 14.2 L 262.1 12.8 L 227.8 8.7 L 192.9 2.0 L 157.4 -7.6 L 121.5 -20.0 L 85.0 -35.2 L 85.0 -176.8 L 140.1 -176.8 L 147.0 -134.2 L 161.7 -98.8 L 184.3 -70.8 L 214.4 -50.5 L 251.6 -38.2 L 295.9 -34.2 L 343.4 -39.8 L 382.9 -56.7 L 414.6 -85.0 L 437.6 -123.8 L 451.4 -172.4 L 456.1 -231.0 L 451.5 -289.2 L 437.7 -337.6 L 414.8 -376.2 L 383.2 -404.3 L 343.6 -421.1 L 295.9 -426.8 L 267.8 -425.0 L 242.0 -419.8 L 218.5 -411.1 L 197.0 -398.8 L 177.3 -382.7 L 159.2 -362.8 L 117.2 -362.8 L 117.2 -729.0 L 502.9 -729.0 ", 0.0)),
+    ('6', glyph!("M 327.1 -34.2 L 369.5 -39.8 L 404.8 -56.6 L 433.1 -84.7 L 453.7 -123.3 L 466.1 -171.7 L 470.2 -230.0 L 466.1 -288.2 L 453.7 -336.6 L 433.1 -375.2 L 404.8 -403.3 L 369.5 -420.2 L 327.1 -425.8 L 284.3 -420.4 L 248.8 -404.1 L 220.7 -377.0 L 200.4 -339.6 L 188.2 -292.5 L 184.1 -235.8 L 188.2 -176.1 L 200.6 -126.2 L 221.2 -86.4 L 249.5 -57.4 L 284.8 -40.0 L 327.1 -34.2 M 168.0 -401.4 L 191.1 -423.7 L 216.1 -441.9 L 243.2 -456.1 L 272.4 -466.1 L 303.9 -472.1 L 337.9 -474.1 L 405.4 -466.9 L 462.8 -445.0 L 510.0 -408.7 L 545.1 -359.8 L 566.2 -300.2 L 573.2 -230.0 L 565.7 -161.0 L 543.0 -101.8 L 505.1 -52.5 L 454.6 -15.5 L 394.0 6.8 L 323.2 14.2 L 247.2 3.9 L 183.9 -26.7 L 133.3 -77.9 L 96.4 -148.0 L 74.3 -235.6 L 66.9 -340.8 L 75.6 -458.8 L 101.8 -557.6 L 145.5 -637.2 L 205.1 -695.5 L 279.0 -730.5 L 367.2 -742.2 L 393.3 -741.4 L 420.4 -738.9 L 448.2 -734.9 L 476.8 -729.2 L 505.8 -721.8 L 535.2 -712.9 L 535.2 -592.8 L 480.0 -592.8 L 473.2 -623.0 L 460.7 -648.1 L 442.4 -668.0 L 418.8 -682.3 L 390.3 -691.0 L 356.9 -693.8 L 299.2 -685.9 L 252.1 -661.9 L 215.8 -622.1 L 189.9 -565.6 L 173.9 -492.1 L 168.0 -401.4 ", 0.0)),
+    ('7', glyph!("M 564.0 -679.2 L 278.8 0.0 L 206.1 0.0 L 478.0 -648.9 L 141.1 -648.9 L 141.1 -559.1 L 84.0 -559.1 L 84.0 -729.0 L 564.0 -729.0 L 564.0 -679.2 ", 0.0)),
+    ('8', glyph!("M 465.8 -199.2 L 461.5 -247.4 L 448.6 -287.9 L 427.0 -320.6 L 397.6 -344.6 L 361.2 -359.0 L 317.9 -363.8 L 274.5 -359.0 L 238.1 -344.6 L 208.7 -320.6 L 187.2 -287.9 L 174.2 -247.4 L 169.9 -199.2 L 174.2 -150.7 L 187.2 -110.1 L 208.7 -77.4 L 238.1 -53.4 L 274.5 -39.0 L 317.9 -34.2 L 361.2 -39.0 L 397.6 -53.4 L 427.0 -77.4 L 448.6 -110.1 L 461.5 -150.7 L 465.8 -199.2 M 445.8 -553.2 L 442.1 -594.2 L 430.8 -628.7 L 412.1 -656.7 L 386.7 -677.4 L 355.3 -689.7 L 317.9 -693.8 L 280.7 -689.7 L 249.4 -677.4 L 223.9 -656.7 L 205.0 -628.7 L 193.7 -594.2 L 189.9 -553.2 L 193.7 -511.9 L 205.0 -477.3 L 223.9 -449.2 L 249.4 -428.6 L 280.7 -416.2 L 317.9 -412.1 L 355.3 -416.2 L 386.7 -428.6 L 412.1 -449.2 L 430.8 -477.3 L 442.1 -511.9 L 445.8 -553.2 M 393.1 -388.2 L 444.3 -376.4 L 487.5 -356.0 L 522.5 -326.9 L 548.2 -290.5 L 563.7 -247.9 L 568.8 -199.2 L 561.6 -136.1 L 540.0 -83.4 L 503.9 -41.3 L 454.4 -10.5 L 392.4 8.0 L 317.9 14.2 L 243.6 8.0 L 181.7 -10.5 L 132.1 -41.3 L 95.9 -83.4 L 74.1 -136.1 L 66.9 -199.2 L 72.0 -247.9 L 87.5 -290.5 L 113.3 -326.9 L 148.3 -356.0 L 191.6 -376.4 L 243.2 -388.2 L 197.8 -400.3 L 160.2 -418.9 L 130.1 -444.1 L 108.3 -475.2 L 95.2 -511.6 L 90.8 -553.2 L 97.5 -607.7 L 117.7 -653.8 L 151.4 -691.7 L 196.8 -719.7 L 252.3 -736.6 L 317.9 -742.2 L 383.5 -736.6 L 439.0 -719.7 L 484.4 -691.7 L 518.0 -653.8 L 538.2 -607.7 L 544.9 -553.2 L 540.6 -511.6 L 527.5 -475.2 L 505.6 -444.1 L 475.6 -418.9 L 438.1 -400.3 L 393.1 -388.2 ", 0.0)),
+    ('9', glyph!("M 467.8 -326.7 L 444.9 -304.3 L 419.8 -286.1 L 392.6 -272.0 L 363.1 -261.9 L 331.2 -255.9 L 296.9 -253.9 L 229.7 -261.2 L 172.6 -283.0 L 125.7 -319.3 L 90.9 -368.3 L 70.0 -427.8 L 63.0 -498.0 L 70.6 -567.0 L 93.3 -626.2 L 131.1 -675.5 L 181.6 -712.6 L 242.2 -734.8 L 313.0 -742.2 L 389.0 -732.0 L 452.2 -701.3 L 502.7 -650.1 L 539.4 -580.0 L 561.5 -492.4 L 568.8 -387.2 L 560.1 -269.2 L 533.9 -170.4 L 490.2 -90.8 L 430.7 -32.5 L 357.0 2.5 L 269.0 14.2 L 242.9 13.3 L 215.9 10.9 L 188.0 6.8 L 159.5 1.1 L 130.5 -6.2 L 101.1 -15.1 L 101.1 -136.2 L 155.8 -136.2 L 162.8 -105.9 L 175.5 -80.7 L 193.8 -60.5 L 217.4 -45.9 L 245.7 -37.1 L 278.8 -34.2 L 336.6 -42.1 L 383.6 -66.0 L 419.9 -105.7 L 445.9 -162.1 L 461.8 -235.7 L 467.8 -326.7 M 309.1 -693.8 L 266.5 -688.2 L 231.1 -671.4 L 202.9 -643.3 L 182.4 -604.7 L 170.1 -556.3 L 166.0 -498.0 L 170.1 -439.8 L 182.4 -391.3 L 202.9 -352.5 L 231.1 -324.3 L 266.5 -307.4 L 309.1 -301.8 L 351.7 -307.2 L 387.1 -323.6 L 415.3 -350.8 L 435.8 -388.3 L 448.1 -435.5 L 452.1 -492.2 L 448.0 -552.0 L 435.7 -601.8 L 415.0 -641.6 L 386.8 -670.6 L 351.5 -688.0 L 309.1 -693.8 ", 0.0)),
+    ('A', glyph!("M 200.2 -264.2 L 467.8 -264.2 L 334.0 -610.8 L 200.2 -264.2 M -5.9 0.0 L -5.9 -51.8 L 58.1 -51.8 L 317.9 -729.0 L 399.9 -729.0 L 660.2 -51.8 L 731.9 -51.8 L 731.9 0.0 L 466.8 0.0 L 466.8 -51.8 L 547.9 -51.8 L 486.8 -211.9 L 180.2 -211.9 L 119.1 -51.8 L 199.2 -51.8 L 199.2 0.0 L -5.9 0.0 ", 0.0)),
+    ('B', glyph!("M 247.1 -51.8 L 393.1 -51.8 L 446.4 -56.0 L 489.2 -68.7 L 521.5 -89.8 L 544.0 -120.2 L 557.5 -160.6 L 562.0 -210.9 L 557.5 -261.0 L 544.1 -301.1 L 521.7 -331.3 L 489.5 -352.3 L 446.6 -364.9 L 393.1 -369.1 L 247.1 -369.1 L 247.1 -51.8 M 247.1 -420.9 L 371.1 -420.9 L 419.7 -424.3 L 458.7 -434.6 L 488.0 -451.7 L 508.5 -476.1 L 520.8 -508.5 L 524.9 -548.8 L 520.8 -589.4 L 508.5 -621.9 L 488.0 -646.2 L 458.7 -663.2 L 419.7 -673.4 L 371.1 -676.8 L 247.1 -676.8 L 247.1 -420.9 M 55.2 0.0 L 55.2 -51.8 L 147.9 -51.8 L 147.9 -676.8 L 55.2 -676.8 L 55.2 -729.0 L 415.0 -729.0 L 482.6 -724.0 L 538.0 -708.9 L 581.3 -683.8 L 612.4 -648.8 L 631.0 -603.8 L 637.2 -548.8 L 632.9 -508.5 L 620.0 -474.0 L 598.4 -445.3 L 568.5 -422.9 L 530.7 -407.1 L 484.9 -397.9 L 541.7 -386.1 L 588.7 -366.4 L 625.7 -338.6 L 652.5 -303.3 L 668.5 -260.7 L 673.8 -210.9 L 666.2 -145.0 L 643.4 -91.8 L 605.5 -51.3 L 551.4 -22.8 L 480.3 -5.7 L 392.1 0.0 L 55.2 0.0 ", 0.0)),
+    ('C', glyph!("M 705.1 -192.9 L 678.7 -130.3 L 642.7 -78.8 L 596.9 -38.3 L 541.8 -9.2 L 477.8 8.3 L 404.8 14.2 L 358.4 11.4 L 314.3 3.3 L 272.5 -10.3 L 233.3 -29.1 L 197.2 -53.0 L 164.1 -82.0 L 130.7 -120.1 L 103.6 -161.6 L 82.8 -206.3 L 68.0 -254.7 L 59.1 -307.2 L 56.2 -363.8 L 67.1 -469.8 L 99.8 -561.1 L 154.3 -637.9 L 226.7 -695.9 L 312.9 -730.6 L 413.1 -742.2 L 453.7 -740.5 L 496.0 -735.2 L 540.0 -726.6 L 586.1 -714.4 L 634.4 -698.6 L 685.1 -679.2 L 685.1 -511.2 L 629.9 -511.2 L 613.8 -567.1 L 589.6 -612.1 L 557.4 -646.5 L 516.2 -670.6 L 465.4 -685.1 L 404.8 -689.9 L 333.3 -680.7 L 274.4 -653.2 L 228.0 -607.2 L 194.7 -543.3 L 174.6 -462.2 L 168.0 -363.8 L 174.6 -265.6 L 194.7 -184.6 L 228.0 -120.8 L 274.4 -74.9 L 333.3 -47.3 L 404.8 -38.1 L 455.9 -42.4 L 500.7 -55.3 L 539.1 -76.9 L 571.0 -107.0 L 596.4 -145.7 L 615.2 -192.9 L 705.1 -192.9 ", 0.0)),
+    ('D', glyph!("M 247.1 -51.8 L 337.9 -51.8 L 425.3 -60.8 L 497.9 -87.8 L 555.9 -132.8 L 598.1 -194.7 L 623.4 -272.1 L 631.8 -365.2 L 623.4 -458.2 L 598.2 -535.4 L 556.2 -596.7 L 498.3 -641.2 L 425.5 -667.9 L 337.9 -676.8 L 247.1 -676.8 L 247.1 -51.8 M 55.2 0.0 L 55.2 -51.8 L 147.9 -51.8 L 147.9 -676.8 L 55.2 -676.8 L 55.2 -729.0 L 345.2 -729.0 L 461.6 -718.3 L 559.5 -686.3 L 638.9 -632.8 L 697.4 -560.3 L 732.4 -471.1 L 744.1 -365.2 L 732.4 -259.0 L 697.3 -169.5 L 638.7 -96.7 L 559.2 -43.0 L 461.4 -10.7 L 345.2 0.0 L 55.2 0.0 ", 0.0)),
+    ('E', glyph!("M 55.2 0.0 L 55.2 -51.8 L 147.9 -51.8 L 147.9 -676.8 L 55.2 -676.8 L 55.2 -729.0 L 642.1 -729.0 L 642.1 -566.9 L 582.0 -566.9 L 582.0 -668.9 L 247.1 -668.9 L 247.1 -424.8 L 485.8 -424.8 L 485.8 -516.1 L 545.9 -516.1 L 545.9 -273.9 L 485.8 -273.9 L 485.8 -365.2 L 247.1 -365.2 L 247.1 -60.1 L 589.8 -60.1 L 589.8 -162.1 L 649.9 -162.1 L 649.9 0.0 L 55.2 0.0 ", 0.0)),
+    ('F', glyph!("M 55.2 0.0 L 55.2 -51.8 L 147.9 -51.8 L 147.9 -676.8 L 55.2 -676.8 L 55.2 -729.0 L 651.9 -729.0 L 651.9 -566.9 L 591.8 -566.9 L 591.8 -668.9 L 247.1 -668.9 L 247.1 -424.8 L 496.1 -424.8 L 496.1 -516.1 L 556.2 -516.1 L 556.2 -273.9 L 496.1 -273.9 L 496.1 -365.2 L 247.1 -365.2 L 247.1 -51.8 L 363.8 -51.8 L 363.8 0.0 L 55.2 0.0 ", 0.0)),
+    ('G', glyph!("M 640.1 -511.2 L 624.6 -566.8 L 600.9 -611.8 L 568.8 -646.2 L 528.0 -670.5 L 477.7 -685.1 L 418.0 -689.9 L 341.1 -680.9 L 278.4 -653.9 L 230.0 -608.9 L 195.5 -545.6 L 174.9 -463.9 L 168.0 -363.8 L 175.1 -265.4 L 196.4 -184.3 L 231.9 -120.6 L 281.3 -74.8 L 343.9 -47.3 L 419.9 -38.1 L 456.2 -39.6 L 491.3 -44.2 L 525.4 -51.8 L 558.4 -62.4 L 590.3 -76.1 L 621.1 -92.8 L 621.1 -280.8 L 483.9 -280.8 L 483.9 -333.0 L 720.2 -333.0 L 720.2 -61.0 L 675.8 -38.1 L 629.2 -19.3 L 580.3 -4.6 L 529.2 5.8 L 475.7 12.1 L 419.9 14.2 L 317.2 2.7 L 229.1 -31.7 L 155.5 -89.1 L 100.3 -165.5 L 67.2 -257.1 L 56.2 -363.8 L 67.2 -471.2 L 100.4 -563.1 L 155.8 -639.4 L 229.8 -696.5 L 319.2 -730.8 L 423.8 -742.2 L 466.0 -740.6 L 509.0 -735.8 L 553.0 -727.8 L 598.4 -716.5 L 645.6 -702.0 L 694.8 -684.1 L 694.8 -511.2 L 640.1 -511.2 ", 0.0)),
+    ('H', glyph!("M 55.2 0.0 L 55.2 -51.8 L 147.9 -51.8 L 147.9 -676.8 L 55.2 -676.8 L 55.2 -729.0 L 339.8 -729.0 L 339.8 -676.8 L 247.1 -676.8 L 247.1 -424.8 L 625.0 -424.8 L 625.0 -676.8 L 532.2 -676.8 L 532.2 -729.0 L 816.9 -729.0 L 816.9 -676.8 L 724.1 -676.8 L 724.1 -51.8 L 816.9 -51.8 L 816.9 0.0 L 532.2 0.0 L 532.2 -51.8 L 625.0 -51.8 L 625.0 -365.2 L 247.1 -365.2 L 247.1 -51.8 L 339.8 -51.8 L 339.8 0.0 L 55.2 0.0 ", 0.0)),
+    ('J', glyph!("M -84.0 174.8 L -84.0 60.1 L -28.3 60.1 L -25.1 89.5 L -17.5 113.6 L -5.4 132.1 L 11.3 145.2 L 32.8 153.1 L 59.1 155.8 L 93.9 151.7 L 120.9 139.5 L 140.1 119.1 L 152.9 87.0 L 160.5 39.3 L 163.1 -23.9 L 163.1 -676.8 L 49.8 -676.8 L 49.8 -729.0 L 355.0 -729.0 L 355.0 -676.8 L 262.2 -676.8 L 262.2 -20.0 L 256.8 53.0 L 240.7 111.1 L 213.9 154.3 L 175.1 184.1 L 123.2 202.0 L 58.1 208.0 L 34.9 207.1 L 11.3 204.3 L -12.5 199.7 L -36.3 193.3 L -60.2 185.0 L -84.0 174.8 ", 0.0)),
+    ('K', glyph!("M 55.2 0.0 L 55.2 -51.8 L 147.9 -51.8 L 147.9 -676.8 L 55.2 -676.8 L 55.2 -729.0 L 339.8 -729.0 L 339.8 -676.8 L 247.1 -676.8 L 247.1 -400.9 L 561.0 -676.8 L 481.9 -676.8 L 481.9 -729.0 L 724.1 -729.0 L 724.1 -676.8 L 642.1 -676.8 L 329.1 -401.9 L 679.2 -51.8 L 762.2 -51.8 L 762.2 0.0 L 592.8 0.0 L 247.1 -346.2 L 247.1 -51.8 L 339.8 -51.8 L 339.8 0.0 L 55.2 0.0 ", 0.0)),
+    ('L', glyph!("M 55.2 0.0 L 55.2 -51.8 L 147.9 -51.8 L 147.9 -676.8 L 55.2 -676.8 L 55.2 -729.0 L 339.8 -729.0 L 339.8 -676.8 L 247.1 -676.8 L 247.1 -60.1 L 580.1 -60.1 L 580.1 -182.1 L 640.1 -182.1 L 640.1 0.0 L 55.2 0.0 ", 0.0)),
+    ('M', glyph!("M 55.2 0.0 L 55.2 -51.8 L 147.9 -51.8 L 147.9 -676.8 L 49.8 -676.8 L 49.8 -729.0 L 262.2 -729.0 L 518.1 -210.0 L 773.9 -729.0 L 973.1 -729.0 L 973.1 -676.8 L 876.0 -676.8 L 876.0 -51.8 L 969.2 -51.8 L 969.2 0.0 L 684.1 0.0 L 684.1 -51.8 L 776.9 -51.8 L 776.9 -615.2 L 526.9 -106.9 L 458.0 -106.9 L 208.0 -615.2 L 208.0 -51.8 L 300.8 -51.8 L 300.8 0.0 L 55.2 0.0 ", 0.0)),
+    ('N', glyph!("M 48.8 0.0 L 48.8 -51.8 L 147.0 -51.8 L 147.0 -676.8 L 48.8 -676.8 L 48.8 -729.0 L 235.8 -729.0 L 672.9 -153.8 L 672.9 -676.8 L 575.2 -676.8 L 575.2 -729.0 L 831.1 -729.0 L 831.1 -676.8 L 732.9 -676.8 L 732.9 14.2 L 673.8 14.2 L 207.0 -600.1 L 207.0 -51.8 L 305.2 -51.8 L 305.2 0.0 L 48.8 0.0 ", 0.0)),
+    ('P', glyph!("M 247.1 -371.1 L 376.0 -371.1 L 420.6 -375.5 L 457.6 -388.6 L 486.8 -410.4 L 508.0 -440.5 L 520.7 -478.3 L 524.9 -523.9 L 520.7 -569.8 L 508.0 -607.7 L 486.8 -637.7 L 457.6 -659.4 L 420.6 -672.4 L 376.0 -676.8 L 247.1 -676.8 L 247.1 -371.1 M 55.2 0.0 L 55.2 -51.8 L 147.9 -51.8 L 147.9 -676.8 L 55.2 -676.8 L 55.2 -729.0 L 399.9 -729.0 L 467.8 -722.8 L 525.6 -704.4 L 573.2 -673.6 L 608.8 -632.3 L 630.1 -582.4 L 637.2 -523.9 L 630.1 -465.7 L 608.8 -415.9 L 573.2 -374.5 L 525.6 -343.6 L 467.8 -325.0 L 399.9 -318.8 L 247.1 -318.8 L 247.1 -51.8 L 359.9 -51.8 L 359.9 0.0 L 55.2 0.0 ", 0.0)),
+    ('Q', glyph!("M 421.9 14.2 L 317.8 2.7 L 228.9 -31.5 L 155.3 -88.6 L 100.2 -164.9 L 67.2 -256.6 L 56.2 -363.8 L 59.1 -420.6 L 68.0 -473.3 L 82.8 -521.7 L 103.6 -566.5 L 130.7 -607.9 L 164.1 -646.0 L 197.5 -675.3 L 233.9 -699.3 L 273.4 -718.0 L 316.0 -731.4 L 361.5 -739.5 L 410.2 -742.2 L 509.2 -730.6 L 594.6 -696.0 L 666.5 -638.2 L 720.8 -561.5 L 753.3 -470.0 L 764.2 -363.8 L 756.0 -272.5 L 731.6 -191.6 L 690.9 -120.8 L 635.9 -63.0 L 568.5 -20.8 L 488.8 5.9 L 507.6 25.8 L 529.0 41.9 L 553.0 54.2 L 579.9 62.9 L 610.3 68.1 L 644.0 69.8 L 659.2 69.8 L 659.2 160.2 L 606.8 153.9 L 559.7 140.5 L 517.8 119.9 L 481.0 92.0 L 449.0 56.7 L 421.9 14.2 M 410.2 -38.1 L 483.4 -47.2 L 543.6 -74.7 L 590.8 -120.4 L 624.7 -184.0 L 645.1 -265.1 L 651.9 -363.8 L 645.1 -462.7 L 624.7 -544.0 L 590.8 -607.7 L 543.6 -653.4 L 483.4 -680.8 L 410.2 -689.9 L 336.6 -680.8 L 276.3 -653.4 L 229.0 -607.7 L 195.1 -544.0 L 174.8 -462.7 L 168.0 -363.8 L 174.8 -265.1 L 195.1 -184.0 L 229.0 -120.4 L 276.3 -74.7 L 336.6 -47.2 L 410.2 -38.1 ", 0.0)),
+    ('R', glyph!("M 479.0 -361.8 L 500.7 -354.2 L 520.3 -343.7 L 537.8 -330.3 L 553.7 -313.5 L 568.4 -292.8 L 582.0 -268.1 L 688.0 -51.8 L 776.9 -51.8 L 776.9 0.0 L 605.0 0.0 L 491.2 -231.9 L 470.0 -271.6 L 450.0 -300.6 L 431.2 -319.1 L 410.6 -330.1 L 385.6 -336.7 L 356.0 -338.9 L 247.1 -338.9 L 247.1 -51.8 L 350.1 -51.8 L 350.1 0.0 L 55.2 0.0 L 55.2 -51.8 L 147.9 -51.8 L 147.9 -676.8 L 55.2 -676.8 L 55.2 -729.0 L 424.8 -729.0 L 490.4 -723.4 L 545.1 -706.4 L 589.1 -678.2 L 621.3 -639.6 L 640.5 -591.6 L 647.0 -534.2 L 642.3 -487.6 L 628.2 -448.1 L 604.7 -415.8 L 572.0 -390.6 L 530.1 -372.6 L 479.0 -361.8 M 247.1 -391.1 L 391.1 -391.1 L 436.0 -395.0 L 472.3 -406.6 L 500.0 -426.0 L 519.5 -453.6 L 531.3 -489.6 L 535.2 -534.2 L 531.3 -578.7 L 519.5 -614.7 L 500.0 -642.1 L 472.3 -661.3 L 436.0 -672.9 L 391.1 -676.8 L 247.1 -676.8 L 247.1 -391.1 ", 0.0)),
+    ('S', glyph!("M 92.8 -35.2 L 92.8 -201.2 L 148.9 -200.7 L 155.4 -150.1 L 171.4 -109.2 L 197.0 -77.9 L 232.7 -55.8 L 279.1 -42.5 L 335.9 -38.1 L 389.3 -41.9 L 433.5 -53.4 L 468.5 -72.5 L 493.9 -99.0 L 509.1 -132.4 L 514.2 -172.9 L 511.1 -205.3 L 501.9 -232.3 L 486.6 -253.9 L 461.4 -272.6 L 422.6 -291.0 L 370.1 -309.1 L 273.9 -337.9 L 211.1 -360.8 L 162.1 -387.2 L 126.7 -417.0 L 103.0 -452.5 L 88.7 -495.8 L 84.0 -546.9 L 91.3 -604.1 L 113.3 -652.1 L 149.9 -690.9 L 199.8 -719.4 L 261.4 -736.5 L 335.0 -742.2 L 369.9 -741.0 L 407.0 -737.3 L 446.3 -731.2 L 487.5 -722.8 L 530.5 -712.1 L 575.2 -699.2 L 575.2 -543.9 L 520.0 -543.9 L 510.6 -590.6 L 493.4 -627.8 L 468.5 -655.5 L 434.7 -674.6 L 390.8 -686.1 L 336.9 -689.9 L 289.6 -686.4 L 250.4 -675.9 L 219.5 -658.4 L 197.1 -634.3 L 183.7 -603.8 L 179.2 -566.9 L 182.6 -534.6 L 192.7 -507.2 L 209.5 -484.9 L 237.2 -465.1 L 280.0 -445.4 L 337.9 -425.8 L 428.2 -398.9 L 488.0 -377.3 L 535.1 -351.9 L 569.6 -323.0 L 593.0 -288.7 L 607.1 -247.5 L 611.8 -199.2 L 604.3 -134.4 L 581.7 -81.2 L 543.9 -39.6 L 491.3 -9.7 L 423.9 8.2 L 341.8 14.2 L 301.5 12.8 L 260.7 8.7 L 219.5 2.0 L 177.7 -7.6 L 135.5 -20.0 L 92.8 -35.2 ", 0.0)),
+    ('T', glyph!("M 190.9 0.0 L 190.9 -51.8 L 284.2 -51.8 L 284.2 -670.9 L 69.8 -670.9 L 69.8 -557.1 L 9.8 -557.1 L 9.8 -729.0 L 657.2 -729.0 L 657.2 -557.1 L 597.2 -557.1 L 597.2 -670.9 L 382.8 -670.9 L 382.8 -51.8 L 476.1 -51.8 L 476.1 0.0 L 190.9 0.0 ", 0.0)),
+    ('U', glyph!("M 140.1 -676.8 L 46.9 -676.8 L 46.9 -729.0 L 332.0 -729.0 L 332.0 -676.8 L 238.8 -676.8 L 238.8 -299.8 L 243.8 -216.1 L 259.1 -151.2 L 284.4 -105.0 L 322.2 -74.3 L 374.7 -55.9 L 441.9 -49.8 L 509.1 -55.9 L 561.6 -74.3 L 599.4 -105.0 L 624.7 -151.2 L 639.9 -216.1 L 645.0 -299.8 L 645.0 -676.8 L 551.8 -676.8 L 551.8 -729.0 L 797.9 -729.0 L 797.9 -676.8 L 705.1 -676.8 L 705.1 -290.0 L 698.0 -189.9 L 676.6 -111.4 L 641.1 -54.7 L 588.5 -16.4 L 516.1 6.5 L 423.8 14.2 L 331.4 6.5 L 258.6 -16.7 L 205.3 -55.2 L 169.1 -112.1 L 147.4 -190.4 L 140.1 -290.0 L 140.1 -676.8 ", 0.0)),
+    ('V', glyph!("M 174.8 -676.8 L 390.1 -117.2 L 605.0 -676.8 L 522.9 -676.8 L 522.9 -729.0 L 736.8 -729.0 L 736.8 -676.8 L 666.0 -676.8 L 405.8 0.0 L 321.8 0.0 L 63.0 -676.8 L -9.8 -676.8 L -9.8 -729.0 L 255.9 -729.0 L 255.9 -676.8 L 174.8 -676.8 ", 0.0)),
+    ('W', glyph!("M 762.2 0.0 L 682.1 0.0 L 515.1 -592.8 L 348.1 0.0 L 268.1 0.0 L 77.1 -676.8 L 4.9 -676.8 L 4.9 -729.0 L 271.0 -729.0 L 271.0 -676.8 L 180.2 -676.8 L 332.0 -138.2 L 498.0 -729.0 L 577.1 -729.0 L 746.1 -131.8 L 898.9 -676.8 L 814.9 -676.8 L 814.9 -729.0 L 1024.9 -729.0 L 1024.9 -676.8 L 953.1 -676.8 L 762.2 0.0 ", 0.0)),
+    ('X', glyph!("M 331.1 -312.0 L 152.8 -51.8 L 247.1 -51.8 L 247.1 0.0 L 5.9 0.0 L 5.9 -51.8 L 89.8 -51.8 L 299.8 -357.9 L 86.9 -676.8 L 8.8 -676.8 L 8.8 -729.0 L 297.9 -729.0 L 297.9 -676.8 L 211.9 -676.8 L 368.2 -441.9 L 528.8 -676.8 L 435.1 -676.8 L 435.1 -729.0 L 673.8 -729.0 L 673.8 -676.8 L 591.8 -676.8 L 398.9 -396.0 L 628.9 -51.8 L 707.0 -51.8 L 707.0 0.0 L 418.0 0.0 L 418.0 -51.8 L 504.9 -51.8 L 331.1 -312.0 ", 0.0)),
+    ('Y', glyph!("M 189.0 0.0 L 189.0 -51.8 L 282.2 -51.8 L 282.2 -314.0 L 57.1 -676.8 L -11.2 -676.8 L -11.2 -729.0 L 259.8 -729.0 L 259.8 -676.8 L 174.8 -676.8 L 356.9 -381.8 L 539.1 -676.8 L 457.0 -676.8 L 457.0 -729.0 L 668.9 -729.0 L 668.9 -676.8 L 600.1 -676.8 L 380.9 -323.2 L 380.9 -51.8 L 474.1 -51.8 L 474.1 0.0 L 189.0 0.0 ", 0.0)),
+    ('Z', glyph!("M 44.9 0.0 L 44.9 -35.2 L 499.0 -668.9 L 122.1 -668.9 L 122.1 -560.1 L 62.0 -560.1 L 62.0 -729.0 L 638.2 -729.0 L 638.2 -693.8 L 184.1 -60.1 L 596.2 -60.1 L 596.2 -162.1 L 655.8 -162.1 L 655.8 0.0 L 44.9 0.0 ", 0.0)),
+    ('a', glyph!("M 397.9 -163.1 L 397.9 -272.9 L 282.2 -272.9 L 241.4 -269.7 L 208.2 -260.1 L 182.6 -244.1 L 164.4 -221.5 L 153.5 -192.1 L 149.9 -155.8 L 153.6 -122.1 L 164.7 -93.5 L 183.1 -69.8 L 207.8 -52.2 L 237.8 -41.6 L 272.9 -38.1 L 308.0 -41.9 L 338.2 -53.5 L 363.5 -72.8 L 382.6 -98.2 L 394.1 -128.3 L 397.9 -163.1 M 487.8 -324.2 L 487.8 -51.8 L 567.9 -51.8 L 567.9 0.0 L 397.9 0.0 L 397.9 -56.2 L 377.1 -34.2 L 354.1 -16.4 L 329.1 -2.9 L 301.6 6.6 L 271.2 12.3 L 237.8 14.2 L 184.1 9.1 L 138.4 -6.2 L 100.6 -31.7 L 72.4 -65.9 L 55.4 -107.3 L 49.8 -155.8 L 56.3 -205.5 L 75.6 -247.1 L 107.9 -280.8 L 152.0 -305.4 L 206.7 -320.3 L 272.0 -325.2 L 397.9 -325.2 L 397.9 -360.8 L 394.0 -397.3 L 382.0 -427.8 L 362.1 -452.4 L 334.9 -470.4 L 301.3 -481.3 L 261.2 -484.9 L 227.9 -482.2 L 199.4 -474.0 L 175.8 -460.4 L 157.3 -441.6 L 144.1 -417.5 L 136.2 -388.2 L 89.8 -388.2 L 89.8 -493.2 L 120.8 -505.4 L 151.2 -515.4 L 180.9 -523.2 L 210.1 -528.8 L 238.9 -532.1 L 267.1 -533.2 L 333.3 -527.3 L 388.1 -509.4 L 431.4 -479.7 L 462.7 -438.7 L 481.5 -386.9 L 487.8 -324.2 ", 0.0)),
+    ('b', glyph!("M 115.2 -51.8 L 115.2 -708.0 L 28.8 -708.0 L 28.8 -759.8 L 205.1 -759.8 L 205.1 -438.0 L 224.3 -467.7 L 246.6 -491.7 L 272.2 -510.0 L 301.5 -522.9 L 335.2 -530.6 L 373.0 -533.2 L 432.9 -524.8 L 485.0 -499.6 L 529.3 -457.5 L 562.9 -401.9 L 583.1 -336.0 L 589.8 -259.8 L 583.1 -183.5 L 562.9 -117.5 L 529.3 -61.8 L 485.0 -19.6 L 432.9 5.7 L 373.0 14.2 L 335.2 11.6 L 301.5 3.9 L 272.2 -9.0 L 246.6 -27.3 L 224.3 -51.4 L 205.1 -81.1 L 205.1 0.0 L 28.8 0.0 L 28.8 -51.8 L 115.2 -51.8 M 205.1 -233.9 L 209.1 -176.4 L 221.0 -128.7 L 241.0 -91.1 L 268.5 -63.8 L 303.2 -47.4 L 345.2 -42.0 L 387.4 -48.1 L 422.1 -66.5 L 449.5 -97.2 L 469.1 -139.8 L 480.9 -194.0 L 484.9 -259.8 L 480.9 -325.8 L 469.1 -380.0 L 449.5 -422.4 L 422.1 -452.7 L 387.4 -471.0 L 345.2 -477.1 L 303.2 -471.6 L 268.5 -455.1 L 241.0 -427.7 L 221.0 -390.0 L 209.1 -342.4 L 205.1 -285.2 L 205.1 -233.9 ", 0.0)),
+    ('c', glyph!("M 514.2 -155.8 L 497.6 -104.8 L 473.1 -62.7 L 440.9 -29.3 L 401.3 -5.2 L 354.5 9.3 L 300.8 14.2 L 230.2 5.8 L 169.5 -19.2 L 118.7 -60.8 L 80.4 -116.2 L 57.5 -182.5 L 49.8 -259.8 L 57.5 -337.2 L 80.4 -403.5 L 118.7 -458.7 L 169.5 -500.1 L 230.2 -524.9 L 300.8 -533.2 L 333.6 -531.9 L 366.3 -528.1 L 398.9 -521.7 L 431.5 -512.7 L 464.2 -501.1 L 497.1 -486.8 L 497.1 -354.0 L 444.8 -354.0 L 435.3 -395.5 L 420.4 -428.7 L 400.1 -453.6 L 373.9 -471.0 L 341.1 -481.4 L 301.8 -484.9 L 257.2 -478.6 L 220.6 -459.8 L 191.9 -428.5 L 171.3 -384.7 L 158.9 -328.4 L 154.8 -259.8 L 158.9 -191.1 L 171.2 -134.8 L 191.7 -90.8 L 220.3 -59.4 L 257.0 -40.5 L 301.8 -34.2 L 337.9 -37.5 L 368.8 -47.6 L 394.5 -64.5 L 415.2 -88.1 L 431.0 -118.5 L 441.9 -155.8 L 514.2 -155.8 ", 0.0)),
+    ('d', glyph!("M 524.9 -51.8 L 610.8 -51.8 L 610.8 0.0 L 435.1 0.0 L 435.1 -81.1 L 415.9 -51.4 L 393.5 -27.3 L 367.9 -9.0 L 338.6 3.9 L 305.0 11.6 L 267.1 14.2 L 207.2 5.7 L 155.0 -19.6 L 110.6 -61.8 L 76.8 -117.5 L 56.6 -183.5 L 49.8 -259.8 L 56.5 -336.0 L 76.7 -401.9 L 110.4 -457.5 L 154.7 -499.6 L 206.9 -524.8 L 267.1 -533.2 L 305.0 -530.6 L 338.6 -522.9 L 367.9 -510.0 L 393.5 -491.7 L 415.9 -467.7 L 435.1 -438.0 L 435.1 -708.0 L 350.1 -708.0 L 350.1 -759.8 L 524.9 -759.8 L 524.9 -51.8 M 435.1 -233.9 L 435.1 -285.2 L 431.1 -342.4 L 419.1 -390.0 L 399.2 -427.7 L 371.6 -455.1 L 336.9 -471.6 L 294.9 -477.1 L 252.5 -471.0 L 217.6 -452.7 L 190.2 -422.4 L 170.5 -380.0 L 158.7 -325.8 L 154.8 -259.8 L 158.7 -194.0 L 170.5 -139.8 L 190.2 -97.2 L 217.6 -66.5 L 252.5 -48.1 L 294.9 -42.0 L 336.9 -47.4 L 371.6 -63.8 L 399.2 -91.1 L 419.1 -128.7 L 431.1 -176.4 L 435.1 -233.9 ", 0.0)),
+    ('e', glyph!("M 542.0 -250.0 L 154.8 -250.0 L 154.8 -246.1 L 159.2 -181.8 L 172.4 -129.0 L 194.3 -87.6 L 224.9 -57.9 L 263.8 -40.1 L 311.0 -34.2 L 348.1 -37.6 L 380.4 -48.0 L 408.0 -65.2 L 430.7 -89.2 L 448.3 -119.9 L 460.9 -157.2 L 533.2 -157.2 L 514.8 -105.0 L 488.4 -62.2 L 453.9 -28.8 L 411.2 -4.9 L 360.5 9.4 L 301.8 14.2 L 230.9 5.8 L 169.9 -19.2 L 118.9 -60.8 L 80.5 -116.2 L 57.5 -182.5 L 49.8 -259.8 L 57.3 -336.5 L 80.0 -402.6 L 117.7 -458.0 L 167.6 -499.8 L 227.1 -524.8 L 295.9 -533.2 L 368.3 -525.1 L 428.5 -501.0 L 476.6 -460.7 L 511.8 -405.1 L 533.6 -334.8 L 542.0 -250.0 M 436.0 -301.8 L 430.5 -357.3 L 417.7 -403.0 L 397.7 -438.7 L 370.7 -464.4 L 336.7 -479.7 L 295.9 -484.9 L 257.5 -479.7 L 224.9 -464.2 L 198.2 -438.5 L 177.6 -402.7 L 163.1 -357.1 L 154.8 -301.8 L 436.0 -301.8 ", 0.0)),
+    ('f', glyph!("M 430.2 -637.2 L 382.8 -637.2 L 380.3 -659.7 L 373.4 -678.2 L 362.1 -692.9 L 346.5 -703.5 L 326.9 -709.8 L 303.2 -711.9 L 273.0 -708.8 L 249.4 -699.7 L 232.4 -684.3 L 221.0 -661.1 L 214.2 -628.3 L 211.9 -585.9 L 211.9 -519.0 L 356.9 -519.0 L 356.9 -466.8 L 211.9 -466.8 L 211.9 -51.8 L 327.1 -51.8 L 327.1 0.0 L 36.1 0.0 L 36.1 -51.8 L 122.1 -51.8 L 122.1 -466.8 L 36.1 -466.8 L 36.1 -519.0 L 122.1 -519.0 L 122.1 -584.0 L 127.1 -637.2 L 142.1 -681.0 L 167.2 -715.3 L 202.0 -740.0 L 246.2 -754.8 L 299.8 -759.8 L 321.6 -759.1 L 343.4 -757.2 L 365.2 -753.9 L 387.0 -749.3 L 408.6 -743.3 L 430.2 -735.8 L 430.2 -637.2 ", 0.0)),
+    ('g', glyph!("M 524.9 -466.8 L 524.9 -11.2 L 518.1 57.6 L 497.6 115.2 L 463.4 161.4 L 416.6 195.1 L 358.1 215.4 L 288.1 222.2 L 254.4 221.1 L 221.7 218.0 L 189.9 212.9 L 159.1 205.7 L 129.2 196.4 L 100.1 185.1 L 100.1 76.2 L 147.0 76.2 L 155.5 107.0 L 169.3 131.7 L 188.5 150.4 L 213.4 163.4 L 244.7 171.2 L 282.2 173.8 L 330.3 168.9 L 368.9 154.0 L 398.2 129.2 L 418.7 93.7 L 431.0 46.9 L 435.1 -11.2 L 435.1 -81.1 L 415.9 -51.4 L 393.5 -27.3 L 367.9 -9.0 L 338.6 3.9 L 305.0 11.6 L 267.1 14.2 L 207.2 5.7 L 155.0 -19.6 L 110.6 -61.8 L 76.8 -117.5 L 56.6 -183.5 L 49.8 -259.8 L 56.5 -336.0 L 76.7 -401.9 L 110.4 -457.5 L 154.7 -499.6 L 206.9 -524.8 L 267.1 -533.2 L 305.0 -530.6 L 338.6 -522.9 L 367.9 -510.0 L 393.5 -491.7 L 415.9 -467.7 L 435.1 -438.0 L 435.1 -519.0 L 610.8 -519.0 L 610.8 -466.8 L 524.9 -466.8 M 435.1 -285.2 L 431.1 -342.4 L 419.1 -390.0 L 399.2 -427.7 L 371.6 -455.1 L 336.9 -471.6 L 294.9 -477.1 L 252.5 -471.0 L 217.6 -452.7 L 190.2 -422.4 L 170.5 -380.0 L 158.7 -325.8 L 154.8 -259.8 L 158.7 -194.0 L 170.5 -139.8 L 190.2 -97.2 L 217.6 -66.5 L 252.5 -48.1 L 294.9 -42.0 L 336.9 -47.4 L 371.6 -63.8 L 399.2 -91.1 L 419.1 -128.7 L 431.1 -176.4 L 435.1 -233.9 L 435.1 -285.2 ", 0.0)),
+    ('h', glyph!("M 41.0 0.0 L 41.0 -51.8 L 122.1 -51.8 L 122.1 -708.0 L 36.1 -708.0 L 36.1 -759.8 L 211.9 -759.8 L 211.9 -426.8 L 230.2 -459.0 L 251.7 -485.6 L 276.6 -506.3 L 304.6 -521.3 L 335.4 -530.2 L 369.1 -533.2 L 421.4 -527.7 L 463.5 -511.3 L 495.6 -483.9 L 518.1 -444.9 L 531.6 -393.6 L 536.1 -330.1 L 536.1 -51.8 L 616.2 -51.8 L 616.2 0.0 L 368.2 0.0 L 368.2 -51.8 L 445.8 -51.8 L 445.8 -301.8 L 443.2 -358.5 L 435.5 -401.9 L 422.6 -431.9 L 403.1 -451.3 L 375.5 -462.9 L 339.8 -466.8 L 301.5 -461.8 L 269.7 -446.6 L 244.6 -421.4 L 226.5 -386.5 L 215.5 -342.4 L 211.9 -289.1 L 211.9 -51.8 L 290.0 -51.8 L 290.0 0.0 L 41.0 0.0 ", 0.0)),
+    ('j', glyph!("M 100.1 -680.2 L 101.9 -694.5 L 107.4 -707.5 L 116.5 -719.2 L 128.1 -728.5 L 141.2 -734.0 L 155.8 -735.8 L 170.1 -734.0 L 183.0 -728.5 L 194.6 -719.2 L 203.7 -707.5 L 209.1 -694.5 L 210.9 -680.2 L 209.2 -665.6 L 203.9 -652.6 L 195.1 -641.1 L 183.6 -632.2 L 170.5 -626.8 L 155.8 -625.0 L 141.2 -626.8 L 128.1 -632.2 L 116.5 -641.1 L 107.4 -652.6 L 101.9 -665.6 L 100.1 -680.2 M 122.1 -466.8 L 37.1 -466.8 L 37.1 -519.0 L 211.9 -519.0 L 211.9 50.8 L 206.6 100.3 L 190.8 142.3 L 164.3 176.5 L 128.6 201.9 L 84.9 217.1 L 33.2 222.2 L 10.1 221.3 L -12.3 218.6 L -33.9 214.1 L -55.1 207.8 L -75.8 199.8 L -96.2 189.9 L -96.2 83.0 L -49.8 83.0 L -45.6 111.5 L -37.5 134.4 L -25.6 151.9 L -9.7 164.1 L 10.6 171.4 L 35.2 173.8 L 62.2 170.5 L 84.1 160.5 L 100.8 143.8 L 112.6 120.1 L 119.7 89.1 L 122.1 50.8 L 122.1 -466.8 ", 0.0)),
+    ('k', glyph!("M 286.1 0.0 L 34.2 0.0 L 34.2 -51.8 L 115.2 -51.8 L 115.2 -708.0 L 28.8 -708.0 L 28.8 -759.8 L 205.1 -759.8 L 205.1 -265.1 L 423.8 -466.8 L 349.1 -466.8 L 349.1 -519.0 L 584.0 -519.0 L 584.0 -466.8 L 495.1 -466.8 L 340.8 -324.2 L 538.1 -51.8 L 612.8 -51.8 L 612.8 0.0 L 356.9 0.0 L 356.9 -51.8 L 431.2 -51.8 L 275.9 -265.1 L 205.1 -199.2 L 205.1 -51.8 L 286.1 -51.8 L 286.1 0.0 ", 0.0)),
+    ('m', glyph!("M 518.1 -418.0 L 536.9 -452.9 L 559.0 -481.7 L 584.2 -504.2 L 612.6 -520.3 L 643.9 -530.0 L 678.2 -533.2 L 728.0 -527.6 L 768.5 -510.7 L 799.8 -482.7 L 822.0 -443.3 L 835.4 -392.4 L 839.8 -330.1 L 839.8 -51.8 L 920.9 -51.8 L 920.9 0.0 L 671.9 0.0 L 671.9 -51.8 L 750.0 -51.8 L 750.0 -319.8 L 747.4 -367.8 L 739.6 -405.5 L 726.6 -433.1 L 707.5 -451.8 L 681.6 -463.1 L 648.9 -466.8 L 612.1 -461.8 L 581.5 -446.6 L 557.4 -421.4 L 539.9 -386.5 L 529.4 -342.4 L 525.9 -289.1 L 525.9 -51.8 L 604.0 -51.8 L 604.0 0.0 L 357.9 0.0 L 357.9 -51.8 L 436.0 -51.8 L 436.0 -323.2 L 433.4 -370.0 L 425.6 -406.9 L 412.6 -433.8 L 393.6 -452.1 L 367.7 -463.1 L 335.0 -466.8 L 298.1 -461.8 L 267.6 -446.6 L 243.4 -421.4 L 225.9 -386.5 L 215.4 -342.4 L 211.9 -289.1 L 211.9 -51.8 L 290.0 -51.8 L 290.0 0.0 L 41.0 0.0 L 41.0 -51.8 L 122.1 -51.8 L 122.1 -467.8 L 36.1 -467.8 L 36.1 -519.0 L 211.9 -519.0 L 211.9 -426.8 L 230.0 -458.6 L 251.2 -484.9 L 275.4 -505.9 L 302.2 -521.1 L 331.4 -530.2 L 362.8 -533.2 L 400.9 -529.9 L 434.6 -519.9 L 463.9 -503.2 L 487.9 -480.4 L 506.0 -452.0 L 518.1 -418.0 ", 0.0)),
+    ('n', glyph!("M 41.0 0.0 L 41.0 -51.8 L 122.1 -51.8 L 122.1 -466.8 L 36.1 -466.8 L 36.1 -519.0 L 211.9 -519.0 L 211.9 -426.8 L 230.2 -459.0 L 251.7 -485.6 L 276.6 -506.3 L 304.6 -521.3 L 335.4 -530.2 L 369.1 -533.2 L 421.4 -527.7 L 463.5 -511.3 L 495.6 -483.9 L 518.1 -444.9 L 531.6 -393.6 L 536.1 -330.1 L 536.1 -51.8 L 616.2 -51.8 L 616.2 0.0 L 368.2 0.0 L 368.2 -51.8 L 445.8 -51.8 L 445.8 -301.8 L 443.2 -358.6 L 435.4 -402.1 L 422.4 -432.4 L 402.8 -452.0 L 375.3 -463.8 L 339.8 -467.8 L 301.5 -462.7 L 269.7 -447.5 L 244.6 -422.1 L 226.5 -387.0 L 215.5 -342.7 L 211.9 -289.1 L 211.9 -51.8 L 290.0 -51.8 L 290.0 0.0 L 41.0 0.0 ", 0.0)),
+    ('p', glyph!("M 205.1 -285.2 L 205.1 -233.9 L 209.1 -176.4 L 221.0 -128.7 L 241.0 -91.1 L 268.5 -63.8 L 303.2 -47.4 L 345.2 -42.0 L 387.4 -48.1 L 422.1 -66.5 L 449.5 -97.2 L 469.1 -139.8 L 480.9 -194.0 L 484.9 -259.8 L 480.9 -325.8 L 469.1 -380.0 L 449.5 -422.4 L 422.1 -452.7 L 387.4 -471.0 L 345.2 -477.1 L 303.2 -471.6 L 268.5 -455.1 L 241.0 -427.7 L 221.0 -390.0 L 209.1 -342.4 L 205.1 -285.2 M 115.2 -466.8 L 28.8 -466.8 L 28.8 -519.0 L 205.1 -519.0 L 205.1 -438.0 L 224.3 -467.7 L 246.6 -491.7 L 272.2 -510.0 L 301.5 -522.9 L 335.2 -530.6 L 373.0 -533.2 L 432.9 -524.8 L 485.0 -499.6 L 529.3 -457.5 L 562.9 -401.9 L 583.1 -336.0 L 589.8 -259.8 L 583.1 -183.5 L 562.9 -117.5 L 529.3 -61.8 L 485.0 -19.6 L 432.9 5.7 L 373.0 14.2 L 335.2 11.6 L 301.5 3.9 L 272.2 -9.0 L 246.6 -27.3 L 224.3 -51.4 L 205.1 -81.1 L 205.1 155.8 L 290.0 155.8 L 290.0 208.0 L 28.8 208.0 L 28.8 155.8 L 115.2 155.8 L 115.2 -466.8 ", 0.0)),
+    ('q', glyph!("M 524.9 -466.8 L 524.9 155.8 L 610.8 155.8 L 610.8 208.0 L 350.1 208.0 L 350.1 155.8 L 435.1 155.8 L 435.1 -81.1 L 415.9 -51.4 L 393.5 -27.3 L 367.9 -9.0 L 338.6 3.9 L 305.0 11.6 L 267.1 14.2 L 207.2 5.7 L 155.0 -19.6 L 110.6 -61.8 L 76.8 -117.5 L 56.6 -183.5 L 49.8 -259.8 L 56.5 -336.0 L 76.7 -401.9 L 110.4 -457.5 L 154.7 -499.6 L 206.9 -524.8 L 267.1 -533.2 L 305.0 -530.6 L 338.6 -522.9 L 367.9 -510.0 L 393.5 -491.7 L 415.9 -467.7 L 435.1 -438.0 L 435.1 -519.0 L 610.8 -519.0 L 610.8 -466.8 L 524.9 -466.8 M 435.1 -285.2 L 431.1 -342.4 L 419.1 -390.0 L 399.2 -427.7 L 371.6 -455.1 L 336.9 -471.6 L 294.9 -477.1 L 252.5 -471.0 L 217.6 -452.7 L 190.2 -422.4 L 170.5 -380.0 L 158.7 -325.8 L 154.8 -259.8 L 158.7 -194.0 L 170.5 -139.8 L 190.2 -97.2 L 217.6 -66.5 L 252.5 -48.1 L 294.9 -42.0 L 336.9 -47.4 L 371.6 -63.8 L 399.2 -91.1 L 419.1 -128.7 L 431.1 -176.4 L 435.1 -233.9 L 435.1 -285.2 ", 0.0)),
+    ('r', glyph!("M 478.0 -520.0 L 478.0 -390.1 L 426.3 -390.1 L 422.8 -413.7 L 415.6 -432.9 L 404.8 -447.8 L 390.1 -458.3 L 371.6 -464.7 L 349.1 -466.8 L 308.3 -461.7 L 274.4 -446.4 L 247.3 -420.9 L 227.6 -385.9 L 215.8 -341.9 L 211.9 -289.1 L 211.9 -51.8 L 315.9 -51.8 L 315.9 0.0 L 41.0 0.0 L 41.0 -51.8 L 122.1 -51.8 L 122.1 -467.8 L 36.1 -467.8 L 36.1 -519.0 L 211.9 -519.0 L 211.9 -426.8 L 231.2 -459.8 L 253.8 -486.5 L 279.8 -507.1 L 309.5 -521.6 L 343.2 -530.3 L 380.9 -533.2 L 395.8 -532.8 L 411.1 -531.7 L 427.0 -529.8 L 443.4 -527.2 L 460.4 -523.9 L 478.0 -520.0 ", 0.0)),
+    ('s', glyph!("M 56.2 -28.8 L 56.2 -149.9 L 107.9 -149.9 L 112.8 -114.4 L 124.9 -85.4 L 144.3 -63.0 L 170.9 -47.0 L 204.8 -37.4 L 246.1 -34.2 L 283.3 -36.7 L 314.0 -44.3 L 338.4 -56.9 L 356.0 -74.3 L 366.6 -96.4 L 370.1 -123.0 L 367.5 -144.4 L 359.8 -162.8 L 346.9 -178.2 L 325.7 -192.5 L 293.1 -207.5 L 249.0 -223.1 L 184.1 -245.1 L 143.6 -260.6 L 111.3 -278.5 L 87.2 -298.8 L 70.5 -322.3 L 60.5 -349.6 L 57.1 -380.9 L 62.9 -425.0 L 80.3 -462.2 L 109.4 -492.7 L 148.7 -515.2 L 196.8 -528.7 L 253.9 -533.2 L 281.7 -532.0 L 310.5 -528.4 L 340.3 -522.5 L 370.9 -514.2 L 402.2 -503.8 L 434.1 -491.2 L 434.1 -377.9 L 382.3 -377.9 L 377.5 -409.0 L 365.8 -435.2 L 347.2 -456.5 L 322.4 -472.3 L 292.3 -481.7 L 256.8 -484.9 L 222.1 -482.6 L 193.6 -476.0 L 171.1 -464.8 L 155.0 -449.3 L 145.3 -429.3 L 142.1 -404.8 L 144.5 -384.4 L 151.9 -366.9 L 164.1 -352.3 L 183.6 -339.0 L 212.9 -325.6 L 252.0 -312.0 L 323.2 -290.0 L 367.8 -273.5 L 403.2 -254.3 L 429.4 -232.7 L 447.5 -207.5 L 458.3 -178.0 L 461.9 -144.0 L 455.6 -98.4 L 436.8 -59.7 L 405.5 -28.1 L 363.2 -4.6 L 311.3 9.5 L 250.0 14.2 L 216.7 13.0 L 184.0 9.4 L 151.9 3.4 L 120.0 -4.9 L 88.1 -15.7 L 56.2 -28.8 ", 0.0)),
+    ('t', glyph!("M 107.9 -466.8 L 28.8 -466.8 L 28.8 -519.0 L 107.9 -519.0 L 107.9 -680.2 L 198.2 -680.2 L 198.2 -519.0 L 367.2 -519.0 L 367.2 -466.8 L 198.2 -466.8 L 198.2 -137.2 L 199.7 -98.5 L 203.9 -70.4 L 210.9 -52.7 L 221.8 -42.4 L 237.4 -36.2 L 257.8 -34.2 L 279.1 -36.5 L 296.2 -43.4 L 309.1 -54.9 L 318.1 -71.6 L 323.8 -94.0 L 326.2 -122.1 L 394.0 -122.1 L 388.0 -79.1 L 375.2 -44.6 L 355.5 -18.6 L 328.3 -0.4 L 293.2 10.5 L 250.0 14.2 L 203.3 10.3 L 166.7 -1.1 L 140.1 -20.3 L 122.2 -48.6 L 111.5 -87.5 L 107.9 -137.2 L 107.9 -466.8 ", 0.0)),
+    ('u', glyph!("M 354.0 -519.0 L 522.0 -519.0 L 522.0 -51.8 L 606.9 -51.8 L 606.9 0.0 L 432.1 0.0 L 432.1 -91.8 L 413.9 -60.0 L 392.4 -33.7 L 367.7 -12.9 L 339.9 2.1 L 309.3 11.1 L 275.9 14.2 L 223.3 8.7 L 180.9 -7.6 L 148.7 -34.9 L 126.0 -73.8 L 112.4 -125.2 L 107.9 -189.0 L 107.9 -466.8 L 26.9 -466.8 L 26.9 -519.0 L 198.2 -519.0 L 198.2 -216.8 L 200.8 -160.3 L 208.6 -117.0 L 221.4 -86.9 L 240.9 -67.4 L 268.5 -55.7 L 304.2 -51.8 L 342.6 -56.9 L 374.3 -72.2 L 399.4 -97.7 L 417.6 -132.9 L 428.5 -177.3 L 432.1 -231.0 L 432.1 -466.8 L 354.0 -466.8 L 354.0 -519.0 ", 0.0)),
+    ('v', glyph!("M 247.1 0.0 L 56.2 -466.8 L -2.9 -466.8 L -2.9 -519.0 L 235.8 -519.0 L 235.8 -466.8 L 152.8 -466.8 L 298.8 -109.9 L 444.8 -466.8 L 367.2 -466.8 L 367.2 -519.0 L 562.0 -519.0 L 562.0 -466.8 L 503.9 -466.8 L 313.0 0.0 L 247.1 0.0 ", 0.0)),
+    ('w', glyph!("M 480.0 -519.0 L 612.8 -113.8 L 730.0 -466.8 L 654.8 -466.8 L 654.8 -519.0 L 842.8 -519.0 L 842.8 -466.8 L 785.2 -466.8 L 630.9 0.0 L 556.2 0.0 L 428.2 -388.2 L 299.8 0.0 L 228.0 0.0 L 74.2 -466.8 L 16.1 -466.8 L 16.1 -519.0 L 251.0 -519.0 L 251.0 -466.8 L 167.0 -466.8 L 283.2 -113.8 L 417.0 -519.0 L 480.0 -519.0 ", 0.0)),
+    ('x', glyph!("M 291.0 -316.9 L 399.9 -466.8 L 330.1 -466.8 L 330.1 -519.0 L 529.8 -519.0 L 529.8 -466.8 L 460.9 -466.8 L 321.8 -274.9 L 483.9 -51.8 L 551.8 -51.8 L 551.8 0.0 L 312.0 0.0 L 312.0 -51.8 L 377.9 -51.8 L 265.1 -207.0 L 151.9 -51.8 L 219.2 -51.8 L 219.2 0.0 L 22.0 0.0 L 22.0 -51.8 L 90.8 -51.8 L 233.9 -249.0 L 76.2 -466.8 L 12.2 -466.8 L 12.2 -519.0 L 244.1 -519.0 L 244.1 -466.8 L 182.1 -466.8 L 291.0 -316.9 ", 0.0)),
+    ('y', glyph!("M 215.8 95.2 L 250.0 8.8 L 56.2 -466.8 L -2.9 -466.8 L -2.9 -519.0 L 235.8 -519.0 L 235.8 -466.8 L 152.8 -466.8 L 298.8 -109.9 L 444.8 -466.8 L 367.2 -466.8 L 367.2 -519.0 L 562.0 -519.0 L 562.0 -466.8 L 503.9 -466.8 L 266.1 117.2 L 249.2 153.3 L 231.2 180.9 L 211.9 200.0 L 189.3 212.3 L 161.3 219.7 L 127.9 222.2 L 112.5 221.7 L 96.8 220.4 L 80.8 218.3 L 64.7 215.3 L 48.5 211.5 L 32.2 207.0 L 32.2 107.9 L 78.1 107.9 L 81.3 128.0 L 86.9 143.8 L 95.0 155.5 L 106.0 163.5 L 120.4 168.3 L 138.2 169.9 L 154.9 168.3 L 169.3 163.3 L 181.4 155.0 L 192.5 141.8 L 204.0 121.8 L 215.8 95.2 ", 0.0)),
+    ('z', glyph!("M 40.0 0.0 L 40.0 -42.0 L 365.2 -466.8 L 107.9 -466.8 L 107.9 -377.0 L 56.2 -377.0 L 56.2 -519.0 L 481.0 -519.0 L 481.0 -477.1 L 155.8 -51.8 L 438.0 -51.8 L 438.0 -146.0 L 490.2 -146.0 L 490.2 0.0 L 40.0 0.0 ", 0.0)),
+    ('×', glyph!("M 700.2 -539.1 L 474.1 -313.0 L 700.2 -87.9 L 644.0 -33.2 L 418.9 -257.8 L 193.8 -33.2 L 138.2 -87.9 L 362.8 -313.0 L 138.2 -539.1 L 193.8 -594.2 L 418.9 -369.1 L 644.0 -594.2 L 700.2 -539.1 ", 0.0)),
+];
@@ -0,0 +1,199 @@
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use super::captcha::now;
+use super::format::escape;
+use super::model::{Bounds, Command, CommandType, Path, RenderMode, Transform};
+use super::{nest, Group, Scene};
+
+/// SliderCaptcha is a puzzle captcha, the piece has to be slid horizontally
+/// into the cutout of the background
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SliderCaptcha {
+    /// background svg with the cutout
+    pub background: String,
+    /// svg of the piece, exactly as large as the piece
+    pub piece: String,
+    /// left edge of the cutout, the answer of the captcha
+    pub x: f64,
+    /// top edge of the cutout, the piece is shown at this height
+    pub y: f64,
+    /// width of the background
+    pub width: f64,
+    /// height of the background
+    pub height: f64,
+    /// width and height of the piece
+    pub piece_size: f64,
+    /// creation time in seconds since the unix epoch
+    pub created_at: u64,
+}
+
+impl SliderCaptcha {
+    /// Cut a piece of 0.4 times the height out of the scene, somewhere right
+    /// of the start position of the piece. the hole is drawn into the scene
+    /// like its other paths, in `nesting` deep groups if set, and the piece
+    /// is drawn in its own coordinates, so neither svg has the position in
+    /// plain text.
+    pub(crate) fn new<R: Rng + ?Sized>(
+        rng: &mut R,
+        mut scene: Scene,
+        nesting: usize,
+    ) -> SliderCaptcha {
+        let size = scene.height * 0.4;
+        let max_x = (scene.width - size).max(0.0);
+        let min_x = (size * 1.2).min(max_x);
+        let x = rng.gen_range(min_x..=max_x);
+        let y = rng.gen_range(0.0..=scene.height - size);
+        let stroke_width = size / 30.0;
+
+        // only what shows through the piece, moved to its origin
+        let shift = Transform {
+            scale: 1.0,
+            angle: 0.0,
+            x: -x,
+            y: -y,
+        };
+        let mut piece = scene.clone().place(&shift, size, size);
+        let inside = |bounds: Bounds| {
+            bounds.max_x >= 0.0
+                && bounds.min_x <= size
+                && bounds.max_y >= 0.0
+                && bounds.min_y <= size
+        };
+        piece.groups.clear();
+        piece.paths.retain(|path| inside(path.bounds()));
+        piece.dots.retain(|dot| {
+            inside(Bounds {
+                min_x: dot.x - dot.r,
+                min_y: dot.y - dot.r,
+                max_x: dot.x + dot.r,
+                max_y: dot.y + dot.r,
+            })
+        });
+        if nesting > 0 {
+            piece.groups = nest(rng, &piece, nesting);
+        }
+        let outline = piece_outline(0.0, 0.0, size);
+        let mut outline_data = String::new();
+        // writing to a string doesn't fail
+        let _ = piece.format.write_path_data(&mut outline_data, &outline);
+        let mut border = String::new();
+        let _ = Path::stroke(outline, "white", stroke_width).write_svg(&mut border, &piece.format);
+        let size_text = piece.format.number(size);
+        let piece = format!(
+            r#"<svg width="{}" height="{}" viewBox="0 0 {} {}" xmlns="http://www.w3.org/2000/svg" version="1.1"><defs><clipPath id="{}-piece"><path d="{}" /></clipPath></defs><g clip-path="url(#{}-piece)">{}</g>{}</svg>"#,
+            size_text,
+            size_text,
+            size_text,
+            size_text,
+            escape(&scene.id_prefix),
+            outline_data,
+            escape(&scene.id_prefix),
+            piece.content(),
+            border,
+        );
+
+        // the hole is darkened and outlined on top of everything, in groups
+        // of its own without nesting so its path data is moved too
+        let hole = piece_outline(x, y, size);
+        let hole = vec![
+            Path {
+                render_mode: RenderMode::Fill,
+                opacity: 0.5,
+                ..Path::stroke(hole.clone(), "black", stroke_width)
+            },
+            Path::stroke(hole, "white", stroke_width),
+        ];
+        if nesting > 0 {
+            scene.paths.extend(hole);
+            scene.groups = nest(rng, &scene, nesting);
+        } else {
+            let mut paths = std::mem::replace(&mut scene.paths, hole);
+            let hole_groups = nest(rng, &scene, 1);
+            scene.groups = vec![Group {
+                transforms: Vec::new(),
+                len: paths.len(),
+            }];
+            scene.groups.extend(hole_groups);
+            paths.append(&mut scene.paths);
+            scene.paths = paths;
+        }
+
+        SliderCaptcha {
+            background: scene.to_svg(),
+            piece,
+            x,
+            y,
+            width: scene.width,
+            height: scene.height,
+            piece_size: size,
+            created_at: now(),
+        }
+    }
+
+    /// whether the piece was slid to `x` within `tolerance` of the cutout
+    pub fn verify(&self, x: f64, tolerance: f64) -> bool {
+        (x - self.x).abs() <= tolerance
+    }
+}
+
+/// Commands of a puzzle piece in a `size` square at (`x`, `y`): a square
+/// with a round knob on the top and the right edge.
+fn piece_outline(x: f64, y: f64, size: f64) -> Vec<Command> {
+    let r = size / 6.0;
+    let body = size - r;
+    let (left, top) = (x, y + r);
+    let knob = CommandType::Arc {
+        rx: r,
+        ry: r,
+        rotation: 0.0,
+        large_arc: false,
+        sweep: true,
+    };
+    vec![
+        Command::new(left, top, CommandType::Move),
+        Command::new(left + body / 2.0 - r, top, CommandType::LineTo),
+        Command::new(left + body / 2.0 + r, top, knob),
+        Command::new(left + body, top, CommandType::LineTo),
+        Command::new(left + body, top + body / 2.0 - r, CommandType::LineTo),
+        Command::new(left + body, top + body / 2.0 + r, knob),
+        Command::new(left + body, top + body, CommandType::LineTo),
+        Command::new(left, top + body, CommandType::LineTo),
+        Command::new(left, top, CommandType::Close),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BiosvgBuilder;
+
+    #[test]
+    fn slider_captcha() {
+        let slider = BiosvgBuilder::new()
+            .length(4)
+            .difficulty(6)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .background("#eeeeee")
+            .width(300)
+            .height(150)
+            .seed(5)
+            .build_slider()
+            .unwrap();
+        assert!(slider.x >= slider.piece_size && slider.x + slider.piece_size <= slider.width);
+        assert!(slider.y >= 0.0 && slider.y + slider.piece_size <= slider.height);
+        assert!(slider.background.contains("fill-opacity"));
+        assert!(slider.piece.contains("clip-path"));
+        assert!(slider.piece.contains(&format!(
+            r#"viewBox="0 0 {} {}""#,
+            slider.piece_size, slider.piece_size
+        )));
+        // neither svg gives the answer away
+        for svg in [&slider.background, &slider.piece] {
+            assert!(!svg.contains(&slider.x.to_string()));
+        }
+        assert!(slider.verify(slider.x + 2.0, 3.0));
+        assert!(!slider.verify(slider.x + 4.0, 3.0));
+    }
+}
@@ -0,0 +1,365 @@
+//! Server side storage of captcha answers, enabled by the `store` feature.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use rand::Rng;
+use thiserror::Error;
+
+use super::captcha::{entropy_rng, now, Captcha};
+use super::verify::{verify, VerifyOptions};
+
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "redis")]
+pub use redis::RedisChallengeStore;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum StoreError {
+    #[error("unknown captcha")]
+    NotFound,
+    #[error("captcha already used")]
+    AlreadyUsed,
+    #[error("captcha expired")]
+    Expired,
+    #[error("wrong captcha answer")]
+    WrongAnswer,
+    #[error("challenge store backend failed: {0}")]
+    Backend(String),
+}
+
+/// ChallengeStore keeps the answers of captchas on the server, so only an
+/// id is sent to the client. `MemoryChallengeStore` and, with the `redis`
+/// feature, `RedisChallengeStore` implement it; other backends like a SQL
+/// table or an existing session layer can implement it too:
+///
+/// - `put` saves `captcha.plain_answer()` and `captcha.expires_at` under a new
+///   unguessable id, e.g. from a UUID, and returns the id
+/// - `verify_and_consume` must read and delete the challenge in one atomic
+///   step, whatever the outcome, so concurrent requests can't verify it twice.
+///   compare with `verify()` and report `NotFound`, `AlreadyUsed` for
+///   challenges consumed before, `Expired` or `WrongAnswer`, and failures of
+///   the backend as `Backend`
+/// - `purge_expired` deletes expired challenges and returns how many, stores
+///   that expire keys by themselves return 0
+pub trait ChallengeStore {
+    /// Store the answer of a captcha until it expires and return its id.
+    fn put(&self, captcha: &Captcha) -> Result<String, StoreError>;
+
+    /// Check the user input against the challenge and remove it, so every
+    /// challenge can be verified once.
+    fn verify_and_consume(&self, id: &str, user_input: &str) -> Result<(), StoreError>;
+
+    /// Remove the expired challenges and return their number, to be called
+    /// periodically.
+    fn purge_expired(&self) -> Result<usize, StoreError>;
+}
+
+/// inserts between two purges of expired challenges in `create`
+const PURGE_EVERY: usize = 1024;
+/// seconds after which `create` purges expired challenges whatever the
+/// number of inserts
+const PURGE_INTERVAL: u64 = 60;
+
+/// Challenge is a stored answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Challenge {
+    answer: String,
+    expires_at: u64,
+    case_insensitive: bool,
+    used: bool,
+}
+
+/// Challenges are the stored challenges and when they were last purged
+#[derive(Debug, Default)]
+struct Challenges {
+    map: HashMap<String, Challenge>,
+    inserts: usize,
+    purged_at: u64,
+}
+
+impl Challenges {
+    fn purge_expired(&mut self, now: u64) -> usize {
+        let len = self.map.len();
+        self.map.retain(|_, challenge| challenge.expires_at >= now);
+        (self.inserts, self.purged_at) = (0, now);
+        len - self.map.len()
+    }
+}
+
+/// MemoryChallengeStore keeps the answers of captchas in memory under a
+/// random UUID until they expire. every challenge can be verified once, it
+/// is used up by the first attempt whether the answer is right or not and
+/// later attempts fail with `AlreadyUsed` until it expires. answers of case
+/// insensitive captchas are compared ignoring case whatever the options are.
+/// expired challenges are purged every 1024 inserts or a minute.
+#[derive(Debug, Default)]
+pub struct MemoryChallengeStore {
+    options: VerifyOptions,
+    challenges: Mutex<Challenges>,
+}
+
+impl MemoryChallengeStore {
+    /// an empty store comparing answers with the default `VerifyOptions`
+    pub fn new() -> MemoryChallengeStore {
+        MemoryChallengeStore::default()
+    }
+
+    /// compare answers with `options` instead of the defaults
+    pub fn with_options(mut self, options: VerifyOptions) -> MemoryChallengeStore {
+        self.options = options;
+        self
+    }
+
+    /// Store the answer of a captcha until it expires and return its id, to
+    /// be sent to the client with the svg. the answer must not be hashed.
+    pub fn create(&self, captcha: &Captcha) -> String {
+        let id = uuid();
+        let mut challenges = self.challenges.lock().unwrap();
+        // expired challenges are dropped as new ones come in, not on every
+        // insert to keep the lock short
+        let now = now();
+        challenges.inserts += 1;
+        if challenges.inserts >= PURGE_EVERY || now >= challenges.purged_at + PURGE_INTERVAL {
+            challenges.purge_expired(now);
+        }
+        challenges.map.insert(
+            id.clone(),
+            Challenge {
+                answer: captcha.plain_answer().to_string(),
+                expires_at: captcha.expires_at,
+                case_insensitive: captcha.case_insensitive,
+                used: false,
+            },
+        );
+        id
+    }
+
+    /// Check the user input against the challenge and use it up, a second
+    /// attempt with the same id fails with `AlreadyUsed`.
+    pub fn verify_and_consume(&self, id: &str, user_input: &str) -> Result<(), StoreError> {
+        let challenge = {
+            let mut challenges = self.challenges.lock().unwrap();
+            let challenge = challenges.map.get_mut(id).ok_or(StoreError::NotFound)?;
+            if challenge.used {
+                return Err(StoreError::AlreadyUsed);
+            }
+            // the used challenge stays until it expires to tell replays apart
+            challenge.used = true;
+            challenge.clone()
+        };
+        if now() > challenge.expires_at {
+            return Err(StoreError::Expired);
+        }
+        let options = options(&self.options, challenge.case_insensitive);
+        match verify(&challenge.answer, user_input, &options) {
+            true => Ok(()),
+            false => Err(StoreError::WrongAnswer),
+        }
+    }
+
+    /// Remove the expired challenges and return their number.
+    pub fn purge_expired(&self) -> usize {
+        self.challenges.lock().unwrap().purge_expired(now())
+    }
+
+    /// number of stored challenges, used and expired ones included
+    pub fn len(&self) -> usize {
+        self.challenges.lock().unwrap().map.len()
+    }
+
+    /// whether no challenges are stored
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl ChallengeStore for MemoryChallengeStore {
+    fn put(&self, captcha: &Captcha) -> Result<String, StoreError> {
+        Ok(self.create(captcha))
+    }
+
+    fn verify_and_consume(&self, id: &str, user_input: &str) -> Result<(), StoreError> {
+        MemoryChallengeStore::verify_and_consume(self, id, user_input)
+    }
+
+    fn purge_expired(&self) -> Result<usize, StoreError> {
+        Ok(MemoryChallengeStore::purge_expired(self))
+    }
+}
+
+/// the options of a store, ignoring case if the captcha does
+fn options(options: &VerifyOptions, case_insensitive: bool) -> VerifyOptions {
+    VerifyOptions {
+        case_insensitive: options.case_insensitive || case_insensitive,
+        ..options.clone()
+    }
+}
+
+/// random version 4 UUID in the hyphenated form
+fn uuid() -> String {
+    let mut bytes = entropy_rng().gen::<[u8; 16]>();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BiosvgBuilder, Charset};
+
+    #[test]
+    fn memory_store() {
+        let builder = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string()]);
+        let store = MemoryChallengeStore::new();
+        let captcha = builder.clone().build_captcha().unwrap();
+        let id = store.create(&captcha);
+        assert_eq!(id.len(), 36);
+        assert_eq!(&id[14..15], "4");
+        let other = store.create(&captcha);
+        assert_ne!(id, other);
+        assert_eq!(store.len(), 2);
+
+        assert_eq!(store.verify_and_consume(&id, &captcha.answer), Ok(()));
+        // single use, a replay of the solved captcha fails
+        assert_eq!(
+            store.verify_and_consume(&id, &captcha.answer),
+            Err(StoreError::AlreadyUsed)
+        );
+        // a wrong guess uses the challenge up too
+        assert_eq!(
+            store.verify_and_consume(&other, "nope"),
+            Err(StoreError::WrongAnswer)
+        );
+        assert_eq!(
+            store.verify_and_consume(&other, &captcha.answer),
+            Err(StoreError::AlreadyUsed)
+        );
+        assert_eq!(
+            store.verify_and_consume("unknown", &captcha.answer),
+            Err(StoreError::NotFound)
+        );
+        // used challenges are kept until they expire
+        assert_eq!(store.len(), 2);
+
+        // lowercase answers take the uppercase letters the user sees
+        let lower = builder
+            .clone()
+            .charset_kind(Charset::Lower)
+            .build_captcha()
+            .unwrap();
+        let id = store.create(&lower);
+        assert_eq!(
+            store.verify_and_consume(&id, &lower.answer.to_uppercase()),
+            Ok(())
+        );
+
+        let expired = Captcha {
+            expires_at: 0,
+            ..captcha.clone()
+        };
+        let id = store.create(&expired);
+        assert_eq!(
+            store.verify_and_consume(&id, &expired.answer),
+            Err(StoreError::Expired)
+        );
+
+        // expired challenges are purged by the inserts after them, but not
+        // on every insert
+        let store = MemoryChallengeStore::new();
+        store.create(&captcha);
+        store.create(&expired);
+        assert_eq!(store.len(), 2);
+        for _ in 2..PURGE_EVERY {
+            store.create(&captcha);
+        }
+        assert_eq!(store.len(), PURGE_EVERY);
+        store.create(&captcha);
+        assert_eq!(store.len(), PURGE_EVERY);
+    }
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn hashed_answer_store() {
+        let captcha = BiosvgBuilder::new()
+            .text("AbcD")
+            .colors(vec!["#0078D6".to_string()])
+            .hash_answer(crate::HashAlgo::Sha256 {
+                salt: "pepper".to_string(),
+            })
+            .build_captcha()
+            .unwrap();
+        let store = MemoryChallengeStore::new();
+        let id = store.put(&captcha).unwrap();
+        assert_eq!(store.verify_and_consume(&id, "AbcD"), Ok(()));
+        let id = store.put(&captcha).unwrap();
+        assert_eq!(
+            store.verify_and_consume(&id, &captcha.answer),
+            Err(StoreError::WrongAnswer)
+        );
+    }
+
+    #[test]
+    fn challenge_store_trait() {
+        use std::sync::Mutex;
+
+        // a custom backend keeping one challenge
+        struct SingleStore(Mutex<Option<(String, Captcha)>>);
+        impl ChallengeStore for SingleStore {
+            fn put(&self, captcha: &Captcha) -> Result<String, StoreError> {
+                *self.0.lock().unwrap() = Some(("only".to_string(), captcha.clone()));
+                Ok("only".to_string())
+            }
+            fn verify_and_consume(&self, id: &str, user_input: &str) -> Result<(), StoreError> {
+                match self.0.lock().unwrap().take() {
+                    Some((stored, captcha)) if stored == id => match captcha.answer == user_input {
+                        true => Ok(()),
+                        false => Err(StoreError::WrongAnswer),
+                    },
+                    _ => Err(StoreError::NotFound),
+                }
+            }
+            fn purge_expired(&self) -> Result<usize, StoreError> {
+                Ok(0)
+            }
+        }
+
+        let captcha = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string()])
+            .build_captcha()
+            .unwrap();
+        let stores: Vec<Box<dyn ChallengeStore>> = vec![
+            Box::new(MemoryChallengeStore::new()),
+            Box::new(SingleStore(Mutex::new(None))),
+        ];
+        for store in &stores {
+            let id = store.put(&captcha).unwrap();
+            assert_eq!(store.verify_and_consume(&id, &captcha.answer), Ok(()));
+            assert!(store.verify_and_consume(&id, &captcha.answer).is_err());
+        }
+
+        let memory = MemoryChallengeStore::new();
+        memory.create(&captcha);
+        memory.create(&Captcha {
+            expires_at: 0,
+            ..captcha.clone()
+        });
+        assert_eq!(ChallengeStore::purge_expired(&memory), Ok(1));
+        assert_eq!(memory.len(), 1);
+    }
+}
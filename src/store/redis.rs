@@ -0,0 +1,337 @@
+use std::fmt;
+use std::time::Duration;
+
+use ::redis::{Client, ConnectionLike, RedisError, Script, Value};
+use once_cell::sync::Lazy;
+use r2d2::{CustomizeConnection, ManageConnection, Pool};
+
+use super::{options, uuid, ChallengeStore, StoreError};
+use crate::captcha::{now, Captcha};
+use crate::verify::{verify, VerifyOptions};
+
+/// Read and delete a challenge and mark it used in one step, so a replay
+/// racing the first attempt sees the marker. returns the answer, or whether
+/// the marker exists if the challenge is gone. the marker expires with the
+/// challenge.
+static CONSUME: Lazy<Script> = Lazy::new(|| {
+    Script::new(
+        r"
+local ttl = redis.call('PTTL', KEYS[1])
+local answer = redis.call('GETDEL', KEYS[1])
+if answer then
+    redis.call('SET', KEYS[2], '1', 'PX', math.max(ttl, 1))
+    return answer
+end
+return redis.call('EXISTS', KEYS[2])
+",
+    )
+});
+
+/// RedisChallengeStore keeps the answers in redis with the expiry of the
+/// captcha as key TTL, so all servers of a cluster share them, along with
+/// whether they are case insensitive. a script reads and deletes the answer
+/// with `GETDEL` and sets a used marker in one step, so a challenge can be
+/// verified once even by concurrent requests and replays fail with
+/// `AlreadyUsed`. requires redis 6.2 or later for `GETDEL`.
+///
+/// connections come from an r2d2 pool of any connection manager of the
+/// redis crate: a `Client` for a single server, or a `ClusterClient` or
+/// `LockedSentinelClient` with the `cluster` and `sentinel` features of the
+/// redis crate. the keys of a challenge share a hash tag, so they are in the
+/// same cluster slot.
+#[derive(Debug)]
+pub struct RedisChallengeStore<M: ManageConnection = Client> {
+    pool: Pool<M>,
+    prefix: String,
+    options: VerifyOptions,
+}
+
+/// Timeouts sets the read and write timeouts of new connections
+#[derive(Debug)]
+struct Timeouts(Duration);
+
+impl CustomizeConnection<::redis::Connection, RedisError> for Timeouts {
+    fn on_acquire(&self, connection: &mut ::redis::Connection) -> Result<(), RedisError> {
+        connection.set_read_timeout(Some(self.0))?;
+        connection.set_write_timeout(Some(self.0))
+    }
+}
+
+impl RedisChallengeStore {
+    /// store challenges in the redis server at `url`, e.g.
+    /// `redis://:password@127.0.0.1:6379/3` for `AUTH password` and database
+    /// 3. `rediss://` urls need a TLS feature of the redis crate such as
+    /// `tls-rustls`. connections are opened on first use and kept for reuse,
+    /// waiting for a connection and every read and write time out after 5
+    /// seconds.
+    pub fn new(url: &str) -> Result<RedisChallengeStore, StoreError> {
+        let timeout = Duration::from_secs(5);
+        let client = Client::open(url).map_err(backend)?;
+        let pool = Pool::builder()
+            .min_idle(Some(0))
+            .connection_timeout(timeout)
+            .connection_customizer(Box::new(Timeouts(timeout)))
+            .build_unchecked(client);
+        Ok(RedisChallengeStore::with_pool(pool))
+    }
+}
+
+impl<M> RedisChallengeStore<M>
+where
+    M: ManageConnection,
+    M::Connection: ConnectionLike,
+{
+    /// store challenges with the connections of `pool`, to connect to a
+    /// cluster, through sentinels or with timeouts of your own
+    pub fn with_pool(pool: Pool<M>) -> RedisChallengeStore<M> {
+        RedisChallengeStore {
+            pool,
+            prefix: "biosvg:challenge:".to_string(),
+            options: VerifyOptions::default(),
+        }
+    }
+
+    /// prefix of the redis keys, defaults to `biosvg:challenge:`
+    pub fn key_prefix(mut self, prefix: &str) -> RedisChallengeStore<M> {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// compare answers with `options` instead of the defaults
+    pub fn with_options(mut self, options: VerifyOptions) -> RedisChallengeStore<M> {
+        self.options = options;
+        self
+    }
+
+    /// key of a challenge, the id is the hash tag
+    fn key(&self, id: &str) -> String {
+        format!("{}{{{}}}", self.prefix, id)
+    }
+}
+
+impl<M> ChallengeStore for RedisChallengeStore<M>
+where
+    M: ManageConnection,
+    M::Connection: ConnectionLike,
+{
+    fn put(&self, captcha: &Captcha) -> Result<String, StoreError> {
+        let id = uuid();
+        let ttl = captcha.expires_at.saturating_sub(now()).max(1);
+        // the case is kept with the answer as a 0 or 1 before a colon
+        let value = format!(
+            "{}:{}",
+            captcha.case_insensitive as u8,
+            captcha.plain_answer()
+        );
+        let mut connection = self.pool.get().map_err(backend)?;
+        ::redis::cmd("SET")
+            .arg(self.key(&id))
+            .arg(value)
+            .arg("EX")
+            .arg(ttl)
+            .exec(&mut *connection)
+            .map_err(backend)?;
+        Ok(id)
+    }
+
+    fn verify_and_consume(&self, id: &str, user_input: &str) -> Result<(), StoreError> {
+        // ids are made by `put`, others could reach keys such as the markers
+        if !is_uuid(id) {
+            return Err(StoreError::NotFound);
+        }
+        let key = self.key(id);
+        let mut connection = self.pool.get().map_err(backend)?;
+        // redis drops the key when the captcha expires
+        let value = match CONSUME
+            .key(&key)
+            .key(format!("{}:used", key))
+            .invoke::<Value>(&mut *connection)
+            .map_err(backend)?
+        {
+            Value::BulkString(value) => String::from_utf8(value).ok(),
+            Value::Int(0) => return Err(StoreError::NotFound),
+            Value::Int(_) => return Err(StoreError::AlreadyUsed),
+            reply => return Err(unexpected(reply)),
+        };
+        let (case_insensitive, answer) = value
+            .as_deref()
+            .and_then(|value| value.split_once(':'))
+            .and_then(|(case_insensitive, answer)| match case_insensitive {
+                "0" => Some((false, answer)),
+                "1" => Some((true, answer)),
+                _ => None,
+            })
+            .ok_or_else(|| StoreError::Backend("invalid stored challenge".to_string()))?;
+        let options = options(&self.options, case_insensitive);
+        match verify(answer, user_input, &options) {
+            true => Ok(()),
+            false => Err(StoreError::WrongAnswer),
+        }
+    }
+
+    fn purge_expired(&self) -> Result<usize, StoreError> {
+        // the keys expire with their TTL
+        Ok(0)
+    }
+}
+
+/// whether the id is a hyphenated UUID like the ids of `put`
+fn is_uuid(id: &str) -> bool {
+    id.len() == 36
+        && id.char_indices().all(|(i, ch)| match i {
+            8 | 13 | 18 | 23 => ch == '-',
+            _ => ch.is_ascii_hexdigit(),
+        })
+}
+
+fn backend(err: impl fmt::Display) -> StoreError {
+    StoreError::Backend(err.to_string())
+}
+
+fn unexpected(reply: Value) -> StoreError {
+    StoreError::Backend(format!("unexpected redis reply {:?}", reply))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BiosvgBuilder, Charset};
+
+    #[test]
+    fn redis_store() {
+        use std::collections::HashMap;
+        use std::io::{BufRead, BufReader, Read, Write};
+        use std::net::TcpListener;
+        use std::sync::{Arc, Mutex};
+
+        // a fake redis server handling the commands of the store and the
+        // consume script
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let keys = Arc::new(Mutex::new(HashMap::<String, String>::new()));
+        let commands = Arc::new(Mutex::new(Vec::<Vec<String>>::new()));
+        let (server_keys, server_commands) = (keys.clone(), commands.clone());
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let stream = stream.unwrap();
+                let (keys, commands) = (server_keys.clone(), server_commands.clone());
+                std::thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut writer = stream;
+                    let mut line = String::new();
+                    while reader.read_line(&mut line).unwrap_or(0) > 0 {
+                        let count: usize = line.trim()[1..].parse().unwrap();
+                        let mut args = Vec::new();
+                        for _ in 0..count {
+                            line.clear();
+                            reader.read_line(&mut line).unwrap();
+                            let len: usize = line.trim()[1..].parse().unwrap();
+                            let mut arg = vec![0; len + 2];
+                            reader.read_exact(&mut arg).unwrap();
+                            arg.truncate(len);
+                            args.push(String::from_utf8(arg).unwrap());
+                        }
+                        let mut keys = keys.lock().unwrap();
+                        let reply = match args[0].as_str() {
+                            "AUTH" | "SELECT" | "CLIENT" => "+OK\r\n".to_string(),
+                            "PING" => "+PONG\r\n".to_string(),
+                            "SET" => {
+                                keys.insert(args[1].clone(), args[2].clone());
+                                "+OK\r\n".to_string()
+                            }
+                            "EVALSHA" => match keys.remove(&args[3]) {
+                                Some(answer) => {
+                                    keys.insert(args[4].clone(), "1".to_string());
+                                    format!("${}\r\n{}\r\n", answer.len(), answer)
+                                }
+                                None => format!(":{}\r\n", keys.contains_key(&args[4]) as u8),
+                            },
+                            _ => "-ERR unknown command\r\n".to_string(),
+                        };
+                        commands.lock().unwrap().push(args);
+                        writer.write_all(reply.as_bytes()).unwrap();
+                        line.clear();
+                    }
+                });
+            }
+        });
+
+        let captcha = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string()])
+            .build_captcha()
+            .unwrap();
+        let store = RedisChallengeStore::new(&format!("redis://:hunter2@{}/3", address)).unwrap();
+        let id = store.put(&captcha).unwrap();
+        let other = store.put(&captcha).unwrap();
+        // lowercase answers take the uppercase letters the user sees
+        let lower = BiosvgBuilder::new()
+            .length(4)
+            .colors(vec!["#0078D6".to_string()])
+            .charset_kind(Charset::Lower)
+            .build_captcha()
+            .unwrap();
+        let lower_id = store.put(&lower).unwrap();
+        assert_eq!(
+            ChallengeStore::verify_and_consume(&store, &lower_id, &lower.answer.to_uppercase()),
+            Ok(())
+        );
+        assert_eq!(
+            ChallengeStore::verify_and_consume(&store, &id, &captcha.answer),
+            Ok(())
+        );
+        assert_eq!(
+            ChallengeStore::verify_and_consume(&store, &id, &captcha.answer),
+            Err(StoreError::AlreadyUsed)
+        );
+        assert_eq!(
+            ChallengeStore::verify_and_consume(&store, &other, "nope"),
+            Err(StoreError::WrongAnswer)
+        );
+        assert_eq!(
+            ChallengeStore::verify_and_consume(&store, &uuid(), "nope"),
+            Err(StoreError::NotFound)
+        );
+        // ids that aren't made by the store never reach redis, so the used
+        // markers can't be consumed
+        let commands_before = commands.lock().unwrap().len();
+        for id in [
+            "unknown",
+            &format!("{}:used", id),
+            &format!("{}}}:used", id),
+        ] {
+            assert_eq!(
+                ChallengeStore::verify_and_consume(&store, id, &captcha.answer),
+                Err(StoreError::NotFound)
+            );
+        }
+        assert_eq!(commands.lock().unwrap().len(), commands_before);
+        assert_eq!(
+            ChallengeStore::verify_and_consume(&store, &id, &captcha.answer),
+            Err(StoreError::AlreadyUsed)
+        );
+
+        let commands = commands.lock().unwrap();
+        assert!(commands.contains(&vec!["AUTH".to_string(), "hunter2".to_string()]));
+        assert!(commands.contains(&vec!["SELECT".to_string(), "3".to_string()]));
+        let key = format!("biosvg:challenge:{{{}}}", id);
+        let set = commands
+            .iter()
+            .find(|args| args[0] == "SET" && args[1] == key)
+            .unwrap();
+        assert_eq!(set[2..4], [format!("0:{}", captcha.answer).as_str(), "EX"]);
+        assert!(set[4].parse::<u64>().unwrap() > 0);
+        let consume = commands
+            .iter()
+            .find(|args| args[0] == "EVALSHA" && args[3] == key)
+            .unwrap();
+        assert_eq!(consume[1], CONSUME.get_hash());
+        assert_eq!(consume[2..], ["2", &key, &format!("{}:used", key)]);
+        assert!(keys.lock().unwrap().contains_key(&format!("{}:used", key)));
+
+        assert!(matches!(
+            RedisChallengeStore::new("127.0.0.1:6379"),
+            Err(StoreError::Backend(_))
+        ));
+    }
+}
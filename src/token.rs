@@ -0,0 +1,526 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use thiserror::Error;
+
+use super::captcha::{now, Captcha};
+use super::verify::{normalize, VerifyOptions};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// version prefix of the token format
+const VERSION: &str = "v3";
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TokenError {
+    #[error("malformed captcha token")]
+    Malformed,
+    #[error("invalid captcha token signature")]
+    BadSignature,
+    #[error("captcha token expired")]
+    Expired,
+    #[error("wrong captcha answer")]
+    WrongAnswer,
+    #[error("captcha token already used")]
+    AlreadyUsed,
+    #[error("too many wrong captcha answers")]
+    TooManyAttempts,
+}
+
+/// UsedTokens remembers the tokens it checked until they expire, so a solved
+/// token can't be replayed and a token fails for good after its
+/// `max_attempts` wrong guesses. expired tokens are forgotten every 1024 new
+/// tokens or a minute.
+#[derive(Debug, Default)]
+pub struct UsedTokens {
+    nonces: Mutex<Nonces>,
+}
+
+/// new tokens between two purges of expired tokens in `UsedTokens`
+const PURGE_EVERY: usize = 1024;
+/// seconds after which `UsedTokens` purges expired tokens whatever the number
+/// of new tokens
+const PURGE_INTERVAL: u64 = 60;
+
+/// Nonces are the remembered tokens and when they were last purged
+#[derive(Debug, Default)]
+struct Nonces {
+    map: HashMap<String, Attempts>,
+    inserts: usize,
+    purged_at: u64,
+}
+
+impl Nonces {
+    /// The attempts of a token, a new or expired entry starts over. expired
+    /// tokens are dropped as new ones come in, not on every check to keep
+    /// the lock short.
+    fn entry(&mut self, nonce: &str, expires_at: u64, now: u64) -> &mut Attempts {
+        if !self.map.contains_key(nonce) {
+            self.inserts += 1;
+            if self.inserts >= PURGE_EVERY || now >= self.purged_at + PURGE_INTERVAL {
+                self.map.retain(|_, attempts| attempts.expires_at >= now);
+                (self.inserts, self.purged_at) = (0, now);
+            }
+        }
+        let fresh = Attempts {
+            expires_at,
+            failures: 0,
+            solved: false,
+        };
+        let attempts = self.map.entry(nonce.to_string()).or_insert(fresh);
+        // a custom nonce can come back after its token expired
+        if attempts.expires_at < now {
+            *attempts = fresh;
+        }
+        attempts
+    }
+}
+
+/// Attempts is what `UsedTokens` knows of a token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Attempts {
+    expires_at: u64,
+    failures: u32,
+    solved: bool,
+}
+
+/// fields of a token with a valid signature
+struct Payload<'a> {
+    expires_at: u64,
+    nonce: &'a str,
+    max_attempts: u32,
+    case_insensitive: bool,
+    hash: Vec<u8>,
+}
+
+impl Captcha {
+    /// Sign a self-contained token of the captcha that expires with the
+    /// captcha at `expires_at`, check it with `verify_token`. the token only
+    /// has a keyed hash of the answer, the answer can't be recovered without
+    /// the secret. `max_attempts` is signed into the token and enforced by
+    /// `UsedTokens::verify_token`, `case_insensitive` is signed too and
+    /// decides how the input is matched. `.` and `%` of a custom `nonce` are
+    /// percent-encoded, the fields of the token are separated by dots.
+    pub fn sign(&self, secret: &[u8]) -> String {
+        let payload = format!(
+            "{}.{}.{}.{}.{}.{}",
+            VERSION,
+            self.expires_at,
+            self.nonce.replace('%', "%25").replace('.', "%2E"),
+            self.max_attempts,
+            self.case_insensitive,
+            hex(&answer_hash(
+                secret,
+                self.plain_answer(),
+                self.case_insensitive
+            ))
+        );
+        let signature = hex(&mac(secret, b"token", payload.as_bytes())
+            .finalize()
+            .into_bytes());
+        format!("{}.{}", payload, signature)
+    }
+}
+
+/// Check a token of `Captcha::sign` against the user input, the input
+/// matches like in `verify` with the default `VerifyOptions` and the
+/// `case_insensitive` of the captcha: trimmed, with the default confusables
+/// and ignoring case if the captcha does. signature and answer are compared
+/// in constant time. it keeps no state, so it can't stop replays or
+/// count attempts, use `UsedTokens` for that.
+pub fn verify_token(secret: &[u8], token: &str, user_input: &str) -> Result<(), TokenError> {
+    let payload = open(secret, token)?;
+    check_answer(secret, &payload, user_input)
+}
+
+impl UsedTokens {
+    /// no tokens used yet
+    pub fn new() -> UsedTokens {
+        UsedTokens::default()
+    }
+
+    /// Check a token like `verify_token`, a token can be verified
+    /// successfully once, later attempts fail with `AlreadyUsed`. after the
+    /// `max_attempts` of the token in wrong guesses every attempt fails with
+    /// `TooManyAttempts`, the right answer too.
+    pub fn verify_token(
+        &self,
+        secret: &[u8],
+        token: &str,
+        user_input: &str,
+    ) -> Result<(), TokenError> {
+        let payload = open(secret, token)?;
+        let mut nonces = self.nonces.lock().unwrap();
+        let attempts = nonces.entry(payload.nonce, payload.expires_at, now());
+        if attempts.solved {
+            return Err(TokenError::AlreadyUsed);
+        }
+        if payload.max_attempts > 0 && attempts.failures >= payload.max_attempts {
+            return Err(TokenError::TooManyAttempts);
+        }
+        match check_answer(secret, &payload, user_input) {
+            Ok(()) => attempts.solved = true,
+            Err(err) => {
+                attempts.failures += 1;
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// number of remembered tokens
+    pub fn len(&self) -> usize {
+        self.nonces.lock().unwrap().map.len()
+    }
+
+    /// whether no tokens are remembered
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Check the signature and expiry of a token and return its fields.
+fn open<'a>(secret: &[u8], token: &'a str) -> Result<Payload<'a>, TokenError> {
+    let (payload, signature) = token.rsplit_once('.').ok_or(TokenError::Malformed)?;
+    let signature = unhex(signature).ok_or(TokenError::Malformed)?;
+    mac(secret, b"token", payload.as_bytes())
+        .verify_slice(&signature)
+        .map_err(|_| TokenError::BadSignature)?;
+
+    let mut fields = payload.split('.');
+    if fields.next() != Some(VERSION) {
+        return Err(TokenError::Malformed);
+    }
+    let expires_at = fields
+        .next()
+        .and_then(|expires_at| expires_at.parse::<u64>().ok())
+        .ok_or(TokenError::Malformed)?;
+    // the nonce is covered by the signature, it makes tokens unique
+    let nonce = fields.next().ok_or(TokenError::Malformed)?;
+    let max_attempts = fields
+        .next()
+        .and_then(|max_attempts| max_attempts.parse::<u32>().ok())
+        .ok_or(TokenError::Malformed)?;
+    let case_insensitive = fields
+        .next()
+        .and_then(|case_insensitive| case_insensitive.parse::<bool>().ok())
+        .ok_or(TokenError::Malformed)?;
+    let hash = fields.next().and_then(unhex).ok_or(TokenError::Malformed)?;
+    if now() > expires_at {
+        return Err(TokenError::Expired);
+    }
+    Ok(Payload {
+        expires_at,
+        nonce,
+        max_attempts,
+        case_insensitive,
+        hash,
+    })
+}
+
+/// compare the normalized input with the answer hash of a token
+fn check_answer(secret: &[u8], payload: &Payload, user_input: &str) -> Result<(), TokenError> {
+    let user_input = normalize(user_input, &options(payload.case_insensitive));
+    mac(secret, b"answer", user_input.as_bytes())
+        .verify_slice(&payload.hash)
+        .map_err(|_| TokenError::WrongAnswer)
+}
+
+/// keyed hash of the answer normalized like in `verify`, so the input can be
+/// checked with the same rule
+fn answer_hash(secret: &[u8], answer: &str, case_insensitive: bool) -> Vec<u8> {
+    let answer = normalize(answer, &options(case_insensitive));
+    mac(secret, b"answer", answer.as_bytes())
+        .finalize()
+        .into_bytes()
+        .to_vec()
+}
+
+/// the default `VerifyOptions`, ignoring case if the captcha does
+fn options(case_insensitive: bool) -> VerifyOptions {
+    VerifyOptions {
+        case_insensitive,
+        ..VerifyOptions::default()
+    }
+}
+
+/// hmac of the message, separated by purpose so an answer hash is never a
+/// valid signature
+fn mac(secret: &[u8], purpose: &[u8], message: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret).expect("hmac accepts any key length");
+    mac.update(purpose);
+    mac.update(b"\0");
+    mac.update(message);
+    mac
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn unhex(hex: &str) -> Option<Vec<u8>> {
+    // from_str_radix takes a leading sign, a signature must be plain hex
+    if hex.len() % 2 != 0 || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verify::verify;
+    use crate::BiosvgBuilder;
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn hashed_answer_token() {
+        let algo = crate::HashAlgo::Sha256 {
+            salt: "pepper".to_string(),
+        };
+        let captcha = BiosvgBuilder::new()
+            .text("AbcD")
+            .colors(vec!["#0078D6".to_string()])
+            .hash_answer(algo.clone())
+            .build_captcha()
+            .unwrap();
+        assert_eq!(captcha.answer, algo.hash("AbcD", false));
+        assert_eq!(captcha.plain_answer(), "AbcD");
+        // the plaintext is never printed
+        assert!(!format!("{:?}", captcha).contains("AbcD"));
+        let token = captcha.sign(b"secret");
+        assert_eq!(verify_token(b"secret", &token, "AbcD"), Ok(()));
+        assert_eq!(
+            verify_token(b"secret", &token, &captcha.answer),
+            Err(TokenError::WrongAnswer)
+        );
+        let used = UsedTokens::new();
+        assert_eq!(used.verify_token(b"secret", &token, "AbcD"), Ok(()));
+    }
+
+    #[test]
+    fn signed_token() {
+        let captcha = BiosvgBuilder::new()
+            .text("AbcD")
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .build_captcha()
+            .unwrap();
+        let token = captcha.sign(b"secret");
+        assert!(!token.contains("AbcD"));
+        assert!(token.contains(&captcha.nonce));
+        assert_eq!(verify_token(b"secret", &token, " AbcD "), Ok(()));
+        assert_eq!(
+            verify_token(b"secret", &token, "abcd"),
+            Err(TokenError::WrongAnswer)
+        );
+        assert_eq!(
+            verify_token(b"other", &token, "AbcD"),
+            Err(TokenError::BadSignature)
+        );
+        let forged = token.replacen(".", ".9", 1);
+        assert_eq!(
+            verify_token(b"secret", &forged, "AbcD"),
+            Err(TokenError::BadSignature)
+        );
+        assert_eq!(
+            verify_token(b"secret", "garbage", "AbcD"),
+            Err(TokenError::Malformed)
+        );
+        // the same rule as verify with the default options
+        let confusing = Captcha {
+            answer: "SOIB".to_string(),
+            ..captcha.clone()
+        };
+        let token = confusing.sign(b"secret");
+        for input in ["SOIB", "5018", " 5oI8 ", "sOIB"] {
+            let options = VerifyOptions::default();
+            assert_eq!(
+                verify_token(b"secret", &token, input).is_ok(),
+                verify(&confusing.answer, input, &options),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(verify_token(b"secret", &token, "5018"), Ok(()));
+
+        // case_insensitive is signed into the token
+        let insensitive = BiosvgBuilder::new()
+            .text("AbcD")
+            .case_insensitive(true)
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .build_captcha()
+            .unwrap();
+        assert!(insensitive.case_insensitive);
+        let token = insensitive.sign(b"secret");
+        for input in ["abcd", "ABCD", " AbcD "] {
+            assert_eq!(verify_token(b"secret", &token, input), Ok(()), "{}", input);
+        }
+        let flipped = token.replacen(".true.", ".false.", 1);
+        assert_eq!(
+            verify_token(b"secret", &flipped, "abcd"),
+            Err(TokenError::BadSignature)
+        );
+
+        // a signed "+f" parses like "0f", the signature must be plain hex
+        let (token, index) = (0..)
+            .find_map(|i| {
+                let token = Captcha {
+                    nonce: i.to_string(),
+                    ..captcha.clone()
+                }
+                .sign(b"secret");
+                let start = token.rfind('.').unwrap() + 1;
+                (start..token.len())
+                    .step_by(2)
+                    .find(|index| token.as_bytes()[*index] == b'0')
+                    .map(|index| (token.clone(), index))
+            })
+            .unwrap();
+        assert_eq!(verify_token(b"secret", &token, "AbcD"), Ok(()));
+        let mut signed = token.clone();
+        signed.replace_range(index..index + 1, "+");
+        assert_eq!(
+            verify_token(b"secret", &signed, "AbcD"),
+            Err(TokenError::Malformed)
+        );
+
+        let expired = Captcha {
+            expires_at: 0,
+            ..captcha
+        };
+        let token = expired.sign(b"secret");
+        assert_eq!(
+            verify_token(b"secret", &token, "AbcD"),
+            Err(TokenError::Expired)
+        );
+    }
+
+    #[test]
+    fn token_nonce() {
+        let captcha = BiosvgBuilder::new()
+            .text("AbcD")
+            .colors(vec!["#0078D6".to_string(), "#aa3333".to_string()])
+            .build_captcha()
+            .unwrap();
+        // dots of a custom nonce don't split the token
+        let dotted = Captcha {
+            nonce: "a.b".to_string(),
+            ..captcha.clone()
+        };
+        let token = dotted.sign(b"secret");
+        assert_eq!(token.matches('.').count(), 6);
+        assert_eq!(verify_token(b"secret", &token, "AbcD"), Ok(()));
+        // and an escaped nonce is still a different one
+        let escaped = Captcha {
+            nonce: "a%2Eb".to_string(),
+            ..captcha
+        };
+        let used = UsedTokens::new();
+        assert_eq!(used.verify_token(b"secret", &token, "AbcD"), Ok(()));
+        let token = escaped.sign(b"secret");
+        assert_eq!(used.verify_token(b"secret", &token, "AbcD"), Ok(()));
+        assert_eq!(used.len(), 2);
+    }
+
+    #[test]
+    fn single_use_token() {
+        let builder = BiosvgBuilder::new()
+            .text("AbcD")
+            .colors(vec!["#0078D6".to_string()]);
+        let token = builder.clone().build_captcha().unwrap().sign(b"secret");
+        let other = builder.build_captcha().unwrap().sign(b"secret");
+        let used = UsedTokens::new();
+        // wrong guesses don't use the token up
+        assert_eq!(
+            used.verify_token(b"secret", &token, "abcd"),
+            Err(TokenError::WrongAnswer)
+        );
+        assert_eq!(used.verify_token(b"secret", &token, "AbcD"), Ok(()));
+        assert_eq!(
+            used.verify_token(b"secret", &token, "AbcD"),
+            Err(TokenError::AlreadyUsed)
+        );
+        assert_eq!(used.verify_token(b"secret", &other, "AbcD"), Ok(()));
+        assert_eq!(used.len(), 2);
+        // the stateless check can't tell
+        assert_eq!(verify_token(b"secret", &token, "AbcD"), Ok(()));
+    }
+
+    #[test]
+    fn used_tokens_purge() {
+        // expired tokens are purged by the new tokens after them, but not on
+        // every check
+        let mut nonces = Nonces::default();
+        nonces.entry("expired", 1010, 1000);
+        nonces.entry("valid", 2000, 1000);
+        for i in 2..PURGE_EVERY {
+            nonces.entry(&i.to_string(), 2000, 1020).failures += 1;
+        }
+        assert_eq!(nonces.map.len(), PURGE_EVERY);
+        // checking a known token doesn't count
+        nonces.entry("valid", 2000, 1020);
+        assert_eq!(nonces.map.len(), PURGE_EVERY);
+        nonces.entry("new", 2000, 1020);
+        assert_eq!(nonces.map.len(), PURGE_EVERY);
+        assert!(!nonces.map.contains_key("expired"));
+        // and after a minute whatever the number of new tokens
+        nonces.entry("late", 2000, 1080);
+        assert_eq!(nonces.map.len(), PURGE_EVERY + 1);
+        // an expired entry is never used for a token with the same nonce
+        let attempts = nonces.entry("2", 4000, 3000);
+        assert_eq!((attempts.failures, attempts.expires_at), (0, 4000));
+    }
+
+    #[test]
+    fn token_attempts() {
+        let captcha = BiosvgBuilder::new()
+            .text("AbcD")
+            .colors(vec!["#0078D6".to_string()])
+            .max_attempts(2)
+            .build_captcha()
+            .unwrap();
+        assert_eq!(captcha.max_attempts, 2);
+        let token = captcha.sign(b"secret");
+        // the limit is signed, raising it breaks the signature
+        let (payload, signature) = token.rsplit_once('.').unwrap();
+        let mut fields = payload.split('.').collect::<Vec<_>>();
+        assert_eq!(fields[3], "2");
+        fields[3] = "99";
+        assert_eq!(
+            verify_token(
+                b"secret",
+                &format!("{}.{}", fields.join("."), signature),
+                "AbcD"
+            ),
+            Err(TokenError::BadSignature)
+        );
+
+        let used = UsedTokens::new();
+        for _ in 0..2 {
+            assert_eq!(
+                used.verify_token(b"secret", &token, "nope"),
+                Err(TokenError::WrongAnswer)
+            );
+        }
+        assert_eq!(
+            used.verify_token(b"secret", &token, "AbcD"),
+            Err(TokenError::TooManyAttempts)
+        );
+        // a forged token doesn't count as an attempt of another
+        let other = Captcha {
+            nonce: "other".to_string(),
+            ..captcha
+        }
+        .sign(b"secret");
+        assert_eq!(
+            used.verify_token(b"other", &other, "nope"),
+            Err(TokenError::BadSignature)
+        );
+        assert_eq!(used.verify_token(b"secret", &other, "AbcD"), Ok(()));
+    }
+}
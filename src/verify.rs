@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use subtle::ConstantTimeEq;
+
+/// VerifyOptions controls how loosely `verify` compares the user input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyOptions {
+    /// ignore the case of letters
+    pub case_insensitive: bool,
+    /// ignore whitespace around the input
+    pub trim: bool,
+    /// characters that are easily mistaken for another one, each key is
+    /// replaced by its value on both sides before comparing
+    pub confusable_map: HashMap<char, char>,
+}
+
+impl Default for VerifyOptions {
+    /// case sensitive, trimmed, with the default confusables
+    fn default() -> Self {
+        VerifyOptions {
+            case_insensitive: false,
+            trim: true,
+            confusable_map: default_confusables(),
+        }
+    }
+}
+
+/// built-in confusables, each character and the one it is mistaken for
+static CONFUSABLES: &[(char, char)] = &[
+    ('O', '0'),
+    ('o', '0'),
+    ('I', '1'),
+    ('l', '1'),
+    ('Z', '2'),
+    ('S', '5'),
+    ('B', '8'),
+];
+
+/// Characters people commonly type for each other: `O`/`o` for `0`, `I`/`l`
+/// for `1`, `Z` for `2`, `S` for `5` and `B` for `8`.
+pub fn default_confusables() -> HashMap<char, char> {
+    CONFUSABLES.iter().copied().collect()
+}
+
+/// whether two different characters are in the same group of the built-in
+/// confusables, e.g. `I` and `l` which both read as `1`
+pub(crate) fn confusable(a: char, b: char) -> bool {
+    let group = |ch: char| {
+        CONFUSABLES
+            .iter()
+            .find(|(from, _)| *from == ch)
+            .map_or(ch, |(_, to)| *to)
+    };
+    a != b && group(a) == group(b)
+}
+
+/// Compare the user input to the answer of a captcha, with the same
+/// normalization everywhere the answer is checked. the normalized texts are
+/// compared in constant time, the time taken only depends on their lengths
+/// and not on how many characters match.
+pub fn verify(answer: &str, user_input: &str, options: &VerifyOptions) -> bool {
+    normalize(answer, options)
+        .as_bytes()
+        .ct_eq(normalize(user_input, options).as_bytes())
+        .into()
+}
+
+/// Normalize a text with the `options` of `verify`, two texts match if their
+/// normalized forms are equal.
+pub(crate) fn normalize(text: &str, options: &VerifyOptions) -> String {
+    let fold = |ch: char| {
+        if options.case_insensitive {
+            ch.to_lowercase().next().unwrap_or(ch)
+        } else {
+            ch
+        }
+    };
+    // confusables are folded too, so `s` also matches `5` ignoring case
+    let confusables = options
+        .confusable_map
+        .iter()
+        .map(|(from, to)| (fold(*from), fold(*to)))
+        .collect::<HashMap<char, char>>();
+    let text = if options.trim { text.trim() } else { text };
+    text.chars()
+        .map(|ch| {
+            let ch = fold(ch);
+            confusables.get(&ch).copied().unwrap_or(ch)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_answer() {
+        let options = VerifyOptions::default();
+        assert!(verify("aB8c", " aB8c\n", &options));
+        assert!(verify("0l5", "OI5", &options));
+        assert!(!verify("aB8c", "ab8c", &options));
+        assert!(!verify("aB8c", "aB8", &options));
+
+        let options = VerifyOptions {
+            case_insensitive: true,
+            ..VerifyOptions::default()
+        };
+        assert!(verify("aB8c", "AbbC", &options));
+        assert!(verify("S2", "5z", &options));
+
+        let options = VerifyOptions {
+            case_insensitive: false,
+            trim: false,
+            confusable_map: Default::default(),
+        };
+        assert!(!verify("0l5", "OI5", &options));
+        assert!(!verify("aB8c", "aB8c ", &options));
+    }
+}
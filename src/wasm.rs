@@ -0,0 +1,108 @@
+//! wasm-bindgen wrappers to generate captchas in the browser or in workers,
+//! enabled by the `wasm` feature.
+
+use wasm_bindgen::prelude::*;
+
+use super::{BiosvgBuilder, Captcha};
+
+/// JavaScript handle of a `BiosvgBuilder`, the setters return the builder
+/// like the Rust one
+#[wasm_bindgen(js_name = BiosvgBuilder)]
+#[derive(Debug, Clone, Default)]
+pub struct WasmBuilder {
+    builder: BiosvgBuilder,
+}
+
+#[wasm_bindgen(js_class = BiosvgBuilder)]
+impl WasmBuilder {
+    /// constructor
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmBuilder {
+        WasmBuilder::default()
+    }
+
+    /// set length of captcha text
+    pub fn length(self, length: usize) -> WasmBuilder {
+        self.map(|builder| builder.length(length))
+    }
+
+    /// set difficulty of captcha
+    pub fn difficulty(self, difficulty: u16) -> WasmBuilder {
+        self.map(|builder| builder.difficulty(difficulty))
+    }
+
+    /// set colors of captcha text and noise lines
+    pub fn colors(self, colors: Vec<String>) -> WasmBuilder {
+        self.map(|builder| builder.colors(colors))
+    }
+
+    /// set the seed of the random generator
+    pub fn seed(self, seed: u64) -> WasmBuilder {
+        self.map(|builder| builder.seed(seed))
+    }
+
+    /// build and generate a captcha, throws on invalid settings
+    pub fn build(&self) -> Result<WasmCaptcha, JsError> {
+        let captcha = self
+            .builder
+            .clone()
+            .build_captcha()
+            .map_err(|err| JsError::new(&err.to_string()))?;
+        Ok(WasmCaptcha { captcha })
+    }
+
+    fn map(self, f: impl FnOnce(BiosvgBuilder) -> BiosvgBuilder) -> WasmBuilder {
+        WasmBuilder {
+            builder: f(self.builder),
+        }
+    }
+}
+
+/// JavaScript handle of a generated `Captcha`
+#[wasm_bindgen(js_name = Captcha)]
+#[derive(Debug, Clone)]
+pub struct WasmCaptcha {
+    captcha: Captcha,
+}
+
+#[wasm_bindgen(js_class = Captcha)]
+impl WasmCaptcha {
+    /// answer text of the captcha
+    #[wasm_bindgen(getter)]
+    pub fn answer(&self) -> String {
+        self.captcha.answer.clone()
+    }
+
+    /// rendered svg document
+    #[wasm_bindgen(getter)]
+    pub fn svg(&self) -> String {
+        self.captcha.svg.clone()
+    }
+
+    /// width of the svg canvas
+    #[wasm_bindgen(getter)]
+    pub fn width(&self) -> f64 {
+        self.captcha.width
+    }
+
+    /// height of the svg canvas
+    #[wasm_bindgen(getter)]
+    pub fn height(&self) -> f64 {
+        self.captcha.height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wasm_builder() {
+        let colors = vec!["#0078D6".to_string(), "#aa3333".to_string()];
+        let builder = WasmBuilder::new().length(5).colors(colors).seed(7);
+        let captcha = builder.build().unwrap();
+        assert_eq!(captcha.answer().len(), 5);
+        assert!(captcha.svg().starts_with("<svg"));
+        assert_eq!(builder.build().unwrap().svg(), captcha.svg());
+    }
+}